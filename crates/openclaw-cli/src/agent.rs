@@ -0,0 +1,230 @@
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
+use clap::Subcommand;
+use ed25519_dalek::SigningKey;
+use openclaw_crypto::did::did_key_from_verifying_key;
+use openclaw_crypto::sign::{sign_artifact, sign_canonical};
+use openclaw_crypto::types::SignatureEnvelopeV1;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Subcommand)]
+pub enum AgentCommand {
+    /// Run as a local daemon that other processes on this machine can ask
+    /// to sign artifacts or approve small spends on this DID's behalf,
+    /// without handing them the private key
+    Run {
+        /// Base64-encoded 32-byte Ed25519 signing seed (see `manifest merge --key`)
+        #[arg(long)]
+        key: PathBuf,
+        /// JSON policy file naming the allowed action types and the largest
+        /// amount this daemon will sign off on unattended
+        #[arg(long)]
+        policy: PathBuf,
+        /// Address to listen on. Not authenticated beyond what's bound to —
+        /// keep this on loopback
+        #[arg(long, default_value = "127.0.0.1:8787")]
+        addr: SocketAddr,
+    },
+}
+
+pub fn run(cmd: AgentCommand) -> Result<()> {
+    match cmd {
+        AgentCommand::Run { key, policy, addr } => {
+            let signing_key = load_signing_key(&key)?;
+            let policy = load_policy(&policy)?;
+            let did = did_key_from_verifying_key(&signing_key.verifying_key());
+            println!("agent daemon signing as {did}, listening on {addr}");
+            let rt = tokio::runtime::Runtime::new().context("starting async runtime")?;
+            rt.block_on(serve(signing_key, did, policy, addr))
+        }
+    }
+}
+
+/// What `--policy` describes: the only things this daemon will do without a
+/// human in the loop. Mirrors `ApprovalTier` on the server side, but local
+/// and unconditional rather than requiring a quorum of other signers —
+/// policy enforcement here is the only thing standing between a compromised
+/// local caller and this DID's key.
+#[derive(Debug, Deserialize)]
+struct AgentPolicy {
+    allowed_action_types: Vec<String>,
+    max_amount: Decimal,
+}
+
+impl AgentPolicy {
+    fn allows(&self, action_type: &str, amount: Decimal) -> Result<(), String> {
+        if !self.allowed_action_types.iter().any(|a| a == action_type) {
+            return Err(format!("action type '{action_type}' is not in this daemon's policy"));
+        }
+        if amount > self.max_amount {
+            return Err(format!("amount {amount} exceeds this daemon's max_amount of {}", self.max_amount));
+        }
+        Ok(())
+    }
+}
+
+fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let seed_bytes = B64
+        .decode(raw.trim())
+        .with_context(|| format!("{}: not valid base64", path.display()))?;
+    let seed: [u8; 32] = seed_bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("{}: signing seed must be exactly 32 bytes", path.display()))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn load_policy(path: &Path) -> Result<AgentPolicy> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))
+}
+
+struct AgentState {
+    signing_key: SigningKey,
+    did: String,
+    policy: AgentPolicy,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignArtifactRequest {
+    /// Base64-encoded file content
+    content_b64: String,
+    filename: String,
+}
+
+#[derive(Debug, Serialize)]
+struct DaemonError {
+    error: String,
+}
+
+fn reject(status: StatusCode, message: impl Into<String>) -> (StatusCode, Json<DaemonError>) {
+    (status, Json(DaemonError { error: message.into() }))
+}
+
+/// `POST /sign-artifact` — signs `content_b64` as this DID, the same shape
+/// `sign::run(Prepare/Complete)` produces, but in one round trip since the
+/// key is already loaded locally rather than held offline.
+async fn sign_artifact_handler(
+    State(state): State<Arc<AgentState>>,
+    Json(req): Json<SignArtifactRequest>,
+) -> Result<Json<SignatureEnvelopeV1>, (StatusCode, Json<DaemonError>)> {
+    if let Err(reason) = state.policy.allows("sign_artifact", Decimal::ZERO) {
+        return Err(reject(StatusCode::FORBIDDEN, reason));
+    }
+    let bytes = B64
+        .decode(&req.content_b64)
+        .map_err(|e| reject(StatusCode::BAD_REQUEST, format!("content_b64: {e}")))?;
+
+    let envelope = sign_artifact(&state.signing_key, &req.filename, &bytes, &chrono::Utc::now().to_rfc3339(), serde_json::json!({}))
+        .map_err(|e| reject(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    println!(
+        "signed artifact '{}' ({} bytes) on behalf of {}",
+        req.filename,
+        bytes.len(),
+        state.did
+    );
+    Ok(Json(envelope))
+}
+
+/// Mirrors `openclaw_server::models::approval::ApprovalVote` — the CLI
+/// doesn't depend on the server crate, but the wire shape (bare
+/// `"Approve"`/`"Reject"`, no `rename_all`) has to match exactly, since it
+/// feeds straight into the signature `services::approvals::decide` verifies.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum Vote {
+    Approve,
+    Reject,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApproveRequest {
+    approval_request_id: Uuid,
+    vote: Vote,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    /// Not part of what gets signed — only used for this daemon's own
+    /// policy check, since it has no way to look up the `ApprovalRequest`
+    /// itself (it never talks to the server). The caller should mirror the
+    /// `action_type`/`amount` of the request it's asking this daemon to
+    /// vote on.
+    action_type: String,
+    amount: Decimal,
+}
+
+/// Must match `openclaw_server::services::approvals::ApprovalDecisionPayload`
+/// field-for-field — that's the exact shape `decide()` reconstructs and
+/// verifies the signature against.
+#[derive(Serialize)]
+struct ApprovalDecisionPayload {
+    action: &'static str,
+    approval_request_id: Uuid,
+    vote: Vote,
+    expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize)]
+struct ApproveResponse {
+    did: String,
+    approval_request_id: Uuid,
+    vote: Vote,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    signature: String,
+}
+
+/// `POST /approve` — signs off on `approval_request_id` within policy, the
+/// way a human approver would via `POST /api/v1/approvals/{id}/decide`, but
+/// without a round trip to a person for actions `--policy` already covers.
+/// The caller submits the resulting `signature` (along with
+/// `approval_request_id`/`vote`/`expires_at`) as that approver's decision to
+/// the server; this daemon never talks to the server itself.
+async fn approve_handler(
+    State(state): State<Arc<AgentState>>,
+    Json(req): Json<ApproveRequest>,
+) -> Result<Json<ApproveResponse>, (StatusCode, Json<DaemonError>)> {
+    if let Err(reason) = state.policy.allows(&req.action_type, req.amount) {
+        return Err(reject(StatusCode::FORBIDDEN, reason));
+    }
+
+    let payload = ApprovalDecisionPayload {
+        action: "approval_decision",
+        approval_request_id: req.approval_request_id,
+        vote: req.vote,
+        expires_at: req.expires_at,
+    };
+    let signature = sign_canonical(&state.signing_key, &payload)
+        .map_err(|e| reject(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    println!(
+        "voted {:?} on approval request {} ('{}', {}) on behalf of {}",
+        req.vote, req.approval_request_id, req.action_type, req.amount, state.did
+    );
+    Ok(Json(ApproveResponse {
+        did: state.did.clone(),
+        approval_request_id: req.approval_request_id,
+        vote: req.vote,
+        expires_at: req.expires_at,
+        signature,
+    }))
+}
+
+async fn serve(signing_key: SigningKey, did: String, policy: AgentPolicy, addr: SocketAddr) -> Result<()> {
+    let state = Arc::new(AgentState { signing_key, did, policy });
+    let app = Router::new()
+        .route("/sign-artifact", post(sign_artifact_handler))
+        .route("/approve", post(approve_handler))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr).await.with_context(|| format!("binding {addr}"))?;
+    axum::serve(listener, app).await.context("agent daemon stopped")
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+mod agent;
+mod manifest;
+mod sign;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "openclaw", version, about = "Protocol M command-line tools")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Inspect and manipulate contribution manifests
+    Manifest {
+        #[command(subcommand)]
+        command: manifest::ManifestCommand,
+    },
+    /// Air-gapped signing: prepare a canonical payload offline, then merge
+    /// back a signature produced by an HSM or other detached signer
+    Sign {
+        #[command(subcommand)]
+        command: sign::SignCommand,
+    },
+    /// Verify a signed envelope and its derivation chain
+    Verify {
+        #[command(subcommand)]
+        command: verify::VerifyCommand,
+    },
+    /// Run a local daemon that delegates policy-scoped signing/approval to
+    /// other processes on this machine
+    Agent {
+        #[command(subcommand)]
+        command: agent::AgentCommand,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Manifest { command } => manifest::run(command),
+        Command::Sign { command } => sign::run(command),
+        Command::Verify { command } => verify::run(command),
+        Command::Agent { command } => agent::run(command),
+    }
+}
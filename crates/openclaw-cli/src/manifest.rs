@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
+use clap::Subcommand;
+use ed25519_dalek::SigningKey;
+use openclaw_crypto::did::{did_key_from_verifying_key, verifying_key_from_did_key};
+use openclaw_crypto::sign::sign_canonical;
+use openclaw_crypto::types::{ManifestV1, SignatureEnvelopeV1};
+use openclaw_crypto::verify::verify_manifest;
+
+#[derive(Subcommand)]
+pub enum ManifestCommand {
+    /// Verify two manifests, deduplicate their artifacts by content hash,
+    /// and re-sign the combined result under a new key.
+    Merge {
+        /// First manifest to merge
+        a: PathBuf,
+        /// Second manifest to merge
+        b: PathBuf,
+        /// Where to write the combined, re-signed manifest
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Base64-encoded 32-byte Ed25519 signing seed for the combined manifest
+        #[arg(long)]
+        key: PathBuf,
+    },
+}
+
+pub fn run(cmd: ManifestCommand) -> Result<()> {
+    match cmd {
+        ManifestCommand::Merge { a, b, out, key } => merge(&a, &b, &out, &key),
+    }
+}
+
+fn load_manifest(path: &Path) -> Result<ManifestV1> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let manifest: ManifestV1 = serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+    let verifying_key = verifying_key_from_did_key(&manifest.did)
+        .with_context(|| format!("{}: manifest did is not a valid did:key", path.display()))?;
+    verify_manifest(&verifying_key, manifest.clone())
+        .with_context(|| format!("{}: manifest signature does not verify", path.display()))?;
+    Ok(manifest)
+}
+
+fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let seed_bytes = B64
+        .decode(raw.trim())
+        .with_context(|| format!("{}: not valid base64", path.display()))?;
+    let seed: [u8; 32] = seed_bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("{}: signing seed must be exactly 32 bytes", path.display()))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn merge(a_path: &Path, b_path: &Path, out_path: &Path, key_path: &Path) -> Result<()> {
+    let manifest_a = load_manifest(a_path)?;
+    let manifest_b = load_manifest(b_path)?;
+
+    if manifest_a.version != "m1" || manifest_b.version != "m1" {
+        bail!("unsupported manifest version");
+    }
+
+    let mut merged: Vec<SignatureEnvelopeV1> = Vec::new();
+    for envelope in manifest_a.artifacts.into_iter().chain(manifest_b.artifacts) {
+        match merged.iter().find(|e: &&SignatureEnvelopeV1| e.hash.value == envelope.hash.value) {
+            Some(existing) if existing.did != envelope.did || existing.metadata != envelope.metadata => {
+                eprintln!(
+                    "conflict: hash {} is signed by {} in one manifest and by {} in the other; keeping the first",
+                    envelope.hash.value, existing.did, envelope.did
+                );
+            }
+            Some(_) => {}
+            None => merged.push(envelope),
+        }
+    }
+
+    let signing_key = load_signing_key(key_path)?;
+    let did = did_key_from_verifying_key(&signing_key.verifying_key());
+
+    let mut combined = ManifestV1 {
+        version: "m1".to_string(),
+        r#type: "contribution_manifest".to_string(),
+        did,
+        algo: "ed25519".to_string(),
+        artifacts: merged,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        metadata: serde_json::json!({}),
+        signature: String::new(),
+    };
+    combined.signature = sign_canonical(&signing_key, &combined)?;
+
+    let json = serde_json::to_string_pretty(&combined)?;
+    fs::write(out_path, json).with_context(|| format!("writing {}", out_path.display()))?;
+    println!("wrote {} artifacts to {}", combined.artifacts.len(), out_path.display());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use openclaw_crypto::did::verifying_key_from_did_key;
+use openclaw_crypto::hash::sha256_hex;
+use openclaw_crypto::jcs::jcs_canonical_bytes;
+use openclaw_crypto::types::{ArtifactInfo, HashRef, SignatureEnvelopeV1};
+use openclaw_crypto::verify::verify_envelope_signature;
+use serde::{Deserialize, Serialize};
+
+#[derive(Subcommand)]
+pub enum SignCommand {
+    /// Emit an unsigned envelope plus the sha256 of its canonical form, for
+    /// an offline signer (an HSM, an air-gapped key) to produce a signature
+    Prepare {
+        /// File to sign
+        file: PathBuf,
+        /// DID the resulting envelope will claim
+        #[arg(long)]
+        did: String,
+        /// Where to write the prepared payload
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+    /// Merge a signature produced out of band into the envelope a previous
+    /// `sign prepare` emitted
+    Complete {
+        /// Prepared payload from `sign prepare`
+        prepared: PathBuf,
+        /// Base64 ed25519 signature over the prepared envelope's JCS canonical bytes
+        signature: String,
+        /// Where to write the completed, verified envelope
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+}
+
+/// What `sign prepare` writes: the envelope with everything but `signature`
+/// filled in, plus the sha256 of its JCS canonical form. The offline signer
+/// signs the canonical bytes themselves, not this hash; the hash lets it
+/// check that it canonicalized the same bytes.
+#[derive(Debug, Serialize, Deserialize)]
+struct PreparedPayload {
+    envelope: SignatureEnvelopeV1,
+    canonical_hash: String,
+}
+
+pub fn run(cmd: SignCommand) -> Result<()> {
+    match cmd {
+        SignCommand::Prepare { file, did, out } => prepare(&file, &did, &out),
+        SignCommand::Complete { prepared, signature, out } => complete(&prepared, &signature, &out),
+    }
+}
+
+fn prepare(file: &PathBuf, did: &str, out: &PathBuf) -> Result<()> {
+    let bytes = fs::read(file).with_context(|| format!("reading {}", file.display()))?;
+    let filename = file.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    let envelope = SignatureEnvelopeV1 {
+        version: "m1".to_string(),
+        r#type: "artifact_signature".to_string(),
+        did: did.to_string(),
+        algo: "ed25519".to_string(),
+        hash: HashRef {
+            algo: "sha256".to_string(),
+            value: sha256_hex(&bytes),
+        },
+        created_at: chrono::Utc::now().to_rfc3339(),
+        artifact: ArtifactInfo { name: filename, size: bytes.len() as u64 },
+        metadata: serde_json::json!({}),
+        signature: String::new(),
+    };
+
+    let canonical = jcs_canonical_bytes(&envelope)?;
+    let canonical_hash = sha256_hex(&canonical);
+
+    let prepared = PreparedPayload { envelope, canonical_hash };
+    fs::write(out, serde_json::to_string_pretty(&prepared)?).with_context(|| format!("writing {}", out.display()))?;
+    println!(
+        "wrote prepared payload to {}; sign the envelope's JCS canonical bytes (sha256 = canonical_hash) and run `sign complete`",
+        out.display()
+    );
+    Ok(())
+}
+
+fn complete(prepared_path: &PathBuf, signature: &str, out: &PathBuf) -> Result<()> {
+    let raw = fs::read_to_string(prepared_path).with_context(|| format!("reading {}", prepared_path.display()))?;
+    let prepared: PreparedPayload = serde_json::from_str(&raw).with_context(|| format!("parsing {}", prepared_path.display()))?;
+
+    let mut envelope = prepared.envelope;
+    envelope.signature = signature.to_string();
+
+    let verifying_key = verifying_key_from_did_key(&envelope.did).context("envelope did is not a valid did:key")?;
+    verify_envelope_signature(&verifying_key, envelope.clone()).context("completed envelope signature does not verify")?;
+
+    fs::write(out, serde_json::to_string_pretty(&envelope)?).with_context(|| format!("writing {}", out.display()))?;
+    println!("wrote verified envelope to {}", out.display());
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use clap::Subcommand;
+use openclaw_crypto::did::verifying_key_from_did_key;
+use openclaw_crypto::types::SignatureEnvelopeV1;
+use openclaw_crypto::verify::verify_envelope_signature;
+use serde::Deserialize;
+
+#[derive(Subcommand)]
+pub enum VerifyCommand {
+    /// Verify a signed envelope locally, then walk its derivation chain
+    /// against the server's lineage API, flagging any ancestor whose
+    /// signature doesn't verify or that's been revoked
+    Chain {
+        /// Signed artifact envelope to verify
+        file: PathBuf,
+        /// Base URL of the server to query for lineage
+        #[arg(long, default_value = "http://localhost:8080")]
+        server: String,
+    },
+}
+
+pub fn run(cmd: VerifyCommand) -> Result<()> {
+    match cmd {
+        VerifyCommand::Chain { file, server } => chain(&file, &server),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteArtifact {
+    id: String,
+    sha256: String,
+    signer_did: String,
+    signature_envelope: SignatureEnvelopeV1,
+}
+
+#[derive(Debug, Deserialize)]
+struct LineageNode {
+    sha256: String,
+    signer_did: String,
+    revoked: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArtifactLineage {
+    ancestors: Vec<LineageNode>,
+    ancestors_cycle_detected: bool,
+}
+
+fn chain(file: &PathBuf, server: &str) -> Result<()> {
+    let raw = fs::read_to_string(file).with_context(|| format!("reading {}", file.display()))?;
+    let envelope: SignatureEnvelopeV1 =
+        serde_json::from_str(&raw).with_context(|| format!("parsing {}", file.display()))?;
+
+    let verifying_key = verifying_key_from_did_key(&envelope.did).context("envelope did is not a valid did:key")?;
+    verify_envelope_signature(&verifying_key, envelope.clone()).context("envelope signature does not verify")?;
+    println!("local: envelope signature verified for {}", envelope.did);
+
+    let client = reqwest::blocking::Client::new();
+    let by_hash: Vec<RemoteArtifact> = client
+        .get(format!("{server}/api/v1/artifacts/by-hash/{}", envelope.hash.value))
+        .send()
+        .context("querying by-hash lookup")?
+        .error_for_status()
+        .context("by-hash lookup failed")?
+        .json()
+        .context("parsing by-hash response")?;
+    let artifact = by_hash
+        .into_iter()
+        .find(|a| a.signer_did == envelope.did)
+        .ok_or_else(|| anyhow::anyhow!("server has no artifact registered for this envelope"))?;
+
+    let lineage: ArtifactLineage = client
+        .get(format!("{server}/api/v1/artifacts/{}/lineage", artifact.id))
+        .send()
+        .context("querying lineage")?
+        .error_for_status()
+        .context("lineage lookup failed")?
+        .json()
+        .context("parsing lineage response")?;
+
+    if lineage.ancestors_cycle_detected {
+        println!("warning: derivation chain contains a cycle; walk was cut short");
+    }
+    if lineage.ancestors.is_empty() {
+        println!("no ancestors declared; chain verification complete");
+        return Ok(());
+    }
+
+    let mut problems = Vec::new();
+    for ancestor in &lineage.ancestors {
+        if ancestor.revoked {
+            problems.push(format!("{} (signed by {}) has been revoked", ancestor.sha256, ancestor.signer_did));
+            continue;
+        }
+
+        let ancestor_artifacts: Vec<RemoteArtifact> = client
+            .get(format!("{server}/api/v1/artifacts/by-hash/{}", ancestor.sha256))
+            .send()
+            .context("querying ancestor by-hash lookup")?
+            .error_for_status()
+            .context("ancestor by-hash lookup failed")?
+            .json()
+            .context("parsing ancestor by-hash response")?;
+
+        let Some(ancestor_artifact) = ancestor_artifacts.into_iter().find(|a| a.signer_did == ancestor.signer_did) else {
+            problems.push(format!("{} (signed by {}) is not resolvable via by-hash lookup", ancestor.sha256, ancestor.signer_did));
+            continue;
+        };
+
+        let Ok(ancestor_key) = verifying_key_from_did_key(&ancestor_artifact.signer_did) else {
+            problems.push(format!("{} has an invalid signer did:key", ancestor.sha256));
+            continue;
+        };
+        match verify_envelope_signature(&ancestor_key, ancestor_artifact.signature_envelope) {
+            Ok(()) => println!("ok: {} (signed by {})", ancestor.sha256, ancestor.signer_did),
+            Err(e) => problems.push(format!("{} (signed by {}) failed signature verification: {e}", ancestor.sha256, ancestor.signer_did)),
+        }
+    }
+
+    if !problems.is_empty() {
+        for problem in &problems {
+            eprintln!("problem: {problem}");
+        }
+        bail!("{} of {} ancestors failed verification", problems.len(), lineage.ancestors.len());
+    }
+
+    println!("all {} ancestors verified", lineage.ancestors.len());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anyhow::{anyhow, Result};
+use ed25519_dalek::VerifyingKey;
+
+/// Ed25519 multicodec prefix (0xed01, varint-encoded) prepended to the raw
+/// public key before base58btc-encoding, per the `did:key` method spec.
+const ED25519_MULTICODEC_PREFIX: [u8; 2] = [0xed, 0x01];
+
+/// Derives a `did:key:z...` identifier from an Ed25519 public key.
+pub fn did_key_from_verifying_key(key: &VerifyingKey) -> String {
+    let mut bytes = Vec::with_capacity(2 + 32);
+    bytes.extend_from_slice(&ED25519_MULTICODEC_PREFIX);
+    bytes.extend_from_slice(key.as_bytes());
+    format!("did:key:z{}", bs58::encode(bytes).into_string())
+}
+
+/// Recovers the Ed25519 public key backing a `did:key:z...` identifier.
+pub fn verifying_key_from_did_key(did: &str) -> Result<VerifyingKey> {
+    let encoded = did
+        .strip_prefix("did:key:z")
+        .ok_or_else(|| anyhow!("not a did:key identifier: {did}"))?;
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| anyhow!("invalid base58btc in did:key: {e}"))?;
+    if bytes.len() < 2 {
+        return Err(anyhow!("did:key too short"));
+    }
+    let (prefix, key_bytes) = bytes.split_at(2);
+    if prefix != ED25519_MULTICODEC_PREFIX {
+        return Err(anyhow!("unsupported did:key multicodec, expected ed25519"));
+    }
+    let key_bytes: [u8; 32] = key_bytes
+        .try_into()
+        .map_err(|_| anyhow!("did:key public key is not 32 bytes"))?;
+    VerifyingKey::from_bytes(&key_bytes).map_err(|e| anyhow!("invalid ed25519 public key: {e}"))
+}
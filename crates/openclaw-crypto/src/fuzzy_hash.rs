@@ -0,0 +1,64 @@
+use sha2::{Digest, Sha256};
+
+/// Width of a shingle (in bytes) hashed into the simhash. Small enough that
+/// a trivial edit (a renamed variable, a reordered import) only perturbs a
+/// handful of shingles rather than the whole fingerprint.
+const SHINGLE_LEN: usize = 8;
+
+/// A 64-bit simhash of `bytes`' overlapping `SHINGLE_LEN`-byte shingles,
+/// hex-encoded. Near-duplicate content produces fingerprints with a small
+/// Hamming distance; unrelated content is expected to differ in roughly
+/// half the bits. This is deliberately not a cryptographic hash — two
+/// different-but-similar inputs are supposed to collide in most bits.
+pub fn simhash(bytes: &[u8]) -> String {
+    let mut bit_weights = [0i64; 64];
+
+    if bytes.len() < SHINGLE_LEN {
+        for bit in 0..64 {
+            if shingle_hash(bytes) & (1u64 << bit) != 0 {
+                bit_weights[bit] += 1;
+            } else {
+                bit_weights[bit] -= 1;
+            }
+        }
+    } else {
+        for window in bytes.windows(SHINGLE_LEN) {
+            let h = shingle_hash(window);
+            for bit in 0..64 {
+                if h & (1u64 << bit) != 0 {
+                    bit_weights[bit] += 1;
+                } else {
+                    bit_weights[bit] -= 1;
+                }
+            }
+        }
+    }
+
+    let mut fingerprint: u64 = 0;
+    for (bit, weight) in bit_weights.iter().enumerate() {
+        if *weight > 0 {
+            fingerprint |= 1u64 << bit;
+        }
+    }
+    format!("{fingerprint:016x}")
+}
+
+fn shingle_hash(shingle: &[u8]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(shingle);
+    let digest = hasher.finalize();
+    u64::from_be_bytes(digest[..8].try_into().expect("sha256 digest is at least 8 bytes"))
+}
+
+/// Similarity of two hex-encoded simhashes from `simhash`, as the fraction
+/// of their 64 bits that agree (1.0 = identical, 0.5 ~= unrelated).
+/// Returns `None` if either isn't a valid 16-hex-digit fingerprint.
+pub fn similarity(a: &str, b: &str) -> Option<f64> {
+    if a.len() != 16 || b.len() != 16 {
+        return None;
+    }
+    let a = u64::from_str_radix(a, 16).ok()?;
+    let b = u64::from_str_radix(b, 16).ok()?;
+    let agreeing_bits = 64 - (a ^ b).count_ones();
+    Some(agreeing_bits as f64 / 64.0)
+}
@@ -0,0 +1,7 @@
+use anyhow::Result;
+
+/// RFC 8785 canonical JSON bytes, so signatures are reproducible regardless
+/// of field order or whitespace introduced along the way.
+pub fn jcs_canonical_bytes<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+    Ok(serde_jcs::to_string(value)?.into_bytes())
+}
@@ -0,0 +1,10 @@
+pub mod did;
+pub mod fuzzy_hash;
+pub mod hash;
+pub mod jcs;
+pub mod sign;
+pub mod types;
+pub mod verify;
+
+pub use did::{did_key_from_verifying_key, verifying_key_from_did_key};
+pub use types::{ArtifactInfo, HashRef, ManifestV1, SignatureEnvelopeV1};
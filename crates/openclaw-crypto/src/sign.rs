@@ -0,0 +1,52 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::Value;
+
+use crate::did::did_key_from_verifying_key;
+use crate::hash::sha256_hex;
+use crate::jcs::jcs_canonical_bytes;
+use crate::types::{ArtifactInfo, HashRef, SignatureEnvelopeV1};
+
+pub fn sign_artifact(
+    signing_key: &SigningKey,
+    filename: &str,
+    file_bytes: &[u8],
+    created_at: &str,
+    metadata: Value,
+) -> Result<SignatureEnvelopeV1> {
+    let hash_hex = sha256_hex(file_bytes);
+    let did = did_key_from_verifying_key(&signing_key.verifying_key());
+
+    let mut env = SignatureEnvelopeV1 {
+        version: "m1".to_string(),
+        r#type: "artifact_signature".to_string(),
+        did,
+        algo: "ed25519".to_string(),
+        hash: HashRef {
+            algo: "sha256".to_string(),
+            value: hash_hex,
+        },
+        created_at: created_at.to_string(),
+        artifact: ArtifactInfo {
+            name: filename.to_string(),
+            size: file_bytes.len() as u64,
+        },
+        metadata,
+        signature: String::new(),
+    };
+
+    let canonical = jcs_canonical_bytes(&env)?;
+    let signature = signing_key.sign(&canonical);
+    env.signature = B64.encode(signature.to_bytes());
+    Ok(env)
+}
+
+/// Signs arbitrary JCS-canonicalizable data, producing just the base64
+/// signature. Used by callers (e.g. reserve attestations) that want the raw
+/// signature over their own envelope shape rather than `SignatureEnvelopeV1`.
+pub fn sign_canonical<T: serde::Serialize>(signing_key: &SigningKey, value: &T) -> Result<String> {
+    let canonical = jcs_canonical_bytes(value)?;
+    let signature = signing_key.sign(&canonical);
+    Ok(B64.encode(signature.to_bytes()))
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct HashRef {
+    pub algo: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ArtifactInfo {
+    pub name: String,
+    pub size: u64,
+}
+
+/// A signed, JCS-canonicalized statement about an artifact. Reserve
+/// attestations in `openclaw-server` use their own shape instead, signed
+/// with `sign::sign_canonical`.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SignatureEnvelopeV1 {
+    pub version: String,
+    pub r#type: String,
+    pub did: String,
+    pub algo: String,
+    pub hash: HashRef,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub artifact: ArtifactInfo,
+    pub metadata: serde_json::Value,
+    pub signature: String,
+}
+
+/// A signed bundle of artifact signatures — "here is everything I
+/// contributed, signed once as a set" rather than one envelope at a time.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ManifestV1 {
+    pub version: String,
+    pub r#type: String,
+    pub did: String,
+    pub algo: String,
+    pub artifacts: Vec<SignatureEnvelopeV1>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub metadata: serde_json::Value,
+    pub signature: String,
+}
@@ -0,0 +1,82 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+use crate::hash::sha256_hex;
+use crate::jcs::jcs_canonical_bytes;
+use crate::types::{ManifestV1, SignatureEnvelopeV1};
+
+pub fn verify_artifact(verifying_key: &VerifyingKey, file_bytes: &[u8], mut env: SignatureEnvelopeV1) -> Result<()> {
+    if env.version != "m1" || env.r#type != "artifact_signature" || env.algo != "ed25519" {
+        return Err(anyhow!("unsupported envelope type/version/algo"));
+    }
+    if env.hash.algo != "sha256" {
+        return Err(anyhow!("unsupported hash algo"));
+    }
+
+    let hash_hex = sha256_hex(file_bytes);
+    if hash_hex != env.hash.value {
+        return Err(anyhow!("hash mismatch"));
+    }
+
+    let signature_bytes = B64.decode(env.signature.as_bytes())?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|_| anyhow!("invalid signature bytes"))?;
+
+    env.signature = String::new();
+    let canonical = jcs_canonical_bytes(&env)?;
+
+    verifying_key
+        .verify(&canonical, &signature)
+        .map_err(|_| anyhow!("signature mismatch"))
+}
+
+/// Verifies an envelope's signature against the DID it claims, without
+/// re-hashing any file content. Use this when a caller registers an
+/// already-signed envelope out of band (no raw artifact bytes on hand to
+/// recompute `hash` from) rather than signing and verifying in one request.
+pub fn verify_envelope_signature(verifying_key: &VerifyingKey, mut env: SignatureEnvelopeV1) -> Result<()> {
+    if env.version != "m1" || env.algo != "ed25519" {
+        return Err(anyhow!("unsupported envelope version/algo"));
+    }
+
+    let signature_bytes = B64.decode(env.signature.as_bytes())?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|_| anyhow!("invalid signature bytes"))?;
+
+    env.signature = String::new();
+    let canonical = jcs_canonical_bytes(&env)?;
+
+    verifying_key
+        .verify(&canonical, &signature)
+        .map_err(|_| anyhow!("signature mismatch"))
+}
+
+/// Verifies a manifest's outer signature against the DID it claims. Does
+/// not re-verify the nested artifact envelopes' own signatures — callers
+/// should run `verify_envelope_signature` over each one too.
+pub fn verify_manifest(verifying_key: &VerifyingKey, mut manifest: ManifestV1) -> Result<()> {
+    if manifest.version != "m1" || manifest.r#type != "contribution_manifest" || manifest.algo != "ed25519" {
+        return Err(anyhow!("unsupported manifest version/type/algo"));
+    }
+
+    let signature_bytes = B64.decode(manifest.signature.as_bytes())?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|_| anyhow!("invalid signature bytes"))?;
+
+    manifest.signature = String::new();
+    let canonical = jcs_canonical_bytes(&manifest)?;
+
+    verifying_key
+        .verify(&canonical, &signature)
+        .map_err(|_| anyhow!("signature mismatch"))
+}
+
+/// Verifies a base64 signature over arbitrary JCS-canonicalizable data, for
+/// callers that signed with `sign::sign_canonical` rather than the full
+/// `SignatureEnvelopeV1` shape.
+pub fn verify_canonical<T: serde::Serialize>(verifying_key: &VerifyingKey, value: &T, signature_b64: &str) -> Result<()> {
+    let signature_bytes = B64.decode(signature_b64.as_bytes())?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|_| anyhow!("invalid signature bytes"))?;
+    let canonical = jcs_canonical_bytes(value)?;
+    verifying_key
+        .verify(&canonical, &signature)
+        .map_err(|_| anyhow!("signature mismatch"))
+}
@@ -0,0 +1,59 @@
+//! Node.js bindings for `openclaw-crypto`, so JS backends and Electron apps
+//! can parse `did:key` identifiers and verify Protocol M signatures with the
+//! same canonical implementation the server uses, instead of reimplementing
+//! JCS canonicalization and ed25519 verification against the wire format.
+
+#![deny(clippy::all)]
+
+use ed25519_dalek::VerifyingKey;
+use napi::bindgen_prelude::Buffer;
+use napi_derive::napi;
+use openclaw_crypto::types::{ManifestV1, SignatureEnvelopeV1};
+use openclaw_crypto::{did, verify};
+
+fn napi_err(e: anyhow::Error) -> napi::Error {
+    napi::Error::from_reason(e.to_string())
+}
+
+/// Derives a `did:key:z...` identifier from a raw 32-byte Ed25519 public key.
+#[napi]
+pub fn did_key_from_public_key(public_key: Buffer) -> napi::Result<String> {
+    let bytes: [u8; 32] = public_key
+        .as_ref()
+        .try_into()
+        .map_err(|_| napi::Error::from_reason("public key must be exactly 32 bytes"))?;
+    let key = VerifyingKey::from_bytes(&bytes).map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(did::did_key_from_verifying_key(&key))
+}
+
+/// Recovers the raw 32-byte Ed25519 public key backing a `did:key:z...`
+/// identifier. Throws if the DID isn't a valid ed25519 `did:key`.
+#[napi]
+pub fn public_key_from_did_key(did: String) -> napi::Result<Buffer> {
+    let key = did::verifying_key_from_did_key(&did).map_err(napi_err)?;
+    Ok(Buffer::from(key.as_bytes().to_vec()))
+}
+
+/// Verifies a `SignatureEnvelopeV1` (as JSON) against the DID it claims,
+/// without re-hashing any file content. Returns `true`/`false` rather than
+/// throwing on a bad signature, since a failed verification is an expected
+/// outcome for a caller, not a usage error; malformed JSON or an invalid DID
+/// still throw.
+#[napi]
+pub fn verify_envelope_signature(did: String, envelope_json: String) -> napi::Result<bool> {
+    let envelope: SignatureEnvelopeV1 =
+        serde_json::from_str(&envelope_json).map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    let key = did::verifying_key_from_did_key(&did).map_err(napi_err)?;
+    Ok(verify::verify_envelope_signature(&key, envelope).is_ok())
+}
+
+/// Verifies a `ManifestV1`'s outer signature (as JSON) against the DID it
+/// claims. Does not re-verify the nested artifact envelopes' own
+/// signatures — call `verifyEnvelopeSignature` over each one too.
+#[napi]
+pub fn verify_manifest_signature(did: String, manifest_json: String) -> napi::Result<bool> {
+    let manifest: ManifestV1 =
+        serde_json::from_str(&manifest_json).map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    let key = did::verifying_key_from_did_key(&did).map_err(napi_err)?;
+    Ok(verify::verify_manifest(&key, manifest).is_ok())
+}
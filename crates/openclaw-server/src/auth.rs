@@ -0,0 +1,29 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use async_trait::async_trait;
+
+/// The caller's DID, taken from the `X-Protocol-M-Did` header.
+///
+/// This is a placeholder for the eventual signature-verified identity
+/// (see the DID binding challenge-response flow); callers are trusted for
+/// now, so handlers that move funds or change ownership must still check
+/// the DID against the resource they're acting on.
+pub struct AuthenticatedDid(pub String);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthenticatedDid
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get("x-protocol-m-did")
+            .and_then(|v| v.to_str().ok())
+            .map(|did| AuthenticatedDid(did.to_string()))
+            .ok_or((StatusCode::UNAUTHORIZED, "missing X-Protocol-M-Did header"))
+    }
+}
@@ -0,0 +1,181 @@
+//! Runtime-tunable parameters. Loaded once at startup from an optional
+//! `CONFIG_FILE` (JSON) layered under individual env var overrides, falling
+//! back to the defaults below, and validated before the server accepts
+//! traffic — a bad value fails fast at startup instead of surfacing as a
+//! confusing downstream error later.
+
+use std::sync::OnceLock;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RuntimeConfig {
+    /// How many $SPORE credits one US dollar buys.
+    pub credits_per_usd: Decimal,
+    pub min_bounty_reward: Decimal,
+    pub max_bounty_reward: Decimal,
+    pub promo_credit_cap: Decimal,
+    pub dispute_window_hours: i64,
+    /// Max purchases a single DID may place within a rolling hour/day
+    /// before `services::fraud` routes further ones to manual review.
+    pub max_purchases_per_hour: i64,
+    pub max_purchases_per_day: i64,
+    /// Same, but keyed by payment-instrument fingerprint instead of DID,
+    /// to catch one card spread across many accounts.
+    pub max_purchases_per_hour_per_card: i64,
+    /// Purchases/redemptions at or above this many credits require a
+    /// `Verified` `services::kyc` status for the credited DID.
+    pub kyc_verification_threshold: Decimal,
+    /// Max API requests a single DID may make in a rolling 24h window
+    /// before `services::usage` rejects further ones. `None` (the
+    /// default) means unlimited, since most deployments run single-tenant.
+    pub max_api_requests_per_day: Option<i64>,
+    /// Percentage (0-100) of a bounty reward split among the direct
+    /// parent artifacts' signers when the winning submission's artifact
+    /// declares a derivation. Zero (the default) pays the full reward to
+    /// the submitter, matching behavior before `services::escrow` knew
+    /// about derivation at all.
+    pub royalty_split_percent: Decimal,
+    /// Percentage (0-100) of every bounty payout and redemption routed to
+    /// `platform_treasury_did` instead of the payee, via `services::fees`.
+    /// Zero (the default) takes no fee.
+    pub platform_fee_percent: Decimal,
+    /// The pseudo-account `services::fees` credits with collected fees.
+    /// Not a real `did:key` — just a ledger/account key, the same way
+    /// `"system"` is used as an audit actor for non-DID-initiated events.
+    pub platform_treasury_did: String,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            credits_per_usd: dec!(100),
+            min_bounty_reward: dec!(1),
+            max_bounty_reward: dec!(1_000_000),
+            promo_credit_cap: dec!(10_000),
+            dispute_window_hours: 72,
+            max_purchases_per_hour: 5,
+            max_purchases_per_day: 20,
+            max_purchases_per_hour_per_card: 3,
+            kyc_verification_threshold: dec!(50_000),
+            max_api_requests_per_day: None,
+            royalty_split_percent: Decimal::ZERO,
+            platform_fee_percent: Decimal::ZERO,
+            platform_treasury_did: "platform:treasury".to_string(),
+        }
+    }
+}
+
+impl RuntimeConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.credits_per_usd <= Decimal::ZERO {
+            return Err("credits_per_usd must be positive".into());
+        }
+        if self.min_bounty_reward <= Decimal::ZERO {
+            return Err("min_bounty_reward must be positive".into());
+        }
+        if self.max_bounty_reward < self.min_bounty_reward {
+            return Err("max_bounty_reward must be >= min_bounty_reward".into());
+        }
+        if self.promo_credit_cap < Decimal::ZERO {
+            return Err("promo_credit_cap must be non-negative".into());
+        }
+        if self.dispute_window_hours <= 0 {
+            return Err("dispute_window_hours must be positive".into());
+        }
+        if self.max_purchases_per_hour <= 0 {
+            return Err("max_purchases_per_hour must be positive".into());
+        }
+        if self.max_purchases_per_day < self.max_purchases_per_hour {
+            return Err("max_purchases_per_day must be >= max_purchases_per_hour".into());
+        }
+        if self.max_purchases_per_hour_per_card <= 0 {
+            return Err("max_purchases_per_hour_per_card must be positive".into());
+        }
+        if self.kyc_verification_threshold <= Decimal::ZERO {
+            return Err("kyc_verification_threshold must be positive".into());
+        }
+        if matches!(self.max_api_requests_per_day, Some(n) if n <= 0) {
+            return Err("max_api_requests_per_day must be positive when set".into());
+        }
+        if self.royalty_split_percent < Decimal::ZERO || self.royalty_split_percent > dec!(100) {
+            return Err("royalty_split_percent must be between 0 and 100".into());
+        }
+        if self.platform_fee_percent < Decimal::ZERO || self.platform_fee_percent > dec!(100) {
+            return Err("platform_fee_percent must be between 0 and 100".into());
+        }
+        if self.platform_treasury_did.trim().is_empty() {
+            return Err("platform_treasury_did must not be empty".into());
+        }
+        Ok(())
+    }
+}
+
+static CONFIG: OnceLock<RuntimeConfig> = OnceLock::new();
+
+/// Loads and validates the runtime config. Idempotent; later calls return
+/// the config from the first call.
+pub fn init() -> &'static RuntimeConfig {
+    CONFIG.get_or_init(|| {
+        let mut cfg = RuntimeConfig::default();
+
+        if let Ok(path) = std::env::var("CONFIG_FILE") {
+            let contents = std::fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("failed to read CONFIG_FILE {path}: {e}"));
+            cfg = serde_json::from_str(&contents)
+                .unwrap_or_else(|e| panic!("failed to parse CONFIG_FILE {path}: {e}"));
+        }
+
+        if let Ok(v) = std::env::var("CREDITS_PER_USD") {
+            cfg.credits_per_usd = v.parse().expect("CREDITS_PER_USD must be a decimal");
+        }
+        if let Ok(v) = std::env::var("MIN_BOUNTY_REWARD") {
+            cfg.min_bounty_reward = v.parse().expect("MIN_BOUNTY_REWARD must be a decimal");
+        }
+        if let Ok(v) = std::env::var("MAX_BOUNTY_REWARD") {
+            cfg.max_bounty_reward = v.parse().expect("MAX_BOUNTY_REWARD must be a decimal");
+        }
+        if let Ok(v) = std::env::var("PROMO_CREDIT_CAP") {
+            cfg.promo_credit_cap = v.parse().expect("PROMO_CREDIT_CAP must be a decimal");
+        }
+        if let Ok(v) = std::env::var("DISPUTE_WINDOW_HOURS") {
+            cfg.dispute_window_hours = v.parse().expect("DISPUTE_WINDOW_HOURS must be an integer");
+        }
+        if let Ok(v) = std::env::var("MAX_PURCHASES_PER_HOUR") {
+            cfg.max_purchases_per_hour = v.parse().expect("MAX_PURCHASES_PER_HOUR must be an integer");
+        }
+        if let Ok(v) = std::env::var("MAX_PURCHASES_PER_DAY") {
+            cfg.max_purchases_per_day = v.parse().expect("MAX_PURCHASES_PER_DAY must be an integer");
+        }
+        if let Ok(v) = std::env::var("MAX_PURCHASES_PER_HOUR_PER_CARD") {
+            cfg.max_purchases_per_hour_per_card = v.parse().expect("MAX_PURCHASES_PER_HOUR_PER_CARD must be an integer");
+        }
+        if let Ok(v) = std::env::var("KYC_VERIFICATION_THRESHOLD") {
+            cfg.kyc_verification_threshold = v.parse().expect("KYC_VERIFICATION_THRESHOLD must be a decimal");
+        }
+        if let Ok(v) = std::env::var("MAX_API_REQUESTS_PER_DAY") {
+            cfg.max_api_requests_per_day = Some(v.parse().expect("MAX_API_REQUESTS_PER_DAY must be an integer"));
+        }
+        if let Ok(v) = std::env::var("ROYALTY_SPLIT_PERCENT") {
+            cfg.royalty_split_percent = v.parse().expect("ROYALTY_SPLIT_PERCENT must be a decimal");
+        }
+        if let Ok(v) = std::env::var("PLATFORM_FEE_PERCENT") {
+            cfg.platform_fee_percent = v.parse().expect("PLATFORM_FEE_PERCENT must be a decimal");
+        }
+        if let Ok(v) = std::env::var("PLATFORM_TREASURY_DID") {
+            cfg.platform_treasury_did = v;
+        }
+
+        cfg.validate().expect("invalid runtime configuration");
+        tracing::info!(?cfg, "runtime configuration loaded");
+        cfg
+    })
+}
+
+/// Returns the effective runtime config. Panics if `init` hasn't run yet;
+/// `main` calls it before the router is built.
+pub fn current() -> &'static RuntimeConfig {
+    CONFIG.get().expect("config::init was not called")
+}
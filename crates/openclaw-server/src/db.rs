@@ -0,0 +1,59 @@
+use std::sync::OnceLock;
+
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+
+/// Shared connection pool handed to every route via axum state.
+///
+/// This is `PgPool`, not `sqlx::AnyPool`, on purpose: routes and services
+/// lean on Postgres-only SQL throughout (`ANY(approvers)` over array
+/// columns, `::uuid`/`::text` casts in filter predicates, `RETURNING`,
+/// `ON CONFLICT ... DO UPDATE`, JSONB columns, enum columns bound via
+/// `sqlx::Type`). Swapping in a SQLite backend isn't a `db.rs`-local
+/// change — it would mean auditing and likely rewriting every query in
+/// `routes/` and `services/` (several dozen call sites) to a dialect both
+/// engines support, which is a project of its own rather than something
+/// to land alongside other work. `connect` rejects non-Postgres URLs
+/// explicitly instead of silently misbehaving against them.
+pub type Db = PgPool;
+
+pub async fn connect(database_url: &str) -> Result<Db, sqlx::Error> {
+    if !database_url.starts_with("postgres://") && !database_url.starts_with("postgresql://") {
+        return Err(sqlx::Error::Configuration(
+            "only postgres:// DATABASE_URLs are supported; see the doc comment on db::Db for why".into(),
+        ));
+    }
+
+    PgPoolOptions::new()
+        .max_connections(10)
+        .connect(database_url)
+        .await
+}
+
+static REPLICA: OnceLock<PgPool> = OnceLock::new();
+
+/// Connects the read-replica pool used by `replica()`. Reads
+/// `DATABASE_URL_REPLICA`; if it's unset, routes that would use the
+/// replica just reuse `write` (a `PgPool` clone is cheap — it's a handle
+/// to the same underlying connection set) so self-hosted deployments
+/// without a replica still work unchanged. Idempotent; later calls are
+/// no-ops.
+pub async fn init_replica(write: &Db) -> Result<(), sqlx::Error> {
+    if REPLICA.get().is_some() {
+        return Ok(());
+    }
+    let pool = match std::env::var("DATABASE_URL_REPLICA") {
+        Ok(url) => connect(&url).await?,
+        Err(_) => write.clone(),
+    };
+    let _ = REPLICA.set(pool);
+    Ok(())
+}
+
+/// The pool heavy list/lineage/reporting queries should read from, so
+/// marketplace browsing doesn't contend with escrow writes on the primary.
+/// Panics if `init_replica` hasn't run yet; `main` calls it right after
+/// `connect`.
+pub fn replica() -> &'static PgPool {
+    REPLICA.get().expect("db::init_replica was not called")
+}
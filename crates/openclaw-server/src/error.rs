@@ -0,0 +1,95 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The stable, machine-readable code every error response carries in
+/// `code`, so clients can branch on it instead of regexing `error`.
+/// `BadRequest`/`Forbidden` are the generic fallback for call sites that
+/// haven't been given a more specific code yet — new checks with a
+/// meaningful failure mode (a balance check, a window that's closed)
+/// should use [`AppError::domain`] with a dedicated variant instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    NotFound,
+    BadRequest,
+    Forbidden,
+    InsufficientBalance,
+    DisputeWindowClosed,
+    Internal,
+}
+
+/// Errors surfaced by route handlers as JSON responses.
+///
+/// Variants map to a fixed HTTP status; add new variants here rather than
+/// returning raw strings from handlers. `NotFound` -> 404, `BadRequest`/
+/// `Domain` -> 400, `Forbidden` -> 403, `Database`/`Internal` -> 500.
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("not found")]
+    NotFound,
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    /// A `BadRequest` with a specific `ErrorCode` and optional structured
+    /// `details` a client can act on programmatically (e.g. the balance
+    /// and amount requested for `InsufficientBalance`).
+    #[error("{message}")]
+    Domain {
+        code: ErrorCode,
+        message: String,
+        details: Option<Value>,
+    },
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl AppError {
+    pub fn domain(code: ErrorCode, message: impl Into<String>) -> Self {
+        AppError::Domain { code, message: message.into(), details: None }
+    }
+
+    pub fn domain_with_details(code: ErrorCode, message: impl Into<String>, details: Value) -> Self {
+        AppError::Domain { code, message: message.into(), details: Some(details) }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    code: ErrorCode,
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<Value>,
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Domain { .. } => StatusCode::BAD_REQUEST,
+            AppError::Database(_) | AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let code = match &self {
+            AppError::NotFound => ErrorCode::NotFound,
+            AppError::BadRequest(_) => ErrorCode::BadRequest,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::Domain { code, .. } => *code,
+            AppError::Database(_) | AppError::Internal(_) => ErrorCode::Internal,
+        };
+        let details = match &self {
+            AppError::Domain { details, .. } => details.clone(),
+            _ => None,
+        };
+        let error = self.to_string();
+        (status, Json(ErrorBody { code, error, details })).into_response()
+    }
+}
+
+pub type AppResult<T> = Result<T, AppError>;
@@ -0,0 +1,181 @@
+//! A tonic gRPC server for agent fleets that poll bounties and submit work
+//! often enough that JSON-over-HTTP overhead matters. Every RPC delegates
+//! to the exact service/route functions the REST API uses
+//! (`services::artifacts::register`, `routes::bounties::list`/`create`,
+//! `routes::submissions::create`), so escrow, validation, and audit
+//! logging behave identically regardless of transport.
+//!
+//! Building this needs `tonic` and `prost` as dependencies; `build.rs`
+//! runs `tonic_build::compile_protos("proto/protocol_m.proto")` and
+//! `generated` below pulls the result in with
+//! `tonic::include_proto!("protocol_m")`.
+//!
+//! `main.rs` runs this alongside the axum server with
+//! `tokio::try_join!(axum::serve(...), grpc::serve(...))` rather than as a
+//! separate binary, so both transports share one process and one `Db` pool.
+
+use std::net::SocketAddr;
+
+use tonic::{Request, Response, Status};
+
+use crate::db::Db;
+use crate::error::AppError;
+
+mod generated {
+    tonic::include_proto!("protocol_m");
+}
+
+pub use generated::agent_service_server::{AgentService, AgentServiceServer};
+pub use generated::{
+    Artifact, Bounty, PollBountiesRequest, PollBountiesResponse, RegisterArtifactRequest,
+    SignatureEnvelope, Submission, SubmitWorkRequest,
+};
+
+pub struct AgentServiceImpl {
+    db: Db,
+}
+
+impl AgentServiceImpl {
+    pub fn new(db: Db) -> Self {
+        Self { db }
+    }
+
+    pub fn into_server(self) -> AgentServiceServer<Self> {
+        AgentServiceServer::new(self)
+    }
+}
+
+/// Maps the errors every handler below can bubble up to gRPC status codes,
+/// mirroring how `error.rs` maps the same `AppError` variants to HTTP
+/// status codes for the REST routes.
+fn status_for(err: AppError) -> Status {
+    match err {
+        AppError::NotFound => Status::not_found("not found"),
+        AppError::BadRequest(msg) => Status::invalid_argument(msg),
+        AppError::Domain { message, .. } => Status::invalid_argument(message),
+        AppError::Forbidden(msg) => Status::permission_denied(msg),
+        AppError::Database(e) => Status::internal(e.to_string()),
+        AppError::Internal(e) => Status::internal(e.to_string()),
+    }
+}
+
+fn envelope_from_proto(envelope: SignatureEnvelope) -> Result<openclaw_crypto::types::SignatureEnvelopeV1, Status> {
+    let metadata = serde_json::from_str(&envelope.metadata_json)
+        .map_err(|e| Status::invalid_argument(format!("metadata_json is not valid JSON: {e}")))?;
+    Ok(openclaw_crypto::types::SignatureEnvelopeV1 {
+        version: envelope.version,
+        r#type: envelope.r#type,
+        did: envelope.did,
+        algo: envelope.algo,
+        hash: openclaw_crypto::types::HashRef {
+            algo: envelope.hash_algo,
+            value: envelope.hash_value,
+        },
+        created_at: envelope.created_at,
+        artifact: openclaw_crypto::types::ArtifactInfo {
+            name: envelope.artifact_name,
+            size: envelope.artifact_size,
+        },
+        metadata,
+        signature: envelope.signature,
+    })
+}
+
+fn bounty_to_proto(b: crate::models::Bounty) -> Bounty {
+    Bounty {
+        id: b.id.to_string(),
+        poster_did: b.poster_did,
+        title: b.title,
+        description: b.description,
+        reward: b.reward.to_string(),
+        status: format!("{:?}", b.status),
+        created_at: b.created_at.to_rfc3339(),
+    }
+}
+
+#[tonic::async_trait]
+impl AgentService for AgentServiceImpl {
+    async fn register_artifact(&self, request: Request<RegisterArtifactRequest>) -> Result<Response<Artifact>, Status> {
+        let req = request.into_inner();
+        let envelope = req
+            .envelope
+            .ok_or_else(|| Status::invalid_argument("envelope is required"))?;
+        let envelope = envelope_from_proto(envelope)?;
+
+        let artifact = crate::services::artifacts::register(&self.db, envelope, req.derived_from)
+            .await
+            .map_err(status_for)?;
+
+        Ok(Response::new(Artifact {
+            id: artifact.id.to_string(),
+            sha256: artifact.sha256,
+            signer_did: artifact.signer_did,
+            registered_at: artifact.registered_at.to_rfc3339(),
+        }))
+    }
+
+    async fn poll_bounties(&self, request: Request<PollBountiesRequest>) -> Result<Response<PollBountiesResponse>, Status> {
+        let req = request.into_inner();
+        let cursor = if req.cursor.is_empty() {
+            None
+        } else {
+            Some(crate::pagination::decode_cursor(&req.cursor).map_err(status_for)?)
+        };
+        let limit = if req.limit <= 0 { 50 } else { req.limit.clamp(1, 500) };
+
+        let bounties = crate::routes::bounties::list(&self.db, cursor, limit, &req.tags)
+            .await
+            .map_err(status_for)?;
+
+        let next_cursor = bounties.last().map(|b| crate::pagination::encode_cursor(b.id)).unwrap_or_default();
+        Ok(Response::new(PollBountiesResponse {
+            bounties: bounties.into_iter().map(bounty_to_proto).collect(),
+            next_cursor,
+        }))
+    }
+
+    async fn submit_work(&self, request: Request<SubmitWorkRequest>) -> Result<Response<Submission>, Status> {
+        let req = request.into_inner();
+        let bounty_id = req
+            .bounty_id
+            .parse()
+            .map_err(|e| Status::invalid_argument(format!("invalid bounty_id: {e}")))?;
+        let artifact_id = if req.artifact_id.is_empty() {
+            None
+        } else {
+            Some(
+                req.artifact_id
+                    .parse()
+                    .map_err(|e| Status::invalid_argument(format!("invalid artifact_id: {e}")))?,
+            )
+        };
+
+        let submission = crate::routes::submissions::create(
+            &self.db,
+            bounty_id,
+            &req.submitter_did,
+            crate::routes::submissions::CreateSubmissionRequest { bounty_id, artifact_id },
+        )
+        .await
+        .map_err(status_for)?;
+
+        Ok(Response::new(Submission {
+            id: submission.id.to_string(),
+            bounty_id: submission.bounty_id.to_string(),
+            submitter_did: submission.submitter_did,
+            status: format!("{:?}", submission.status),
+            created_at: submission.created_at.to_rfc3339(),
+        }))
+    }
+}
+
+/// Serves `AgentService` on `addr` until the process is killed. Meant to
+/// run as a sibling task to `axum::serve` in `main.rs`, not a standalone
+/// binary.
+pub async fn serve(db: Db, addr: SocketAddr) -> anyhow::Result<()> {
+    tonic::transport::Server::builder()
+        .add_service(AgentServiceImpl::new(db).into_server())
+        .serve(addr)
+        .await?;
+    Ok(())
+}
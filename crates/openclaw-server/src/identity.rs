@@ -0,0 +1,246 @@
+//! The server's own signing identity, used to sign things it attests to
+//! (reserve attestations, execution receipts, ...) so third parties can
+//! verify them offline with nothing but the DID published at
+//! `/.well-known/did.json`. Supports scheduled rotation with an overlap
+//! window (see `rotate`): an outgoing key stays verifiable for a while
+//! after a new key takes over signing, so an attestation signed just
+//! before a rotation doesn't suddenly look forged to a verifier that
+//! fetched the DID document a minute late.
+//!
+//! Every generation is persisted to `server_signing_keys` (see `init` and
+//! `rotate`), not just kept in the in-process `KEY_RING` — a restart that
+//! only remembered `SERVER_SIGNING_KEY` would silently revert to the
+//! original pinned key and forget every key rotated in since, including
+//! ones still inside their overlap window with signatures circulating.
+
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use ed25519_dalek::SigningKey;
+use openclaw_crypto::did_key_from_verifying_key;
+use openclaw_crypto::hash::sha256_hex;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::ServerSigningKey;
+
+/// How long a retired signing key stays verifiable (via `find` and
+/// `/.well-known/did.json`) after a new one takes over signing, so an
+/// attestation signed right before a rotation stays verifiable for a day
+/// afterwards. Shorter than `jobs::key_rotation`'s weekly interval, so at
+/// most one retired key is inside its overlap window at a time.
+pub const ROTATION_OVERLAP: chrono::Duration = chrono::Duration::hours(24);
+
+/// One generation of the server's signing key. `key_id` is a short,
+/// deterministic fingerprint (not a secret) included in every signed
+/// attestation so a verifier — or an operator reading logs — can tell
+/// which generation produced a given signature without decoding the DID.
+#[derive(Clone)]
+pub struct SigningKeyEntry {
+    pub key_id: String,
+    pub signing_key: SigningKey,
+    pub did: String,
+    pub activated_at: DateTime<Utc>,
+    /// Set once this key has been rotated out. Still returned by `find`
+    /// until it falls outside its overlap window.
+    pub retired_at: Option<DateTime<Utc>>,
+}
+
+impl SigningKeyEntry {
+    fn fresh(signing_key: SigningKey, activated_at: DateTime<Utc>) -> Self {
+        let did = did_key_from_verifying_key(&signing_key.verifying_key());
+        let key_id = sha256_hex(did.as_bytes())[..16].to_string();
+        Self { key_id, signing_key, did, activated_at, retired_at: None }
+    }
+
+    fn seed_hex(&self) -> String {
+        hex::encode(self.signing_key.to_bytes())
+    }
+
+    /// Reconstructs an entry from a `server_signing_keys` row. Panics on a
+    /// malformed `seed_hex` — that column is only ever written by `init`/
+    /// `rotate` below, so corruption here means the database was edited by
+    /// hand, not a reachable runtime condition.
+    fn from_row(row: &ServerSigningKey) -> Self {
+        let seed_bytes = hex::decode(&row.seed_hex).expect("server_signing_keys.seed_hex must be hex-encoded");
+        let seed: [u8; 32] = seed_bytes
+            .try_into()
+            .expect("server_signing_keys.seed_hex must decode to 32 bytes");
+        Self {
+            key_id: row.key_id.clone(),
+            signing_key: SigningKey::from_bytes(&seed),
+            did: row.did.clone(),
+            activated_at: row.activated_at,
+            retired_at: row.retired_at,
+        }
+    }
+}
+
+struct KeyRing {
+    current: SigningKeyEntry,
+    /// Retired keys still inside their rotation overlap window, most
+    /// recently retired first.
+    previous: Vec<SigningKeyEntry>,
+}
+
+static KEY_RING: OnceLock<RwLock<KeyRing>> = OnceLock::new();
+
+/// Loads the initial signing key from `SERVER_SIGNING_KEY` (a hex-encoded
+/// 32-byte Ed25519 seed) — an env var today, the obvious place to swap in a
+/// KMS-backed fetch later without touching callers — or generates a fresh
+/// one. Only consulted by `init` when `server_signing_keys` is empty (first
+/// boot ever); once a key has been persisted, it and every key rotated in
+/// after it take priority over this env var.
+fn load_key_from_env() -> SigningKey {
+    match std::env::var("SERVER_SIGNING_KEY") {
+        Ok(hex_seed) => {
+            let seed_bytes = hex::decode(hex_seed.trim()).expect("SERVER_SIGNING_KEY must be hex-encoded");
+            let seed: [u8; 32] = seed_bytes
+                .try_into()
+                .expect("SERVER_SIGNING_KEY must decode to 32 bytes");
+            SigningKey::from_bytes(&seed)
+        }
+        Err(_) => {
+            tracing::warn!(
+                "SERVER_SIGNING_KEY not set; generating an ephemeral signing identity for this process"
+            );
+            SigningKey::generate(&mut rand::rngs::OsRng)
+        }
+    }
+}
+
+/// Initializes the key ring, preferring whatever `server_signing_keys` has
+/// on record over `SERVER_SIGNING_KEY` so a restart after `rotate` doesn't
+/// revert to the original pinned key and forget every key rotated in
+/// since. Only an empty table (first boot ever) falls back to
+/// `SERVER_SIGNING_KEY`, and persists the result as the first row so
+/// subsequent restarts load it from here too. Idempotent; `main` calls it
+/// once, right after connecting to the database, before the router is
+/// built.
+pub async fn init(db: &PgPool) -> Result<(), sqlx::Error> {
+    if KEY_RING.get().is_some() {
+        return Ok(());
+    }
+
+    let rows = sqlx::query_as::<_, ServerSigningKey>(
+        "SELECT * FROM server_signing_keys
+         WHERE retired_at IS NULL OR retired_at > now() - $1
+         ORDER BY activated_at DESC",
+    )
+    .bind(ROTATION_OVERLAP)
+    .fetch_all(db)
+    .await?;
+
+    let ring = if rows.is_empty() {
+        let entry = SigningKeyEntry::fresh(load_key_from_env(), Utc::now());
+        sqlx::query(
+            "INSERT INTO server_signing_keys (id, key_id, seed_hex, did, activated_at, retired_at)
+             VALUES ($1, $2, $3, $4, $5, NULL)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(&entry.key_id)
+        .bind(entry.seed_hex())
+        .bind(&entry.did)
+        .bind(entry.activated_at)
+        .execute(db)
+        .await?;
+
+        KeyRing { current: entry, previous: Vec::new() }
+    } else {
+        let mut entries: Vec<SigningKeyEntry> = rows.iter().map(SigningKeyEntry::from_row).collect();
+        let current_index = entries
+            .iter()
+            .position(|e| e.retired_at.is_none())
+            .expect("server_signing_keys has no current (non-retired) row");
+        let current = entries.remove(current_index);
+        KeyRing { current, previous: entries }
+    };
+
+    tracing::info!(did = %ring.current.did, key_id = %ring.current.key_id, "server signing identity ready");
+    let _ = KEY_RING.set(RwLock::new(ring));
+    Ok(())
+}
+
+fn ring() -> &'static RwLock<KeyRing> {
+    KEY_RING.get().expect("identity::init was not called")
+}
+
+/// The key currently used to sign new attestations.
+pub fn current() -> SigningKeyEntry {
+    ring().read().expect("key ring lock poisoned").current.clone()
+}
+
+/// Looks up a key (current, or retired but still inside its overlap
+/// window) by the `key_id` an attestation claims it was signed with.
+pub fn find(key_id: &str) -> Option<SigningKeyEntry> {
+    let guard = ring().read().expect("key ring lock poisoned");
+    if guard.current.key_id == key_id {
+        return Some(guard.current.clone());
+    }
+    guard.previous.iter().find(|k| k.key_id == key_id).cloned()
+}
+
+/// Every key still inside its overlap window, current first — what
+/// `/.well-known/did.json` publishes alongside the primary DID so a
+/// verifier mid-rotation isn't stuck trusting only the newest key.
+pub fn active_keys() -> Vec<SigningKeyEntry> {
+    let guard = ring().read().expect("key ring lock poisoned");
+    std::iter::once(guard.current.clone()).chain(guard.previous.iter().cloned()).collect()
+}
+
+/// Rotates the signing key: the current key retires (but stays verifiable
+/// via `find` for `overlap`) and a freshly generated key takes over
+/// signing. Persists both the retirement and the new key to
+/// `server_signing_keys` before swapping the in-memory ring, so the new
+/// generation — and the old one's overlap window — survive a restart
+/// instead of only existing in this process's memory. Rows retired longer
+/// than `overlap` ago are pruned. Run on a schedule by `jobs::key_rotation`;
+/// see `services::key_rotation` for the DID-level, user-initiated
+/// equivalent this mirrors for the server's own identity.
+pub async fn rotate(db: &PgPool, overlap: chrono::Duration) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+    let incoming = SigningKeyEntry::fresh(SigningKey::generate(&mut rand::rngs::OsRng), now);
+
+    let (old_key_id, old_did) = {
+        let guard = ring().read().expect("key ring lock poisoned");
+        (guard.current.key_id.clone(), guard.current.did.clone())
+    };
+
+    sqlx::query("UPDATE server_signing_keys SET retired_at = $2 WHERE key_id = $1")
+        .bind(&old_key_id)
+        .bind(now)
+        .execute(db)
+        .await?;
+    sqlx::query(
+        "INSERT INTO server_signing_keys (id, key_id, seed_hex, did, activated_at, retired_at)
+         VALUES ($1, $2, $3, $4, $5, NULL)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&incoming.key_id)
+    .bind(incoming.seed_hex())
+    .bind(&incoming.did)
+    .bind(incoming.activated_at)
+    .execute(db)
+    .await?;
+    sqlx::query("DELETE FROM server_signing_keys WHERE retired_at IS NOT NULL AND retired_at < $1")
+        .bind(now - overlap)
+        .execute(db)
+        .await?;
+
+    tracing::info!(
+        old_did = %old_did, old_key_id = %old_key_id,
+        new_did = %incoming.did, new_key_id = %incoming.key_id,
+        "server signing identity rotated"
+    );
+
+    let mut guard = ring().write().expect("key ring lock poisoned");
+    let mut retiring = guard.current.clone();
+    retiring.retired_at = Some(now);
+    guard
+        .previous
+        .retain(|k| k.retired_at.map(|retired_at| now - retired_at < overlap).unwrap_or(true));
+    guard.previous.insert(0, retiring);
+    guard.current = incoming;
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::services::approvals;
+
+/// Expires pending approval requests past `expires_at` so a
+/// `pending_approval` bounty (or any other action filed via
+/// `POST /api/v1/approvals`) doesn't sit in limbo forever when approvers
+/// simply never respond.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match approvals::expire_stale(&db).await {
+            Ok(count) if count > 0 => tracing::info!(count, "expired approval requests"),
+            Ok(_) => {}
+            Err(err) => tracing::error!(?err, "approval expiry sweep failed"),
+        }
+    }
+}
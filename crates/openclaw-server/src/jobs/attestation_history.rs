@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::services::attestation;
+
+/// Periodically snapshots a signed reserve attestation into the
+/// hash-chained `reserve_attestations` history, independent of anyone
+/// hitting the live `/api/v1/credits/reserves/attestation` endpoint.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match attestation::snapshot(&db).await {
+            Ok(row) => tracing::info!(id = %row.id, "reserve attestation snapshot recorded"),
+            Err(err) => tracing::error!(?err, "reserve attestation snapshot failed"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::services::{escrow, notifications};
+
+#[derive(FromRow)]
+struct ExpiredBounty {
+    id: Uuid,
+    poster_did: String,
+}
+
+/// Polls for open bounties whose deadline has passed, marks them expired,
+/// refunds the poster's escrow, and notifies the poster. Deadlines used to
+/// only be checked opportunistically inside accept/submit, which left
+/// abandoned bounties holding escrow indefinitely.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = sweep(&db).await {
+            tracing::error!(?err, "bounty expiry sweep failed");
+        }
+    }
+}
+
+async fn sweep(db: &Db) -> anyhow::Result<()> {
+    let expired = sqlx::query_as::<_, ExpiredBounty>(
+        "UPDATE bounties SET status = 'expired', updated_at = now()
+         WHERE status = 'open' AND deadline IS NOT NULL AND deadline < now()
+         RETURNING id, poster_did",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for bounty in expired {
+        escrow::refund_escrow_for_bounty(db, bounty.id, crate::models::EscrowPurpose::BountyReward).await?;
+        notifications::notify(
+            db,
+            &bounty.poster_did,
+            "bounty.expired",
+            json!({ "bounty_id": bounty.id }),
+        )
+        .await;
+    }
+
+    Ok(())
+}
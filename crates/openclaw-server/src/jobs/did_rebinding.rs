@@ -0,0 +1,17 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::services::did_binding;
+
+/// Polls for rebindings whose cooldown has elapsed and propagates the
+/// balance and open bounties from the old DID to the new one. See the
+/// rebinding request for why this doesn't happen synchronously.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = did_binding::finalize_due_rebindings(&db).await {
+            tracing::error!(?err, "DID rebinding finalization sweep failed");
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::models::{Dispute, DisputeOutcome};
+use crate::services::disputes;
+
+/// Auto-resolves disputes that have sat past `dispute_deadline` without an
+/// arbiter decision. Policy default is to favor the submitter: the bounty's
+/// escrow releases and the initiator's stake is returned, on the theory that
+/// an unresolved dispute shouldn't indefinitely withhold payment.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = sweep(&db).await {
+            tracing::error!(?err, "dispute auto-resolution sweep failed");
+        }
+    }
+}
+
+async fn sweep(db: &Db) -> anyhow::Result<()> {
+    let overdue = sqlx::query_as::<_, Dispute>(
+        "SELECT * FROM disputes WHERE status = 'open' AND dispute_deadline < now()",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for dispute in overdue {
+        disputes::settle(db, &dispute, DisputeOutcome::FavorSubmitter).await?;
+    }
+
+    Ok(())
+}
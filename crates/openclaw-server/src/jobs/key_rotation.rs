@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::identity;
+
+/// Rotates the server's own attestation-signing identity on a schedule.
+/// Unlike `jobs::did_rebinding`, there's no user request or cooldown here —
+/// this is purely the server keeping its own key fresh. `identity::rotate`
+/// persists each generation to `server_signing_keys` itself; this job just
+/// drives the schedule.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = identity::rotate(&db, identity::ROTATION_OVERLAP).await {
+            tracing::error!(?err, "server signing key rotation failed");
+        }
+    }
+}
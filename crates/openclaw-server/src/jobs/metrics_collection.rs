@@ -0,0 +1,17 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::metrics;
+
+/// Periodically samples gauges that reflect point-in-time state (DB pool
+/// utilization, escrow totals, ledger event counts) rather than discrete
+/// events, so `/metrics` stays current between scrapes.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = metrics::sample_gauges(&db).await {
+            tracing::error!(?err, "metrics gauge sampling failed");
+        }
+    }
+}
@@ -0,0 +1,33 @@
+pub mod approval_expiry;
+pub mod attestation_history;
+pub mod bounty_expiry;
+pub mod did_rebinding;
+pub mod dispute_resolution;
+pub mod key_rotation;
+pub mod metrics_collection;
+pub mod notification_delivery;
+pub mod promo_expiry;
+pub mod queue;
+pub mod reconciliation;
+pub mod webhook_delivery;
+
+use std::time::Duration;
+
+use crate::db::Db;
+
+/// Spawns the background workers that run for the lifetime of the server.
+/// Each job owns its own polling loop for now; see the request to
+/// consolidate these into a shared runner once there are more than a couple.
+pub fn spawn_all(db: Db) {
+    tokio::spawn(bounty_expiry::run(db.clone(), Duration::from_secs(60)));
+    tokio::spawn(approval_expiry::run(db.clone(), Duration::from_secs(60)));
+    tokio::spawn(dispute_resolution::run(db.clone(), Duration::from_secs(60)));
+    tokio::spawn(webhook_delivery::run(db.clone(), Duration::from_secs(15)));
+    tokio::spawn(notification_delivery::run(db.clone(), Duration::from_secs(15)));
+    tokio::spawn(promo_expiry::run(db.clone(), Duration::from_secs(300)));
+    tokio::spawn(reconciliation::run(db.clone(), Duration::from_secs(86_400)));
+    tokio::spawn(attestation_history::run(db.clone(), Duration::from_secs(3_600)));
+    tokio::spawn(did_rebinding::run(db.clone(), Duration::from_secs(300)));
+    tokio::spawn(key_rotation::run(db.clone(), Duration::from_secs(7 * 86_400)));
+    tokio::spawn(metrics_collection::run(db, Duration::from_secs(30)));
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::models::{NotificationChannel, NotificationDelivery, NotificationDeliveryStatus};
+use crate::services::{chat, email};
+
+const MAX_ATTEMPTS: i32 = 8;
+
+/// Delivers pending email/chat notification rows, retrying failures with
+/// exponential backoff (capped at `MAX_ATTEMPTS`) before they're left in
+/// the `failed` state for the admin failures view to surface — mirrors
+/// `jobs::webhook_delivery`.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = sweep(&db).await {
+            tracing::error!(?err, "notification delivery sweep failed");
+        }
+    }
+}
+
+async fn sweep(db: &Db) -> anyhow::Result<()> {
+    let due = sqlx::query_as::<_, NotificationDelivery>(
+        "SELECT * FROM notification_deliveries
+         WHERE status = 'pending' AND next_attempt_at <= now()
+         LIMIT 100",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for row in due {
+        let result = match row.channel {
+            NotificationChannel::Email => email::send(&row.destination, &row.event, &row.body).await,
+            NotificationChannel::Chat => {
+                let link = format!("protocol-m://events/{}", row.event);
+                chat::post(&row.destination, &row.event, &link).await
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                sqlx::query("UPDATE notification_deliveries SET status = 'delivered' WHERE id = $1")
+                    .bind(row.id)
+                    .execute(db)
+                    .await?;
+            }
+            Err(err) => {
+                let attempts = row.attempts + 1;
+                let status = if attempts >= MAX_ATTEMPTS {
+                    NotificationDeliveryStatus::Failed
+                } else {
+                    NotificationDeliveryStatus::Pending
+                };
+                let backoff = Duration::from_secs(2u64.saturating_pow(attempts as u32).min(3600));
+                let next_attempt_at = Utc::now() + chrono::Duration::from_std(backoff)?;
+
+                sqlx::query(
+                    "UPDATE notification_deliveries
+                     SET attempts = $2, status = $3, last_error = $4, next_attempt_at = $5
+                     WHERE id = $1",
+                )
+                .bind(row.id)
+                .bind(attempts)
+                .bind(status)
+                .bind(err.to_string())
+                .bind(next_attempt_at)
+                .execute(db)
+                .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `GET /api/v1/admin/notifications/failures` — deliveries that exhausted
+/// `MAX_ATTEMPTS`, for operators to inspect or replay manually.
+pub async fn list_failures(db: &Db) -> Result<Vec<NotificationDelivery>, sqlx::Error> {
+    sqlx::query_as::<_, NotificationDelivery>(
+        "SELECT * FROM notification_deliveries WHERE status = 'failed' ORDER BY created_at DESC",
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// `POST /api/v1/admin/notifications/failures/:id/replay` — puts a
+/// dead-lettered notification back in the pending queue with a fresh
+/// attempt budget.
+pub async fn replay(db: &Db, id: Uuid) -> Result<NotificationDelivery, sqlx::Error> {
+    sqlx::query_as::<_, NotificationDelivery>(
+        "UPDATE notification_deliveries
+         SET status = 'pending', attempts = 0, last_error = NULL, next_attempt_at = now()
+         WHERE id = $1 AND status = 'failed'
+         RETURNING *",
+    )
+    .bind(id)
+    .fetch_one(db)
+    .await
+}
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::services::promo;
+
+/// Burns promo grants past their `expires_at` so stale promotional balance
+/// doesn't linger as spendable credit indefinitely.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match promo::burn_expired(&db).await {
+            Ok(count) if count > 0 => tracing::info!(count, "expired promo grants burned"),
+            Ok(_) => {}
+            Err(err) => tracing::error!(?err, "promo expiry sweep failed"),
+        }
+    }
+}
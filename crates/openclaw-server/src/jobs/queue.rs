@@ -0,0 +1,144 @@
+//! A small DB-backed job queue other modules can enqueue work onto instead
+//! of rolling their own polling loop. `webhook_delivery`, `promo_expiry`,
+//! and friends predate this and aren't migrated here — this is the
+//! framework new scheduled/queued work should build on.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::db::Db;
+
+const MAX_ATTEMPTS: i32 = 8;
+const LEASE_SECONDS: i64 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+enum JobStatus {
+    Pending,
+    Leased,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone, FromRow)]
+struct QueuedJob {
+    id: Uuid,
+    kind: String,
+    payload: serde_json::Value,
+    attempts: i32,
+}
+
+/// A unit of work a module registers under a `kind` name. `run` should be
+/// idempotent: if a worker crashes mid-job the lease expires and a later
+/// sweep hands the same row to another worker.
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    fn kind(&self) -> &'static str;
+    async fn run(&self, db: &Db, payload: serde_json::Value) -> anyhow::Result<()>;
+}
+
+/// Enqueues a one-off job of `kind`, runnable once `run_at` (defaults to
+/// now) has passed. `kind` must match a handler passed to `Runner::new` or
+/// it sits pending until one is registered and a sweep picks it up.
+pub async fn enqueue(
+    db: &Db,
+    kind: &str,
+    payload: serde_json::Value,
+    run_at: Option<DateTime<Utc>>,
+) -> Result<Uuid, sqlx::Error> {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO background_jobs (id, kind, payload, status, attempts, run_at, created_at)
+         VALUES ($1, $2, $3, 'pending', 0, COALESCE($4, now()), now())",
+    )
+    .bind(id)
+    .bind(kind)
+    .bind(payload)
+    .bind(run_at)
+    .execute(db)
+    .await?;
+    Ok(id)
+}
+
+/// Leases and runs due jobs for every registered handler, retrying
+/// failures with exponential backoff (capped at `MAX_ATTEMPTS`) before
+/// leaving them `failed`. There's no cron syntax: a recurring schedule is
+/// just a handler whose `run` calls `enqueue` again for its next
+/// occurrence before returning.
+pub struct Runner {
+    handlers: Vec<Arc<dyn JobHandler>>,
+}
+
+impl Runner {
+    pub fn new(handlers: Vec<Arc<dyn JobHandler>>) -> Self {
+        Self { handlers }
+    }
+
+    pub async fn run(self, db: Db, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = self.sweep(&db).await {
+                tracing::error!(?err, "job queue sweep failed");
+            }
+        }
+    }
+
+    async fn sweep(&self, db: &Db) -> anyhow::Result<()> {
+        for handler in &self.handlers {
+            let leased = sqlx::query_as::<_, QueuedJob>(
+                "UPDATE background_jobs
+                 SET status = 'leased', leased_until = now() + ($3 || ' seconds')::interval
+                 WHERE id IN (
+                     SELECT id FROM background_jobs
+                     WHERE kind = $1 AND status = 'pending' AND run_at <= now()
+                     ORDER BY run_at ASC
+                     LIMIT 20
+                     FOR UPDATE SKIP LOCKED
+                 )
+                 RETURNING id, kind, payload, attempts",
+            )
+            .bind(handler.kind())
+            .bind(20_i64)
+            .bind(LEASE_SECONDS.to_string())
+            .fetch_all(db)
+            .await?;
+
+            for job in leased {
+                match handler.run(db, job.payload).await {
+                    Ok(()) => {
+                        sqlx::query("UPDATE background_jobs SET status = 'done' WHERE id = $1")
+                            .bind(job.id)
+                            .execute(db)
+                            .await?;
+                    }
+                    Err(err) => {
+                        let attempts = job.attempts + 1;
+                        let status = if attempts >= MAX_ATTEMPTS { JobStatus::Failed } else { JobStatus::Pending };
+                        let backoff = Duration::from_secs(2u64.saturating_pow(attempts as u32).min(3600));
+                        let run_at = Utc::now() + chrono::Duration::from_std(backoff)?;
+                        sqlx::query(
+                            "UPDATE background_jobs
+                             SET status = $2, attempts = $3, last_error = $4, run_at = $5
+                             WHERE id = $1",
+                        )
+                        .bind(job.id)
+                        .bind(status)
+                        .bind(attempts)
+                        .bind(err.to_string())
+                        .bind(run_at)
+                        .execute(db)
+                        .await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use sqlx::FromRow;
+
+use crate::db::Db;
+
+#[derive(FromRow)]
+struct BalancePair {
+    did: String,
+    ledger_balance: rust_decimal::Decimal,
+    account_balance: rust_decimal::Decimal,
+}
+
+/// Nightly reconciliation: recomputes every DID's balance from
+/// `m_credits_ledger` and compares it to the materialized
+/// `m_credits_accounts` row, recording any mismatch in
+/// `ledger_discrepancies` for the admin endpoint to surface.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let started = Instant::now();
+        match sweep(&db).await {
+            Ok(found) => tracing::info!(found, elapsed = ?started.elapsed(), "ledger reconciliation complete"),
+            Err(err) => tracing::error!(?err, "ledger reconciliation failed"),
+        }
+    }
+}
+
+async fn sweep(db: &Db) -> anyhow::Result<u64> {
+    let mismatches = sqlx::query_as::<_, BalancePair>(
+        "SELECT a.did, COALESCE(l.balance, 0) AS ledger_balance, a.balance AS account_balance
+         FROM m_credits_accounts a
+         LEFT JOIN (
+             SELECT did, SUM(amount) AS balance FROM m_credits_ledger GROUP BY did
+         ) l ON l.did = a.did
+         WHERE COALESCE(l.balance, 0) <> a.balance",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for m in &mismatches {
+        sqlx::query(
+            "INSERT INTO ledger_discrepancies (id, did, ledger_balance, account_balance, detected_at)
+             VALUES (gen_random_uuid(), $1, $2, $3, now())",
+        )
+        .bind(&m.did)
+        .bind(m.ledger_balance)
+        .bind(m.account_balance)
+        .execute(db)
+        .await?;
+    }
+
+    Ok(mismatches.len() as u64)
+}
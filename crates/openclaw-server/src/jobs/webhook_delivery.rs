@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::FromRow;
+
+use crate::db::Db;
+use crate::models::{WebhookDelivery, WebhookDeliveryStatus};
+use crate::services::webhooks;
+
+const MAX_ATTEMPTS: i32 = 8;
+
+#[derive(FromRow)]
+struct DeliveryWithUrl {
+    #[sqlx(flatten)]
+    delivery: WebhookDelivery,
+    url: String,
+    secret: String,
+}
+
+/// Delivers pending webhook rows, retrying failures with exponential
+/// backoff (capped at `MAX_ATTEMPTS`) before they're left in the `failed`
+/// state for the admin failures view to surface.
+pub async fn run(db: Db, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = sweep(&db).await {
+            tracing::error!(?err, "webhook delivery sweep failed");
+        }
+    }
+}
+
+async fn sweep(db: &Db) -> anyhow::Result<()> {
+    let due = sqlx::query_as::<_, DeliveryWithUrl>(
+        "SELECT d.*, w.url, w.secret FROM webhook_deliveries d
+         JOIN webhook_registrations w ON w.id = d.webhook_id
+         WHERE d.status = 'pending' AND d.next_attempt_at <= now()
+         LIMIT 100",
+    )
+    .fetch_all(db)
+    .await?;
+
+    let client = reqwest::Client::new();
+    for row in due {
+        let body = serde_json::to_vec(&row.delivery.payload)?;
+        let signature = webhooks::sign_payload(&row.secret, &body);
+
+        let result = client
+            .post(&row.url)
+            .header("X-Protocol-M-Signature", signature)
+            .header("X-Protocol-M-Event", &row.delivery.event)
+            .body(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                sqlx::query("UPDATE webhook_deliveries SET status = 'delivered' WHERE id = $1")
+                    .bind(row.delivery.id)
+                    .execute(db)
+                    .await?;
+            }
+            other => {
+                let attempts = row.delivery.attempts + 1;
+                let error = match other {
+                    Ok(resp) => format!("http {}", resp.status()),
+                    Err(err) => err.to_string(),
+                };
+                let status = if attempts >= MAX_ATTEMPTS {
+                    WebhookDeliveryStatus::Failed
+                } else {
+                    WebhookDeliveryStatus::Pending
+                };
+                let backoff = Duration::from_secs(2u64.saturating_pow(attempts as u32).min(3600));
+                let next_attempt_at = Utc::now() + chrono::Duration::from_std(backoff)?;
+
+                sqlx::query(
+                    "UPDATE webhook_deliveries
+                     SET attempts = $2, status = $3, last_error = $4, next_attempt_at = $5
+                     WHERE id = $1",
+                )
+                .bind(row.delivery.id)
+                .bind(attempts)
+                .bind(status)
+                .bind(error)
+                .bind(next_attempt_at)
+                .execute(db)
+                .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `GET /api/v1/admin/webhooks/failures` — the rows that exhausted
+/// `MAX_ATTEMPTS`, for operators to inspect or manually replay.
+pub async fn list_failures(db: &Db) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+    sqlx::query_as::<_, WebhookDelivery>(
+        "SELECT * FROM webhook_deliveries WHERE status = 'failed' ORDER BY created_at DESC",
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// `POST /api/v1/admin/webhooks/failures/:id/replay` — puts a dead-lettered
+/// delivery back in the pending queue with a fresh attempt budget so the
+/// next sweep picks it up immediately.
+pub async fn replay(db: &Db, id: uuid::Uuid) -> Result<WebhookDelivery, sqlx::Error> {
+    sqlx::query_as::<_, WebhookDelivery>(
+        "UPDATE webhook_deliveries
+         SET status = 'pending', attempts = 0, last_error = NULL, next_attempt_at = now()
+         WHERE id = $1 AND status = 'failed'
+         RETURNING *",
+    )
+    .bind(id)
+    .fetch_one(db)
+    .await
+}
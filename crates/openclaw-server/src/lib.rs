@@ -0,0 +1,18 @@
+pub mod auth;
+pub mod config;
+pub mod db;
+pub mod error;
+pub mod grpc;
+pub mod identity;
+pub mod jobs;
+pub mod metrics;
+pub mod models;
+pub mod openapi;
+pub mod pagination;
+pub mod request_id;
+pub mod routes;
+pub mod security;
+pub mod services;
+pub mod validation;
+
+pub use error::{AppError, AppResult};
@@ -0,0 +1,31 @@
+use openclaw_server::services::event_bus;
+use openclaw_server::{config, db, grpc, identity, jobs, metrics, routes};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    config::init();
+    let metrics_handle = metrics::install_recorder();
+
+    let database_url = std::env::var("DATABASE_URL")?;
+    let db = db::connect(&database_url).await?;
+    db::init_replica(&db).await?;
+    identity::init(&db).await?;
+    event_bus::init(&db).await?;
+    jobs::spawn_all(db.clone());
+    let app = routes::create_router(db.clone(), metrics_handle);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+    tracing::info!("openclaw-server listening on 0.0.0.0:8080");
+
+    let grpc_addr: std::net::SocketAddr = std::env::var("GRPC_BIND_ADDR")
+        .unwrap_or_else(|_| "0.0.0.0:50051".to_string())
+        .parse()?;
+    tracing::info!(%grpc_addr, "openclaw-server gRPC listening");
+
+    tokio::try_join!(
+        async { axum::serve(listener, app).await.map_err(anyhow::Error::from) },
+        grpc::serve(db, grpc_addr),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Prometheus metrics: a tower middleware records per-request latency and
+//! status, while a background job (`jobs::metrics_collection`) periodically
+//! samples gauges that aren't naturally tied to a single request (DB pool
+//! utilization, escrow totals, ledger event counts).
+
+use std::time::Instant;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::db::Db;
+
+/// Installs the global Prometheus recorder and returns a handle whose
+/// `render()` produces the text exposition format served at `/metrics`.
+pub fn install_recorder() -> PrometheusHandle {
+    PrometheusBuilder::new()
+        .install_recorder()
+        .expect("failed to install Prometheus recorder")
+}
+
+/// Tower middleware: records `http_requests_total` labeled by method, route
+/// (not raw path, to keep cardinality bounded), and status code,
+/// `http_request_duration_seconds` labeled by method and route, and
+/// `http_errors_total` labeled by status code for non-2xx responses.
+pub async fn track_http_metrics(req: Request, next: Next) -> impl IntoResponse {
+    let method = req.method().to_string();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let start = Instant::now();
+    let response = next.run(req).await;
+    let elapsed = start.elapsed().as_secs_f64();
+    let status = response.status().as_u16().to_string();
+
+    metrics::counter!("http_requests_total", "method" => method.clone(), "route" => route.clone(), "status" => status.clone()).increment(1);
+    metrics::histogram!("http_request_duration_seconds", "method" => method, "route" => route)
+        .record(elapsed);
+    if !response.status().is_success() {
+        metrics::counter!("http_errors_total", "status" => status).increment(1);
+    }
+
+    response
+}
+
+/// `GET /metrics` — renders the current Prometheus text exposition.
+pub async fn serve_metrics(State(handle): State<PrometheusHandle>) -> String {
+    handle.render()
+}
+
+/// Samples gauges that reflect point-in-time state rather than discrete
+/// events: DB pool utilization, total escrow held, and per-event-type
+/// ledger counts. Called periodically by `jobs::metrics_collection`.
+pub async fn sample_gauges(db: &Db) -> crate::AppResult<()> {
+    metrics::gauge!("db_pool_connections", "state" => "total").set(db.size() as f64);
+    metrics::gauge!("db_pool_connections", "state" => "idle").set(db.num_idle() as f64);
+
+    let escrow_held: Option<rust_decimal::Decimal> =
+        sqlx::query_scalar("SELECT SUM(amount) FROM escrow_holds WHERE status = 'held'")
+            .fetch_one(db)
+            .await?;
+    metrics::gauge!("escrow_held_total").set(escrow_held.unwrap_or_default().to_f64().unwrap_or(0.0));
+
+    let ledger_counts: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT event_type, COUNT(*) FROM m_credits_ledger GROUP BY event_type",
+    )
+    .fetch_all(db)
+    .await?;
+    for (event_type, count) in ledger_counts {
+        metrics::gauge!("ledger_events_total", "event_type" => event_type).set(count as f64);
+    }
+
+    Ok(())
+}
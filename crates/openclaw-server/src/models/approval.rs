@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Expired,
+}
+
+/// A request for `approvers` to sign off on an action that exceeded a
+/// policy limit — originally just over-limit bounty creation, generalized
+/// later to arbitrary agent actions. `approvers` and `quorum` are a
+/// snapshot of the matching `ApprovalTier` at filing time, so a tier
+/// edited mid-flight doesn't retroactively change what an in-flight
+/// request needs to pass.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ApprovalRequest {
+    pub id: Uuid,
+    pub requester_did: String,
+    pub approvers: Vec<String>,
+    pub quorum: i32,
+    pub action_type: String,
+    pub action_payload: Value,
+    pub status: ApprovalStatus,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ApprovalVote {
+    Approve,
+    Reject,
+}
+
+/// One approver's vote on an `ApprovalRequest`. Unique per
+/// `(approval_request_id, approver_did)` so an approver can change their
+/// mind before quorum is reached, the same as `SubmissionReview`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ApprovalDecision {
+    pub id: Uuid,
+    pub approval_request_id: Uuid,
+    pub approver_did: String,
+    pub vote: ApprovalVote,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A content-addressed, signed artifact. `signature_envelope` is the
+/// `SignatureEnvelopeV1` it was registered with, stored verbatim so the
+/// original signature can be re-verified later without us re-deriving it.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Artifact {
+    pub id: Uuid,
+    pub sha256: String,
+    pub signer_did: String,
+    pub signature_envelope: serde_json::Value,
+    pub derived_from: Vec<String>,
+    /// Set once `confirm_content_uploaded` confirms the object stored
+    /// under this artifact's content hash actually hashes to it.
+    pub content_stored: bool,
+    /// IPFS CID the content was pinned under, if IPFS pinning is
+    /// configured for this deployment.
+    pub ipfs_cid: Option<String>,
+    /// Simhash fingerprint of the uploaded content (see
+    /// `openclaw_crypto::fuzzy_hash`), set alongside `content_stored`. Null
+    /// until content is confirmed uploaded, since `sha256` alone can't
+    /// compute it off an envelope.
+    pub fuzzy_hash: Option<String>,
+    pub registered_at: DateTime<Utc>,
+    /// Set by `services::artifacts::revoke` when the signer yanks the
+    /// artifact — a signal to downstream consumers not to build on it,
+    /// not a deletion; the row and its lineage edges are left in place.
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revocation_reason: Option<String>,
+}
+
+/// An existing artifact whose content fingerprint is close enough to
+/// another artifact's to be worth a human look, with how close.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SimilarArtifact {
+    pub artifact: Artifact,
+    /// Fraction of simhash bits in agreement: 1.0 is byte-identical
+    /// content, ~0.5 is unrelated. See `openclaw_crypto::fuzzy_hash::similarity`.
+    pub similarity: f64,
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Whether the parent artifact's signer has countersigned a derivation
+/// claim made against it. Created `Pending`; only `Acknowledged` or
+/// `Waived` edges count as verified lineage (see
+/// `services::artifacts::lineage`) or feed a royalty split (see
+/// `services::escrow::release_to_with_royalties`) — anyone can declare a
+/// `parent_artifact_id` when registering, so an unresolved claim can't be
+/// trusted on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum DerivationStatus {
+    Pending,
+    /// The parent's signer confirmed the derivation is legitimate.
+    Acknowledged,
+    /// The parent's signer declined to countersign but doesn't dispute the
+    /// claim either — e.g. they no longer hold the key that signed the
+    /// parent. Still counts as resolved, verified lineage.
+    Waived,
+}
+
+/// One edge in the provenance graph: `child_artifact_id` declares itself
+/// derived from `parent_artifact_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ArtifactDerivation {
+    pub parent_artifact_id: Uuid,
+    pub child_artifact_id: Uuid,
+    pub status: DerivationStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Artifact identity plus enough context (signer, originating bounty) to
+/// render a lineage node without a second round-trip per node.
+#[derive(Debug, Clone, FromRow)]
+pub struct ArtifactContextRow {
+    pub artifact_id: Uuid,
+    pub sha256: String,
+    pub signer_did: String,
+    pub bounty_id: Option<Uuid>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// One node in a lineage response, tagged with its distance from the
+/// artifact the lineage was requested for.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct LineageNode {
+    pub artifact_id: Uuid,
+    pub sha256: String,
+    pub signer_did: String,
+    pub bounty_id: Option<Uuid>,
+    pub depth: i32,
+    pub revoked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ArtifactLineage {
+    pub ancestors: Vec<LineageNode>,
+    /// True if walking ancestors ran into a derivation cycle (some
+    /// ancestor declares itself derived from one of its own descendants).
+    /// The cycle is cut rather than causing non-termination; this just
+    /// tells the caller the graph isn't a clean DAG.
+    pub ancestors_cycle_detected: bool,
+    pub descendants: Vec<LineageNode>,
+    pub descendants_cycle_detected: bool,
+}
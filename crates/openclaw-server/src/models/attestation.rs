@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A signed statement of how many credits are outstanding versus how many
+/// USD have actually been collected for them. The envelope is signed with
+/// the server's identity (see `identity::current`) over its own JCS
+/// canonicalization, so auditors can verify it without trusting the API
+/// that served it. `key_id` names which generation of the server's signing
+/// key produced `signature`, so a verifier mid-rotation knows which
+/// `identity::find` entry (or `/.well-known/did.json` key) to check against
+/// instead of assuming it's always the newest one.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ReserveAttestation {
+    pub did: String,
+    pub key_id: String,
+    pub total_credits_liability: Decimal,
+    pub total_usd_reserves: Decimal,
+    pub attested_at: DateTime<Utc>,
+    pub signature: String,
+}
+
+/// Same shape, without `signature`, so we can canonicalize-and-sign it and
+/// then fill the field in afterwards without hashing our own signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsignedReserveAttestation {
+    pub did: String,
+    pub key_id: String,
+    pub total_credits_liability: Decimal,
+    pub total_usd_reserves: Decimal,
+    pub attested_at: DateTime<Utc>,
+}
+
+/// A persisted, hash-chained reserve attestation. `prev_hash` links back to
+/// the previous snapshot's `hash` (null for the first one ever taken), so
+/// an auditor walking the table in order can detect a gap or a rewrite: any
+/// break in the chain means a row was deleted or tampered with.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ReserveAttestationSnapshot {
+    pub id: Uuid,
+    pub did: String,
+    pub key_id: String,
+    pub total_credits_liability: Decimal,
+    pub total_usd_reserves: Decimal,
+    pub signature: String,
+    pub prev_hash: Option<String>,
+    pub hash: String,
+    pub attested_at: DateTime<Utc>,
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum BountyStatus {
+    PendingApproval,
+    Open,
+    InReview,
+    Closed,
+    Cancelled,
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ClosureType {
+    Requester,
+    Quorum,
+    Test,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Bounty {
+    pub id: Uuid,
+    pub poster_did: String,
+    pub title: String,
+    pub description: String,
+    pub reward: Decimal,
+    pub closure_type: ClosureType,
+    pub status: BountyStatus,
+    pub deadline: Option<DateTime<Utc>>,
+    pub metadata: Value,
+    /// Category taxonomy entries from `services::tags::TAXONOMY`, used for
+    /// listing filters and tag subscription fan-out.
+    pub tags: Vec<String>,
+    /// Only meaningful when `closure_type` is `Quorum`: how many reviewer
+    /// votes are required and the minimum reputation a reviewer must hold.
+    pub reviewer_count: Option<i32>,
+    pub min_reviewer_rep: Option<Decimal>,
+    /// Why a `Cancelled` bounty was cancelled. Set by
+    /// `services::approvals` when a `pending_approval` bounty's approval
+    /// request is rejected or expires, so the detail endpoint can tell a
+    /// poster why their bounty never opened instead of leaving them to
+    /// guess from `status` alone.
+    pub cancellation_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One row per accepted edit to an open bounty, so posters and reviewers can
+/// see what changed after the fact instead of only the latest snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct BountyEdit {
+    pub id: Uuid,
+    pub bounty_id: Uuid,
+    pub edited_by: String,
+    pub field: String,
+    pub previous_value: Value,
+    pub new_value: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PatchBountyRequest {
+    pub description: Option<String>,
+    pub deadline: Option<DateTime<Utc>>,
+    pub metadata: Option<Value>,
+    pub reward: Option<Decimal>,
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::ClosureType;
+
+/// A poster's saved title/description/closure-config scaffold, so a poster
+/// who repeatedly posts similar bounties (e.g. eval harnesses) doesn't have
+/// to re-enter the same JSON every time. Instantiating one just seeds a
+/// `CreateBountyRequest` with these defaults; the reward and deadline are
+/// still supplied per-bounty since those vary every time.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct BountyTemplate {
+    pub id: Uuid,
+    pub poster_did: String,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub closure_type: ClosureType,
+    pub default_metadata: Value,
+    pub default_tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A compute provider `/credits/redeem` can allocate against.
+/// `credential_ref` is a pointer into the secrets store (e.g. a Vault path
+/// or env var name), never the credential itself — this row is returned
+/// over the admin API as-is.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ComputeProvider {
+    pub id: Uuid,
+    pub name: String,
+    /// Credits charged per unit of compute this provider allocates.
+    pub conversion_rate: Decimal,
+    pub credential_ref: String,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
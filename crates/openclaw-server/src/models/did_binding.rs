@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct DidBinding {
+    pub id: Uuid,
+    pub did: String,
+    pub bound_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Row from `did_challenges`. Never serialized back to a client beyond the
+/// nonce returned at issuance, so no `ToSchema`.
+#[derive(Debug, Clone, FromRow)]
+pub struct DidChallenge {
+    pub id: Uuid,
+    pub did: String,
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A requested transition from `old_did` to `new_did`. Revocation of the
+/// old binding and creation of the new one both happen immediately;
+/// `cooldown_until` only gates when `jobs::did_rebinding` is allowed to
+/// propagate the balance and open bounties over to `new_did`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct DidRebinding {
+    pub id: Uuid,
+    pub old_did: String,
+    pub new_did: String,
+    pub confirmed_by_old_did: bool,
+    pub requested_at: DateTime<Utc>,
+    pub cooldown_until: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct LedgerDiscrepancy {
+    pub id: Uuid,
+    pub did: String,
+    pub ledger_balance: Decimal,
+    pub account_balance: Decimal,
+    pub detected_at: DateTime<Utc>,
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum DisputeStatus {
+    Open,
+    Resolved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum DisputeOutcome {
+    FavorSubmitter,
+    FavorRequester,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct OpenDisputeRequest {
+    pub submission_id: Uuid,
+    pub reason: String,
+    /// Held in escrow under `EscrowPurpose::DisputeStake` until the dispute
+    /// resolves; returned to the initiator if the arbiter sides with them,
+    /// slashed otherwise (see `services::disputes::settle`).
+    pub stake: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Dispute {
+    pub id: Uuid,
+    pub bounty_id: Uuid,
+    pub submission_id: Uuid,
+    pub initiator_did: String,
+    pub respondent_did: String,
+    pub reason: String,
+    pub initiator_stake: Decimal,
+    pub status: DisputeStatus,
+    pub outcome: Option<DisputeOutcome>,
+    pub dispute_deadline: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
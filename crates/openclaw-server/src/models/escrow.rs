@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum EscrowStatus {
+    Held,
+    Released,
+    Refunded,
+    Slashed,
+}
+
+/// What an `escrow_holds` row is holding funds for. Multiple holds can
+/// share a `bounty_id` — reward top-ups (`BountyReward`) and a dispute
+/// initiator's stake (`DisputeStake`) both reference the bounty they're
+/// about — so release/refund operations must filter by purpose as well as
+/// `bounty_id` to avoid touching the wrong hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum EscrowPurpose {
+    BountyReward,
+    DisputeStake,
+    JurorStake,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct EscrowHold {
+    pub id: Uuid,
+    pub bounty_id: Uuid,
+    pub held_did: String,
+    pub amount: Decimal,
+    pub status: EscrowStatus,
+    pub purpose: EscrowPurpose,
+    /// How much of `amount` was drawn from promo credit rather than main
+    /// balance (see `services::promo::spend_promo_first`). Refunding or
+    /// redistributing a hold must return this portion to promo credit and
+    /// only `amount - promo_amount` to `m_credits_accounts.balance` — they
+    /// came from different places, so they have to go back to different
+    /// places.
+    pub promo_amount: Decimal,
+    pub created_at: DateTime<Utc>,
+}
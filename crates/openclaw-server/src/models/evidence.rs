@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A signed evidence artifact attached to a dispute (log excerpt, diff,
+/// screenshot, ...). The envelope itself is stored verbatim; `kind` is a
+/// free-text hint for rendering, not a security boundary.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct DisputeEvidence {
+    pub id: Uuid,
+    pub dispute_id: Uuid,
+    pub submitted_by: String,
+    pub kind: String,
+    pub envelope: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AttachEvidenceRequest {
+    pub kind: String,
+    pub envelope: Value,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A runner's signed report of running a `ClosureType::Test` submission's
+/// test suite. `signature` is over `{action: "execution_receipt",
+/// submission_id, passed, detail}` signed by `runner_did`, verified against
+/// the bounty's `trusted_runners` before the receipt is accepted.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ExecutionReceipt {
+    pub id: Uuid,
+    pub submission_id: Uuid,
+    pub runner_did: String,
+    pub passed: bool,
+    pub detail: Value,
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
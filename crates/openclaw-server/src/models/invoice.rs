@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Pending,
+    /// Flagged by `services::fraud` and held for an operator to clear via
+    /// the manual review queue before a checkout session is ever opened.
+    PendingReview,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Invoice {
+    pub id: Uuid,
+    pub did: String,
+    pub amount_usd: Decimal,
+    pub currency: String,
+    pub credits: Decimal,
+    pub status: InvoiceStatus,
+    pub external_ref: Option<String>,
+    /// Opaque fingerprint of the payment instrument (never a raw card
+    /// number), used by `services::fraud` to rate-limit purchases per
+    /// instrument independent of which DID they're credited to.
+    pub card_fingerprint: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PurchaseRequest {
+    pub amount_usd: Decimal,
+    pub currency: Option<String>,
+    /// Opaque fingerprint of the payment instrument, supplied by the
+    /// client's payment SDK (e.g. Stripe's `payment_method.card.fingerprint`).
+    pub card_fingerprint: Option<String>,
+    /// Credits this purchase to a different DID than the authenticated
+    /// caller (e.g. a platform buying credits on behalf of a user). A
+    /// mismatch here is one of the signals `services::fraud` checks.
+    pub credit_to_did: Option<String>,
+}
+
+impl crate::validation::Validate for PurchaseRequest {
+    fn validate(&self) -> crate::error::AppResult<()> {
+        crate::validation::positive(self.amount_usd, "amount_usd")?;
+        if let Some(currency) = &self.currency {
+            crate::validation::max_len(currency, 3, "currency")?;
+        }
+        if let Some(credit_to_did) = &self.credit_to_did {
+            crate::validation::did(credit_to_did, "credit_to_did")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PurchaseResponse {
+    pub invoice_id: Uuid,
+    pub status: InvoiceStatus,
+    /// Absent when the purchase was routed to manual review instead of a
+    /// checkout session.
+    pub checkout_url: Option<String>,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::DisputeOutcome;
+
+/// A juror's staked vote on a dispute. The stake is held in escrow (see
+/// `EscrowPurpose::JurorStake`) until the dispute resolves: jurors on the
+/// losing side forfeit their stake, split across jurors who voted for the
+/// outcome the arbiter actually picked.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct JurorVote {
+    pub id: Uuid,
+    pub dispute_id: Uuid,
+    pub juror_did: String,
+    pub vote: DisputeOutcome,
+    pub stake: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct StakeJurorVoteRequest {
+    pub vote: DisputeOutcome,
+    pub stake: Decimal,
+}
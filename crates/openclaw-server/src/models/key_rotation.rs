@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A signed statement from `old_did` endorsing `new_did` as its successor
+/// signing key. Unlike `DidRebinding`, this never moves funds and has no
+/// cooldown — it exists purely so `old_did`'s attribution history (artifacts,
+/// bounties, reputation) can still be resolved after the key changes.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct DidKeyRotation {
+    pub id: Uuid,
+    pub old_did: String,
+    pub new_did: String,
+    pub rotated_at: DateTime<Utc>,
+}
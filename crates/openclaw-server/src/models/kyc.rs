@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum KycStatus {
+    Unverified,
+    Pending,
+    Verified,
+    Rejected,
+}
+
+/// A DID's identity-verification state with whichever `services::kyc`
+/// provider ran the check. One row per DID — a new verification attempt
+/// overwrites the prior status rather than appending history, since only
+/// the current state gates `/credits` routes.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct KycVerification {
+    pub did: String,
+    pub status: KycStatus,
+    pub provider: String,
+    pub external_ref: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
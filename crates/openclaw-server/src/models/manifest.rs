@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A verified contribution manifest, stored linked to the signer DID. The
+/// manifest envelope (including every nested artifact signature) is kept
+/// verbatim for later re-verification.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct StoredManifest {
+    pub id: Uuid,
+    pub signer_did: String,
+    pub manifest: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
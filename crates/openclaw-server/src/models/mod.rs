@@ -0,0 +1,75 @@
+pub mod approval;
+pub mod artifact;
+pub mod audit;
+pub mod artifact_derivation;
+pub mod attestation;
+pub mod bounty;
+pub mod bounty_template;
+pub mod compute_provider;
+pub mod did_binding;
+pub mod discrepancy;
+pub mod dispute;
+pub mod escrow;
+pub mod evidence;
+pub mod execution_receipt;
+pub mod invoice;
+pub mod juror_vote;
+pub mod key_rotation;
+pub mod kyc;
+pub mod manifest;
+pub mod notification_delivery;
+pub mod notification_preference;
+pub mod policy;
+pub mod post;
+pub mod profile;
+pub mod promo;
+pub mod purchase_review;
+pub mod redemption_receipt;
+pub mod reputation;
+pub mod reviewer;
+pub mod runner_registration;
+pub mod server_signing_key;
+pub mod similarity_report;
+pub mod submission;
+pub mod tag_subscription;
+pub mod trusted_runner;
+pub mod usage_event;
+pub mod webhook;
+
+pub use approval::{ApprovalDecision, ApprovalRequest, ApprovalStatus, ApprovalVote};
+pub use artifact::{Artifact, SimilarArtifact};
+pub use artifact_derivation::{ArtifactContextRow, ArtifactDerivation, ArtifactLineage, DerivationStatus, LineageNode};
+pub use audit::AuditLogEntry;
+pub use attestation::{ReserveAttestation, ReserveAttestationSnapshot, UnsignedReserveAttestation};
+pub use bounty::{Bounty, BountyEdit, BountyStatus, ClosureType, PatchBountyRequest};
+pub use bounty_template::BountyTemplate;
+pub use compute_provider::ComputeProvider;
+pub use did_binding::{DidBinding, DidChallenge, DidRebinding};
+pub use discrepancy::LedgerDiscrepancy;
+pub use dispute::{Dispute, DisputeOutcome, DisputeStatus, OpenDisputeRequest};
+pub use evidence::{AttachEvidenceRequest, DisputeEvidence};
+pub use escrow::{EscrowHold, EscrowPurpose, EscrowStatus};
+pub use execution_receipt::ExecutionReceipt;
+pub use invoice::{Invoice, InvoiceStatus, PurchaseRequest, PurchaseResponse};
+pub use juror_vote::{JurorVote, StakeJurorVoteRequest};
+pub use key_rotation::DidKeyRotation;
+pub use kyc::{KycStatus, KycVerification};
+pub use manifest::StoredManifest;
+pub use notification_delivery::{NotificationChannel, NotificationDelivery, NotificationDeliveryStatus};
+pub use notification_preference::{NotificationPreferences, UpdateNotificationPreferencesRequest};
+pub use policy::{AccountFreezeEvent, ApprovalTier, PolicyRevision, UserPolicy};
+pub use post::{Comment, Post};
+pub use profile::{Profile, ProfilePublicKey, ProfileView, UpdateProfileRequest};
+pub use promo::PromoGrant;
+pub use purchase_review::{PurchaseReview, PurchaseReviewStatus};
+pub use redemption_receipt::{RedemptionReceipt, RedemptionReceiptStatus};
+pub use reputation::{ReputationEvent, ReputationEventKind};
+pub use reviewer::ReviewerAssignment;
+pub use runner_registration::{RunnerRegistration, RunnerRegistrationStatus};
+pub use server_signing_key::ServerSigningKey;
+pub use similarity_report::{SimilarityMatch, SimilarityMatchType, SubmissionSimilarityReport};
+pub use tag_subscription::TagSubscription;
+pub use trusted_runner::TrustedRunner;
+pub use usage_event::{UsageEvent, UsageMetric};
+pub use webhook::{WebhookDelivery, WebhookDeliveryStatus, WebhookRegistration};
+pub use submission::{ReviewVote, Submission, SubmissionReview, SubmissionStatus, SubmitReviewRequest};
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Per-DID opt-in for which channels and event categories they want to
+/// hear about. Separate from `UserPolicy` because these are preferences a
+/// DID sets for itself, not approval/spend controls an operator sets.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct NotificationPreferences {
+    pub did: String,
+    pub channels: Vec<String>,
+    pub event_types: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub channels: Vec<String>,
+    pub event_types: Vec<String>,
+}
+
+impl NotificationPreferences {
+    pub fn wants(&self, channel: &str, event_type: &str) -> bool {
+        self.channels.iter().any(|c| c == channel) && self.event_types.iter().any(|e| e == event_type)
+    }
+}
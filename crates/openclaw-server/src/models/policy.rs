@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct UserPolicy {
+    pub did: String,
+    pub max_spend_per_day: Option<Decimal>,
+    pub max_spend_per_bounty: Option<Decimal>,
+    pub allowed_delegates: Vec<String>,
+    pub notification_channels: Vec<String>,
+    pub emergency_contact: Value,
+    /// `{"kind": "slack" | "discord", "webhook_url": "..."}`, set when
+    /// `notification_channels` includes `"chat"`.
+    pub chat_webhook: Option<Value>,
+    pub version: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A past `UserPolicy` revision, kept around (with the envelope that
+/// authorized it) so a loosened approval threshold can be audited and, via
+/// `services::policy::rollback`, reverted.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct PolicyRevision {
+    pub id: Uuid,
+    pub did: String,
+    pub version: i32,
+    pub document: Value,
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One entry of `did`'s freeze history, newest first by `created_at`; the
+/// account is currently frozen iff the newest entry has `frozen = true`.
+/// Kept as an append-only log rather than a single flag on `UserPolicy` so
+/// the freeze/unfreeze trail survives independently of unrelated policy
+/// edits.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct AccountFreezeEvent {
+    pub id: Uuid,
+    pub did: String,
+    pub frozen: bool,
+    pub reason: String,
+    pub actor_did: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ApprovalTier {
+    pub id: Uuid,
+    pub did: String,
+    pub name: String,
+    pub approvers: Vec<String>,
+    pub quorum: i32,
+    pub threshold: Decimal,
+    pub notification_channel: Option<String>,
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Post {
+    pub id: Uuid,
+    pub author_did: String,
+    pub body: String,
+    /// Signature over `{action: "create_post", body}`, proving `author_did`
+    /// authored this content rather than just being trusted as the caller.
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Comment {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub author_did: String,
+    pub body: String,
+    /// Signature over `{action: "create_comment", post_id, body}`.
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Self-asserted profile metadata for a DID. Separate from `DidBinding`
+/// (which proves control of the DID) and `NotificationPreferences` (which a
+/// DID sets for itself but never shows to anyone else) — this is the
+/// public-facing record other callers fetch via `GET /api/v1/profile/{did}`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Profile {
+    pub did: String,
+    pub display_name: Option<String>,
+    pub links: Vec<String>,
+    pub avatar_artifact_id: Option<Uuid>,
+    /// Signature over `{action: "update_profile", display_name, links,
+    /// avatar_artifact_id}`, proving `did` authored this update.
+    pub signature: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateProfileRequest {
+    pub display_name: Option<String>,
+    pub links: Vec<String>,
+    pub avatar_artifact_id: Option<Uuid>,
+    pub signature: String,
+}
+
+/// `GET /api/v1/profile/{did}` response: the self-asserted profile plus
+/// facts this server can independently verify, so a caller doesn't have to
+/// cross-reference three endpoints to render one profile page.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ProfileView {
+    pub did: String,
+    pub display_name: Option<String>,
+    pub links: Vec<String>,
+    pub avatar_artifact_id: Option<Uuid>,
+    pub verified_artifact_count: i64,
+    pub reputation_score: rust_decimal::Decimal,
+    pub public_key: ProfilePublicKey,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// A DID-document-shaped public-key record, mirroring
+/// `routes::well_known::did_document` but parameterized by an arbitrary DID
+/// instead of the server's own identity.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ProfilePublicKey {
+    pub id: String,
+    pub verification_method: String,
+    pub public_key_multibase: String,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A promotional credit grant. `remaining` is drawn down as it's spent;
+/// `expires_at` (if set) is when any leftover balance is burned by the
+/// expiry job rather than carried forward indefinitely.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct PromoGrant {
+    pub id: Uuid,
+    pub did: String,
+    pub amount: Decimal,
+    pub remaining: Decimal,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum PurchaseReviewStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A purchase `services::fraud` flagged for a human to clear before any
+/// checkout session is opened. `reasons` records which velocity rule(s)
+/// tripped, so reviewers don't have to re-derive it from raw invoice history.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct PurchaseReview {
+    pub id: Uuid,
+    pub invoice_id: Uuid,
+    pub did: String,
+    pub reasons: Vec<String>,
+    pub status: PurchaseReviewStatus,
+    pub decided_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+}
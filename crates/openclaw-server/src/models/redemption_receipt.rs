@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum RedemptionReceiptStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// A `/credits/redeem` burn and what it bought. `status` starts `pending`
+/// and is updated asynchronously once `services::provider_clients` confirms
+/// (or fails) the allocation with the provider; `external_ref` is the
+/// provider's own identifier for the grant, set once `status` is
+/// `completed`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct RedemptionReceipt {
+    pub id: Uuid,
+    pub did: String,
+    pub provider_id: Uuid,
+    pub amount: Decimal,
+    pub status: RedemptionReceiptStatus,
+    pub external_ref: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ReputationEventKind {
+    Mint,
+    Decay,
+    Slash,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ReputationEvent {
+    pub id: Uuid,
+    pub did: String,
+    pub kind: ReputationEventKind,
+    pub amount: Decimal,
+    pub bounty_id: Option<Uuid>,
+    pub submission_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A reviewer assigned to vote on a quorum-closure submission. Recorded so
+/// the assignment engine can rotate fairly instead of always picking the
+/// same top-reputation reviewers.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ReviewerAssignment {
+    pub id: Uuid,
+    pub submission_id: Uuid,
+    pub reviewer_did: String,
+    pub assigned_at: DateTime<Utc>,
+}
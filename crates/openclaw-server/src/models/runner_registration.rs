@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Status of a runner's application to the global registry. Distinct from
+/// `TrustedRunner`, which is a poster's per-bounty allowlist — this is the
+/// directory submitters consult to see which runners an operator has
+/// vetted at all before a poster ever trusts one for a specific bounty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum RunnerRegistrationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Suspended,
+}
+
+/// A runner DID's application to the global trusted-runner directory,
+/// describing what it's capable of and the operator's decision on it.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct RunnerRegistration {
+    pub id: Uuid,
+    pub runner_did: String,
+    /// Test harnesses this runner can execute, e.g. `"cargo-test"`, `"pytest"`.
+    pub harness_types: Vec<String>,
+    /// Free-form attestation details (hardware, sandboxing, operator identity)
+    /// submitted for the operator to review; not independently verified.
+    pub attestation: Value,
+    pub status: RunnerRegistrationStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
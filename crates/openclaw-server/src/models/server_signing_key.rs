@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A durable record of one generation of the server's own attestation-
+/// signing key, so `identity::rotate` survives a restart or runs safely
+/// across replicas instead of reverting to `SERVER_SIGNING_KEY` on every
+/// boot and forgetting every key rotated in since. `seed_hex` is the same
+/// hex-encoded Ed25519 seed `SERVER_SIGNING_KEY` takes — storing it in the
+/// database is a stopgap until there's a KMS to hold it instead; see
+/// `identity::load_key_from_env`. Deliberately not `Serialize`/`ToSchema`:
+/// nothing should ever put `seed_hex` on the wire or in an audit log.
+#[derive(Debug, Clone, FromRow)]
+pub struct ServerSigningKey {
+    pub id: Uuid,
+    pub key_id: String,
+    pub seed_hex: String,
+    pub did: String,
+    pub activated_at: DateTime<Utc>,
+    pub retired_at: Option<DateTime<Utc>>,
+}
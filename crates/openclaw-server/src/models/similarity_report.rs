@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Why `services::plagiarism` flagged another artifact against a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SimilarityMatchType {
+    /// Byte-identical content, signed by a different DID.
+    ExactHash,
+    /// Near-duplicate content per `openclaw_crypto::fuzzy_hash`.
+    FuzzyHash,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SimilarityMatch {
+    pub artifact_id: Uuid,
+    pub signer_did: String,
+    pub similarity: f64,
+    pub match_type: SimilarityMatchType,
+}
+
+/// A snapshot of what a submission's artifact looked like against the rest
+/// of the registry at submission time, for quorum reviewers and the
+/// requester to weigh before escrow releases. Computed once, at creation;
+/// it isn't recomputed if later-registered artifacts turn out similar.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct SubmissionSimilarityReport {
+    pub id: Uuid,
+    pub submission_id: Uuid,
+    pub matches: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
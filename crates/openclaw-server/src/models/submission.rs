@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum SubmissionStatus {
+    Pending,
+    InReview,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct Submission {
+    pub id: Uuid,
+    pub bounty_id: Uuid,
+    pub submitter_did: String,
+    pub artifact_id: Option<Uuid>,
+    pub status: SubmissionStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ReviewVote {
+    Approve,
+    Reject,
+}
+
+/// One reviewer's vote on a quorum-closure submission.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct SubmissionReview {
+    pub id: Uuid,
+    pub submission_id: Uuid,
+    pub reviewer_did: String,
+    pub vote: ReviewVote,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubmitReviewRequest {
+    pub vote: ReviewVote,
+    pub comment: Option<String>,
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A DID's standing interest in a bounty tag, so `services::tags::notify_subscribers`
+/// knows who to fan a newly posted bounty out to.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct TagSubscription {
+    pub id: Uuid,
+    pub did: String,
+    pub tag: String,
+    pub created_at: DateTime<Utc>,
+}
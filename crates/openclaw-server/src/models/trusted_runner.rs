@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A runner DID the poster trusts to report execution results for a
+/// `ClosureType::Test` bounty. Scoped per bounty, same as `PatchBountyRequest`
+/// changes, rather than a global registry — the poster is the only one with
+/// authority over who can close their own bounty.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct TrustedRunner {
+    pub id: Uuid,
+    pub bounty_id: Uuid,
+    pub runner_did: String,
+    pub added_by: String,
+    pub created_at: DateTime<Utc>,
+}
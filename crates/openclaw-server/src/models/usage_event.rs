@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum UsageMetric {
+    ApiRequest,
+    ArtifactRegistered,
+    SubmissionCreated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct UsageEvent {
+    pub id: Uuid,
+    pub did: String,
+    pub metric: UsageMetric,
+    pub created_at: DateTime<Utc>,
+}
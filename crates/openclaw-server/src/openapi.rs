@@ -0,0 +1,309 @@
+//! Aggregates every route's `#[utoipa::path]` annotation into one OpenAPI
+//! document, served at `/api/v1/openapi.json` with a Swagger UI mounted at
+//! `/swagger-ui` so integrators can explore the API without reading source.
+
+use utoipa::OpenApi;
+
+use crate::config::RuntimeConfig;
+use crate::pagination::{ApprovalRequestPage, ArtifactPage, BountyPage, LedgerEntryPage};
+use crate::models::{
+    Artifact, ArtifactDerivation, ArtifactLineage, ApprovalRequest, ApprovalVote, AuditLogEntry, Bounty,
+    BountyTemplate, Comment, ComputeProvider, DidBinding, DidKeyRotation, DidRebinding, Dispute, DisputeEvidence,
+    ExecutionReceipt, Invoice, InvoiceStatus, JurorVote, LedgerDiscrepancy, NotificationDelivery, NotificationPreferences, Post, Profile,
+    KycStatus, KycVerification, ProfilePublicKey, ProfileView, PromoGrant, PurchaseResponse, PurchaseReview, PurchaseReviewStatus,
+    RedemptionReceipt, RedemptionReceiptStatus, StakeJurorVoteRequest, SimilarArtifact,
+    ReputationEvent, ReserveAttestation, AccountFreezeEvent, PolicyRevision,
+    OpenDisputeRequest, ReserveAttestationSnapshot, RunnerRegistration, StoredManifest, Submission, SubmissionSimilarityReport, TagSubscription,
+    TrustedRunner, UpdateProfileRequest, UserPolicy, WebhookDelivery,
+};
+use crate::routes::approvals::{CreateApprovalRequest, DecideApprovalRequest, ListApprovalsQuery};
+use crate::routes::admin::{
+    AuditLogQuery, DecidePurchaseReviewRequest, ListPurchaseReviewsQuery, RebuildLedgerProjectionQuery,
+    RebuildLedgerProjectionResponse, TreasuryBalanceResponse,
+};
+use crate::routes::compute_providers::{CreateComputeProviderRequest, UpdateComputeProviderRequest};
+use crate::routes::identity::{
+    BindChallengeRequest, BindChallengeResponse, BindRequest, RebindRequest, RevokeBindingRequest,
+    RotateKeyRequest,
+};
+use crate::routes::artifacts::{
+    ListArtifactsQuery, PresignedUrlResponse, RegisterArtifactRequest, ResolveDerivationRequest,
+    RevokeArtifactRequest,
+};
+use crate::routes::bounties::{AddTrustedRunnerRequest, CreateBountyRequest, ListBountiesQuery};
+use crate::routes::bounty_templates::{CreateBountyTemplateRequest, InstantiateBountyTemplateRequest};
+use crate::routes::credits::{
+    ApplePayChargeRequest, ListInvoicesQuery, ListRedemptionsQuery, RedeemRequest, RedeemResponse,
+    ValidateMerchantRequest,
+};
+use crate::routes::disputes::ResolveDisputeRequest;
+use crate::routes::ledger::{LedgerEntry, LedgerQuery, PublicLedgerEvent};
+use crate::routes::posts::{
+    CreateCommentRequest, CreatePostRequest, ListCommentsQuery, ListPostsQuery,
+};
+use crate::routes::reputation::{
+    ClosureTypeBreakdown, LeaderboardEntry, LeaderboardQuery, ReputationHistoryQuery,
+};
+use crate::routes::policies::{FreezeAccountRequest, RollbackPolicyRequest, SetPolicyRequest, UnfreezeAccountRequest};
+use crate::routes::promo::{GrantPromoBatchRequest, GrantPromoBatchResponse, GrantPromoBatchResult, GrantPromoRequest};
+use crate::routes::runners::{RegisterRunnerRequest, SetRunnerStatusRequest};
+use crate::routes::submissions::{
+    CreateSubmissionRequest, ExecutionReceiptRequest, ListBountySubmissionsQuery,
+    ListSubmissionsQuery, ReviewResponse, SelectWinnerRequest,
+};
+use crate::routes::usage::UsageResponse;
+use crate::services::policy::ApprovalTierInput;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::routes::bounties::create_bounty,
+        crate::routes::bounties::list_bounties,
+        crate::routes::bounties::get_bounty,
+        crate::routes::bounties::patch_bounty,
+        crate::routes::bounty_templates::create_template,
+        crate::routes::bounty_templates::list_templates,
+        crate::routes::bounty_templates::instantiate,
+        crate::routes::submissions::create_submission,
+        crate::routes::submissions::review_submission,
+        crate::routes::submissions::select_winner,
+        crate::routes::submissions::withdraw,
+        crate::routes::submissions::list_bounty_submissions,
+        crate::routes::submissions::list_submissions,
+        crate::routes::submissions::submit_execution_receipt,
+        crate::routes::submissions::get_similarity_report,
+        crate::routes::bounties::add_trusted_runner,
+        crate::routes::bounties::list_trusted_runners,
+        crate::routes::runners::register,
+        crate::routes::runners::list,
+        crate::routes::runners::set_status,
+        crate::routes::approvals::list,
+        crate::routes::approvals::create,
+        crate::routes::approvals::decide,
+        crate::routes::disputes::create_dispute,
+        crate::routes::disputes::resolve_dispute,
+        crate::routes::disputes::attach_evidence,
+        crate::routes::disputes::list_evidence,
+        crate::routes::disputes::stake_juror_vote,
+        crate::routes::disputes::list_jurors,
+        crate::routes::events::stream_events,
+        crate::routes::admin::list_webhook_failures,
+        crate::routes::admin::replay_webhook_failure,
+        crate::routes::admin::list_notification_failures,
+        crate::routes::admin::replay_notification_failure,
+        crate::routes::admin::list_ledger_discrepancies,
+        crate::routes::admin::rebuild_ledger_projection,
+        crate::routes::admin::get_config,
+        crate::routes::admin::get_treasury_balance,
+        crate::routes::admin::list_audit_log,
+        crate::routes::admin::list_purchase_reviews,
+        crate::routes::admin::decide_purchase_review,
+        crate::routes::compute_providers::create,
+        crate::routes::compute_providers::list,
+        crate::routes::compute_providers::update,
+        crate::routes::compute_providers::deactivate,
+        crate::routes::compute_providers::activate,
+        crate::routes::notifications::get_preferences,
+        crate::routes::notifications::put_preferences,
+        crate::routes::credits::purchase,
+        crate::routes::credits::redeem_credits,
+        crate::routes::credits::list_redemptions,
+        crate::routes::credits::refresh_kyc,
+        crate::routes::credits::list_invoices,
+        crate::routes::credits::get_invoice,
+        crate::routes::credits::stripe_webhook,
+        crate::routes::credits::apple_pay_validate_merchant,
+        crate::routes::credits::apple_pay_charge,
+        crate::routes::promo::grant_promo,
+        crate::routes::promo::grant_promo_batch,
+        crate::routes::credits::get_reserve_attestation,
+        crate::routes::credits::verify_reserve_attestation,
+        crate::routes::credits::reserve_attestation_history,
+        crate::routes::ledger::query_ledger,
+        crate::routes::ledger::stream_public_ledger,
+        crate::routes::reputation::leaderboard,
+        crate::routes::reputation::history,
+        crate::routes::posts::create_post,
+        crate::routes::posts::list_posts,
+        crate::routes::posts::create_comment,
+        crate::routes::posts::list_comments,
+        crate::routes::profile::put_profile,
+        crate::routes::profile::get_profile,
+        crate::routes::tags::list_taxonomy,
+        crate::routes::tags::list_subscriptions,
+        crate::routes::tags::subscribe,
+        crate::routes::tags::unsubscribe,
+        crate::routes::well_known::did_document,
+        crate::routes::well_known::resolve_did,
+        crate::routes::identity::bind_challenge,
+        crate::routes::identity::bind,
+        crate::routes::identity::revoke_binding,
+        crate::routes::identity::rebind,
+        crate::routes::identity::rotate,
+        crate::routes::policies::get_policy,
+        crate::routes::policies::set_policy,
+        crate::routes::policies::get_policy_history,
+        crate::routes::policies::rollback_policy,
+        crate::routes::policies::freeze,
+        crate::routes::policies::unfreeze,
+        crate::routes::artifacts::register_artifact,
+        crate::routes::artifacts::list_artifacts,
+        crate::routes::artifacts::get_by_hash,
+        crate::routes::artifacts::check_by_hash,
+        crate::routes::artifacts::get_lineage,
+        crate::routes::artifacts::get_similar,
+        crate::routes::artifacts::resolve_derivation,
+        crate::routes::artifacts::revoke,
+        crate::routes::artifacts::get_upload_url,
+        crate::routes::artifacts::get_download_url,
+        crate::routes::artifacts::confirm_upload,
+        crate::routes::manifests::submit_manifest,
+        crate::routes::manifests::get_manifest,
+        crate::routes::health::healthz,
+        crate::routes::health::readyz,
+        crate::routes::usage::get_usage,
+    ),
+    components(schemas(
+        Artifact,
+        ArtifactLineage,
+        SimilarArtifact,
+        RegisterArtifactRequest,
+        ListArtifactsQuery,
+        PresignedUrlResponse,
+        ArtifactDerivation,
+        ResolveDerivationRequest,
+        RevokeArtifactRequest,
+        Bounty,
+        CreateBountyRequest,
+        ListBountiesQuery,
+        BountyTemplate,
+        CreateBountyTemplateRequest,
+        InstantiateBountyTemplateRequest,
+        TagSubscription,
+        Submission,
+        SubmissionSimilarityReport,
+        CreateSubmissionRequest,
+        ReviewResponse,
+        SelectWinnerRequest,
+        ListBountySubmissionsQuery,
+        ListSubmissionsQuery,
+        ExecutionReceipt,
+        ExecutionReceiptRequest,
+        TrustedRunner,
+        AddTrustedRunnerRequest,
+        RunnerRegistration,
+        RegisterRunnerRequest,
+        SetRunnerStatusRequest,
+        ApprovalRequest,
+        ApprovalVote,
+        ListApprovalsQuery,
+        CreateApprovalRequest,
+        DecideApprovalRequest,
+        Dispute,
+        DisputeEvidence,
+        OpenDisputeRequest,
+        ResolveDisputeRequest,
+        JurorVote,
+        StakeJurorVoteRequest,
+        WebhookDelivery,
+        NotificationDelivery,
+        LedgerDiscrepancy,
+        NotificationPreferences,
+        Invoice,
+        InvoiceStatus,
+        ListInvoicesQuery,
+        PurchaseResponse,
+        RedeemRequest,
+        RedeemResponse,
+        ReserveAttestation,
+        ReserveAttestationSnapshot,
+        LedgerEntry,
+        LedgerQuery,
+        PublicLedgerEvent,
+        StoredManifest,
+        RuntimeConfig,
+        AuditLogEntry,
+        AuditLogQuery,
+        RebuildLedgerProjectionQuery,
+        RebuildLedgerProjectionResponse,
+        TreasuryBalanceResponse,
+        DidBinding,
+        DidRebinding,
+        BindChallengeRequest,
+        BindChallengeResponse,
+        BindRequest,
+        RevokeBindingRequest,
+        RebindRequest,
+        DidKeyRotation,
+        RotateKeyRequest,
+        LeaderboardEntry,
+        LeaderboardQuery,
+        ClosureTypeBreakdown,
+        ReputationEvent,
+        ReputationHistoryQuery,
+        Post,
+        CreatePostRequest,
+        ListPostsQuery,
+        Comment,
+        CreateCommentRequest,
+        ListCommentsQuery,
+        Profile,
+        ProfileView,
+        ProfilePublicKey,
+        UpdateProfileRequest,
+        UserPolicy,
+        ApprovalTierInput,
+        SetPolicyRequest,
+        PolicyRevision,
+        RollbackPolicyRequest,
+        AccountFreezeEvent,
+        FreezeAccountRequest,
+        UnfreezeAccountRequest,
+        ComputeProvider,
+        CreateComputeProviderRequest,
+        UpdateComputeProviderRequest,
+        RedemptionReceipt,
+        RedemptionReceiptStatus,
+        ListRedemptionsQuery,
+        PromoGrant,
+        GrantPromoRequest,
+        GrantPromoBatchRequest,
+        GrantPromoBatchResult,
+        GrantPromoBatchResponse,
+        PurchaseReview,
+        PurchaseReviewStatus,
+        ListPurchaseReviewsQuery,
+        DecidePurchaseReviewRequest,
+        KycVerification,
+        KycStatus,
+        ValidateMerchantRequest,
+        ApplePayChargeRequest,
+        BountyPage,
+        ArtifactPage,
+        LedgerEntryPage,
+        ApprovalRequestPage,
+        UsageResponse,
+    )),
+    tags(
+        (name = "artifacts", description = "Content-addressed, signed artifact registry"),
+        (name = "bounties", description = "Bounty lifecycle"),
+        (name = "submissions", description = "Bounty submissions and reviews"),
+        (name = "disputes", description = "Dispute resolution"),
+        (name = "credits", description = "Credits, purchases, and redemptions"),
+        (name = "notifications", description = "Per-DID notification preferences"),
+        (name = "manifests", description = "Signed contribution manifests"),
+        (name = "admin", description = "Operator-facing diagnostics"),
+        (name = "events", description = "Server-sent event streams"),
+        (name = "identity", description = "Server identity and DID resolution"),
+        (name = "reputation", description = "Reputation scores and leaderboards"),
+        (name = "posts", description = "Public posts feed and comments"),
+        (name = "profile", description = "Per-DID public profile metadata"),
+        (name = "tags", description = "Bounty category taxonomy and tag subscriptions"),
+        (name = "runners", description = "Global execution-runner registry"),
+        (name = "approvals", description = "Multi-approver sign-off on over-limit actions"),
+        (name = "policies", description = "Per-DID spend policy and approval tiers"),
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "usage", description = "Per-DID API usage metering and quotas"),
+    )
+)]
+pub struct ApiDoc;
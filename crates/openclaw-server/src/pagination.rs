@@ -0,0 +1,62 @@
+//! Shared cursor pagination so every listing endpoint exposes the same
+//! shape: a clamped `limit`, an opaque `cursor` string instead of a raw row
+//! id on the wire, and a `Page<T>` envelope carrying `next_cursor`.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+
+pub const DEFAULT_LIMIT: i64 = 50;
+pub const MAX_LIMIT: i64 = 500;
+
+/// Clamps a client-supplied `limit` into `[1, MAX_LIMIT]`, defaulting to
+/// `DEFAULT_LIMIT` when omitted.
+pub fn clamp_limit(limit: Option<i64>) -> i64 {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+/// Encodes a row id as the opaque cursor clients pass back in `cursor`,
+/// so pagination state isn't a raw, enumerable uuid on the wire.
+pub fn encode_cursor(id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(id.as_bytes())
+}
+
+/// Decodes a cursor produced by `encode_cursor`. A malformed cursor is a
+/// client error, not a 500.
+pub fn decode_cursor(cursor: &str) -> AppResult<Uuid> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| AppError::BadRequest("invalid cursor".into()))?;
+    Uuid::from_slice(&bytes).map_err(|_| AppError::BadRequest("invalid cursor".into()))
+}
+
+/// Standard listing response: the page of items plus the cursor to pass
+/// back for the next page, or `None` once the caller has reached the end.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[aliases(
+    BountyPage = Page<crate::models::Bounty>,
+    ArtifactPage = Page<crate::models::Artifact>,
+    LedgerEntryPage = Page<crate::routes::ledger::LedgerEntry>,
+    ApprovalRequestPage = Page<crate::models::ApprovalRequest>,
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from rows fetched with `LIMIT limit`, deriving
+    /// `next_cursor` from the last row's id when a full page came back
+    /// (a short page means there's nothing left to fetch).
+    pub fn new(items: Vec<T>, limit: i64, last_id: impl Fn(&T) -> Uuid) -> Self {
+        let next_cursor = if items.len() as i64 == limit {
+            items.last().map(|item| encode_cursor(last_id(item)))
+        } else {
+            None
+        };
+        Self { items, next_cursor }
+    }
+}
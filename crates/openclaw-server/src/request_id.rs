@@ -0,0 +1,59 @@
+//! Per-request IDs: generated (or taken from an inbound `x-request-id`
+//! header) by `tower_http`, carried through the tracing span every log line
+//! in that request's lifetime inherits from, and echoed back on the
+//! response — including error responses, since the span wraps the whole
+//! service chain — so an incident can be correlated across services by one
+//! ID.
+
+use std::time::Duration;
+
+use axum::extract::{MatchedPath, Request};
+use axum::http::HeaderName;
+use axum::response::Response;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, RequestId, SetRequestIdLayer};
+use tracing::Span;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Assigns `x-request-id` to requests that don't already carry one.
+pub fn set_request_id_layer() -> SetRequestIdLayer<MakeRequestUuid> {
+    SetRequestIdLayer::new(REQUEST_ID_HEADER, MakeRequestUuid)
+}
+
+/// Copies the request's `x-request-id` onto every response, success or
+/// error.
+pub fn propagate_request_id_layer() -> PropagateRequestIdLayer {
+    PropagateRequestIdLayer::new(REQUEST_ID_HEADER)
+}
+
+/// Opens one span per request carrying the request ID, method, and matched
+/// route; `status`/`latency_ms` are filled in by `on_response` once the
+/// response is ready.
+pub fn make_span(req: &Request) -> Span {
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .and_then(|id| id.header_value().to_str().ok())
+        .unwrap_or("unknown")
+        .to_string();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str())
+        .unwrap_or_else(|| req.uri().path());
+    tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        route = %route,
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+/// Records status and latency onto the request's span and logs completion.
+pub fn on_response<B>(response: &Response<B>, latency: Duration, span: &Span) {
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency.as_millis() as u64);
+    tracing::info!(parent: span, "request completed");
+}
@@ -0,0 +1,336 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::AuthenticatedDid;
+use crate::config::{self, RuntimeConfig};
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::jobs::{notification_delivery, webhook_delivery};
+use crate::models::{
+    AuditLogEntry, LedgerDiscrepancy, NotificationDelivery, PurchaseReview, PurchaseReviewStatus,
+    WebhookDelivery,
+};
+use crate::services::{audit, fees, fraud, ledger_projection};
+
+fn is_admin(did: &str) -> bool {
+    std::env::var("ADMIN_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+/// `GET /api/v1/admin/webhooks/failures` — deliveries that exhausted their
+/// retry budget, for operators to inspect or replay manually.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/webhooks/failures",
+    responses((status = 200, description = "Exhausted webhook deliveries", body = Vec<WebhookDelivery>)),
+    tag = "admin"
+)]
+pub async fn list_webhook_failures(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<WebhookDelivery>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let failures = webhook_delivery::list_failures(&db).await?;
+    Ok(Json(failures))
+}
+
+/// `POST /api/v1/admin/webhooks/failures/{id}/replay` — dead-lettered
+/// deliveries don't retry themselves; this re-queues one with a fresh
+/// attempt budget for the next sweep to pick up.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/webhooks/failures/{id}/replay",
+    params(("id" = uuid::Uuid, Path, description = "Webhook delivery id")),
+    responses((status = 200, description = "Delivery re-queued", body = WebhookDelivery)),
+    tag = "admin"
+)]
+pub async fn replay_webhook_failure(
+    State(db): State<Db>,
+    Path(id): Path<uuid::Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<WebhookDelivery>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let delivery = webhook_delivery::replay(&db, id)
+        .await
+        .map_err(|_| AppError::NotFound)?;
+    audit::record(
+        &db,
+        &caller_did,
+        "replay_webhook_delivery",
+        "webhook_delivery",
+        id,
+        None,
+        Some(serde_json::json!(&delivery)),
+    )
+    .await?;
+    Ok(Json(delivery))
+}
+
+/// `GET /api/v1/admin/notifications/failures` — email/chat notifications
+/// that exhausted their retry budget, for operators to inspect or replay.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/notifications/failures",
+    responses((status = 200, description = "Exhausted notification deliveries", body = Vec<NotificationDelivery>)),
+    tag = "admin"
+)]
+pub async fn list_notification_failures(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<NotificationDelivery>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let failures = notification_delivery::list_failures(&db).await?;
+    Ok(Json(failures))
+}
+
+/// `POST /api/v1/admin/notifications/failures/{id}/replay` — re-queues a
+/// dead-lettered email/chat notification with a fresh attempt budget.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/notifications/failures/{id}/replay",
+    params(("id" = uuid::Uuid, Path, description = "Notification delivery id")),
+    responses((status = 200, description = "Notification re-queued", body = NotificationDelivery)),
+    tag = "admin"
+)]
+pub async fn replay_notification_failure(
+    State(db): State<Db>,
+    Path(id): Path<uuid::Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<NotificationDelivery>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let delivery = notification_delivery::replay(&db, id)
+        .await
+        .map_err(|_| AppError::NotFound)?;
+    audit::record(
+        &db,
+        &caller_did,
+        "replay_notification_delivery",
+        "notification_delivery",
+        id,
+        None,
+        Some(serde_json::json!(&delivery)),
+    )
+    .await?;
+    Ok(Json(delivery))
+}
+
+/// `GET /api/v1/admin/ledger/discrepancies` — mismatches the nightly
+/// reconciliation job found between `m_credits_ledger` and
+/// `m_credits_accounts`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/ledger/discrepancies",
+    responses((status = 200, description = "Ledger/account balance mismatches", body = Vec<LedgerDiscrepancy>)),
+    tag = "admin"
+)]
+pub async fn list_ledger_discrepancies(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<LedgerDiscrepancy>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let discrepancies = sqlx::query_as::<_, LedgerDiscrepancy>(
+        "SELECT * FROM ledger_discrepancies ORDER BY detected_at DESC",
+    )
+    .fetch_all(&db)
+    .await?;
+    Ok(Json(discrepancies))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct RebuildLedgerProjectionQuery {
+    /// Rebuilds only this DID's balance; omit to rebuild every account.
+    pub did: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RebuildLedgerProjectionResponse {
+    pub accounts_rebuilt: u64,
+}
+
+/// `POST /api/v1/admin/ledger/rebuild` — recomputes one (or every)
+/// account's `m_credits_accounts.balance` from `m_credits_ledger`,
+/// overwriting whatever discrepancy `jobs::reconciliation` flagged. See
+/// `services::ledger_projection` for why this is a repair tool rather
+/// than the account table's only write path.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/ledger/rebuild",
+    params(RebuildLedgerProjectionQuery),
+    responses((status = 200, description = "Accounts rebuilt from the ledger", body = RebuildLedgerProjectionResponse)),
+    tag = "admin"
+)]
+pub async fn rebuild_ledger_projection(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Query(q): Query<RebuildLedgerProjectionQuery>,
+) -> AppResult<Json<RebuildLedgerProjectionResponse>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+
+    let accounts_rebuilt = match q.did {
+        Some(did) => {
+            ledger_projection::rebuild_one(&db, &did).await?;
+            1
+        }
+        None => ledger_projection::rebuild_all(&db).await?,
+    };
+
+    audit::record(
+        &db,
+        &caller_did,
+        "rebuild_ledger_projection",
+        "m_credits_accounts",
+        uuid::Uuid::nil(),
+        None,
+        Some(serde_json::json!({ "accounts_rebuilt": accounts_rebuilt })),
+    )
+    .await?;
+
+    Ok(Json(RebuildLedgerProjectionResponse { accounts_rebuilt }))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListPurchaseReviewsQuery {
+    pub status: Option<PurchaseReviewStatus>,
+}
+
+/// `GET /api/v1/admin/purchase-reviews` — purchases `services::fraud`
+/// flagged for velocity or payer/DID mismatch, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/purchase-reviews",
+    params(ListPurchaseReviewsQuery),
+    responses((status = 200, description = "Flagged purchases awaiting review", body = Vec<PurchaseReview>)),
+    tag = "admin"
+)]
+pub async fn list_purchase_reviews(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Query(q): Query<ListPurchaseReviewsQuery>,
+) -> AppResult<Json<Vec<PurchaseReview>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    Ok(Json(fraud::list_reviews(&db, q.status).await?))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct DecidePurchaseReviewRequest {
+    pub approve: bool,
+}
+
+/// `POST /api/v1/admin/purchase-reviews/{id}/decide` — clears or rejects a
+/// flagged purchase. Approving does not retroactively open a checkout
+/// session; the buyer re-submits `/credits/purchase` once cleared.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/purchase-reviews/{id}/decide",
+    params(("id" = uuid::Uuid, Path, description = "Review to decide")),
+    request_body = DecidePurchaseReviewRequest,
+    responses((status = 200, description = "Review decided", body = PurchaseReview)),
+    tag = "admin"
+)]
+pub async fn decide_purchase_review(
+    State(db): State<Db>,
+    Path(id): Path<uuid::Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<DecidePurchaseReviewRequest>,
+) -> AppResult<Json<PurchaseReview>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let review = fraud::decide_review(&db, id, req.approve, &caller_did).await?;
+    audit::record(
+        &db,
+        &caller_did,
+        "decide_purchase_review",
+        "purchase_review",
+        id,
+        None,
+        Some(serde_json::json!(&review)),
+    )
+    .await?;
+    Ok(Json(review))
+}
+
+/// `GET /api/v1/admin/config` — the runtime configuration actually in
+/// effect for this process, for operators to confirm an env/file override
+/// took.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/config",
+    responses((status = 200, description = "Effective runtime configuration", body = RuntimeConfig)),
+    tag = "admin"
+)]
+pub async fn get_config(AuthenticatedDid(caller_did): AuthenticatedDid) -> AppResult<Json<RuntimeConfig>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    Ok(Json(config::current().clone()))
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TreasuryBalanceResponse {
+    pub balance: rust_decimal::Decimal,
+}
+
+/// `GET /api/v1/admin/treasury` — the platform treasury's current balance,
+/// accumulated from `services::fees` on bounty payouts and redemptions.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/treasury",
+    responses((status = 200, description = "Platform treasury balance", body = TreasuryBalanceResponse)),
+    tag = "admin"
+)]
+pub async fn get_treasury_balance(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<TreasuryBalanceResponse>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let balance = fees::treasury_balance(&db).await?;
+    Ok(Json(TreasuryBalanceResponse { balance }))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct AuditLogQuery {
+    pub entity_type: Option<String>,
+    pub actor_did: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/admin/audit-log` — the append-only record of mutating
+/// operations (bounty, escrow, approval, and credit actions), newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit-log",
+    params(AuditLogQuery),
+    responses((status = 200, description = "Audit log entries, newest first", body = Vec<AuditLogEntry>)),
+    tag = "admin"
+)]
+pub async fn list_audit_log(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Query(q): Query<AuditLogQuery>,
+) -> AppResult<Json<Vec<AuditLogEntry>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+    let limit = q.limit.unwrap_or(100).clamp(1, 1000);
+    let entries = audit::list(&db, q.entity_type.as_deref(), q.actor_did.as_deref(), limit).await?;
+    Ok(Json(entries))
+}
@@ -0,0 +1,154 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{ApprovalRequest, ApprovalStatus, ApprovalVote};
+use crate::pagination;
+use crate::services::approvals;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateApprovalRequest {
+    /// Identifies what's being asked for (e.g. `"api_spend"`,
+    /// `"deployment"`, `"transfer"`) so approvers and `services::approvals`
+    /// know how to read `action_payload`; `create_bounty` is reserved for
+    /// the server's own bounty-approval flow and not accepted here.
+    pub action_type: String,
+    pub action_payload: serde_json::Value,
+    /// Governs which `ApprovalTier` is matched, the same way a bounty's
+    /// `reward` does. Callers with nothing naturally denominated in credits
+    /// (a deployment, say) can leave this unset to fall back to the
+    /// requester's default tier.
+    pub amount: Option<Decimal>,
+}
+
+impl crate::validation::Validate for CreateApprovalRequest {
+    fn validate(&self) -> AppResult<()> {
+        crate::validation::non_empty(&self.action_type, "action_type")?;
+        crate::validation::max_len(&self.action_type, 100, "action_type")?;
+        if self.action_type == "create_bounty" {
+            return Err(crate::error::AppError::BadRequest(
+                "create_bounty approvals are filed by POST /api/v1/bounties, not this endpoint".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `POST /api/v1/approvals` — lets any integrated agent file an approval
+/// request for an action outside this server's own domain (an API spend, a
+/// deployment, a transfer — anything `action_type` names), using the same
+/// tier lookup, quorum voting, and `GET /api/v1/approvals` inbox that
+/// `bounties::create` uses internally. Unlike `create_bounty`, there's no
+/// corresponding row here for `services::approvals::apply` to flip out of
+/// `pending_approval` — approval of a generic action is just a signal; the
+/// caller is expected to poll `GET /api/v1/approvals` (or its own
+/// notifications) and carry the action out itself once `status` is
+/// `approved`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/approvals",
+    request_body = CreateApprovalRequest,
+    responses((status = 200, description = "Approval request filed", body = ApprovalRequest)),
+    tag = "approvals"
+)]
+pub async fn create(
+    State(db): State<Db>,
+    AuthenticatedDid(requester_did): AuthenticatedDid,
+    Json(req): Json<CreateApprovalRequest>,
+) -> AppResult<Json<ApprovalRequest>> {
+    use crate::validation::Validate;
+    req.validate()?;
+
+    // Unlike `bounties::create`, there's no built-in operator to fall back
+    // to here — this is a generic action with no poster already on record.
+    // Defaulting an untiered requester to approving themselves would let
+    // any agent file a request and immediately `decide()` it with its own
+    // signature, so an `ApprovalTier` is required rather than assumed.
+    let tier = approvals::tier_for_amount(&db, &requester_did, req.amount.unwrap_or_default())
+        .await?
+        .ok_or_else(|| {
+            crate::error::AppError::BadRequest(
+                "no approval tier configured for this DID; an operator must set one via services::policy before filing generic approval requests".into(),
+            )
+        })?;
+    let (approver_dids, quorum) = (tier.approvers, tier.quorum);
+
+    let request = approvals::request_approval(
+        &db,
+        &requester_did,
+        approver_dids,
+        quorum,
+        &req.action_type,
+        req.action_payload,
+    )
+    .await?;
+    Ok(Json(request))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListApprovalsQuery {
+    /// Only requests `operator` is a designated approver for.
+    pub operator: Option<String>,
+    pub status: Option<ApprovalStatus>,
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/approvals` — an operator's inbox of approval requests,
+/// soonest-expiring first so nothing lapses unnoticed. `operator` filters to
+/// requests where the DID is one of the (possibly several) `approvers`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/approvals",
+    params(ListApprovalsQuery),
+    responses((status = 200, description = "Page of approval requests, soonest-expiring first", body = pagination::ApprovalRequestPage)),
+    tag = "approvals"
+)]
+pub async fn list(
+    Query(q): Query<ListApprovalsQuery>,
+) -> AppResult<Json<pagination::Page<ApprovalRequest>>> {
+    let limit = pagination::clamp_limit(q.limit);
+    let cursor = q.cursor.as_deref().map(pagination::decode_cursor).transpose()?;
+    let requests = approvals::list(crate::db::replica(), q.operator.as_deref(), q.status, cursor, Some(limit)).await?;
+    Ok(Json(pagination::Page::new(requests, limit, |r| r.id)))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct DecideApprovalRequest {
+    pub approver_did: String,
+    pub vote: ApprovalVote,
+    pub expires_at: DateTime<Utc>,
+    pub signature: String,
+}
+
+/// `POST /api/v1/approvals/{id}/decide` — one of the request's designated
+/// approvers casts a vote. Not `AuthenticatedDid`-gated: `signature` must be
+/// over `{action: "approval_decision", approval_request_id, vote,
+/// expires_at}`, signed by `approver_did`, so a decision can't be forged by
+/// anyone who merely knows an approver's DID. Once `quorum` approve votes
+/// are in, the underlying action (e.g. opening a `pending_approval` bounty
+/// and holding its escrow) runs automatically; once quorum can no longer be
+/// reached, the request is rejected outright.
+#[utoipa::path(
+    post,
+    path = "/api/v1/approvals/{id}/decide",
+    params(("id" = Uuid, Path, description = "Approval request ID")),
+    request_body = DecideApprovalRequest,
+    responses((status = 200, description = "Updated approval request", body = ApprovalRequest)),
+    tag = "approvals"
+)]
+pub async fn decide(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<DecideApprovalRequest>,
+) -> AppResult<Json<ApprovalRequest>> {
+    let request = approvals::decide(&db, id, &req.approver_did, req.vote, req.expires_at, &req.signature).await?;
+    Ok(Json(request))
+}
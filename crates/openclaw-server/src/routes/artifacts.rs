@@ -0,0 +1,294 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use openclaw_crypto::types::SignatureEnvelopeV1;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{Artifact, ArtifactDerivation, ArtifactLineage, DerivationStatus, SimilarArtifact};
+use crate::pagination;
+use crate::services::{artifacts, key_rotation, storage};
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RegisterArtifactRequest {
+    pub signature_envelope: SignatureEnvelopeV1,
+    #[serde(default)]
+    pub derived_from: Vec<String>,
+}
+
+/// `POST /api/v1/artifacts` — registers an artifact out of band, instead of
+/// only as a side effect of a bounty's auto-approval. Verifies the envelope
+/// signature server-side and dedupes by content hash.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artifacts",
+    request_body = RegisterArtifactRequest,
+    responses((status = 200, description = "Artifact registered (or the existing one, if already registered)", body = Artifact)),
+    tag = "artifacts"
+)]
+pub async fn register_artifact(
+    State(db): State<Db>,
+    Json(req): Json<RegisterArtifactRequest>,
+) -> AppResult<Json<Artifact>> {
+    let artifact = artifacts::register(&db, req.signature_envelope, req.derived_from).await?;
+    crate::services::usage::record(&db, &artifact.signer_did, crate::models::UsageMetric::ArtifactRegistered).await?;
+    Ok(Json(artifact))
+}
+
+/// `GET /api/v1/artifacts/by-hash/{sha256}` — all artifacts registered
+/// under a given content hash, so a downstream consumer holding a binary
+/// can ask "is this attributed, and to whom?" Usually a single row; more
+/// than one means multiple signers independently registered the same bytes.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts/by-hash/{sha256}",
+    params(("sha256" = String, Path, description = "Content hash to look up")),
+    responses((status = 200, description = "Artifacts registered under this hash", body = Vec<Artifact>)),
+    tag = "artifacts"
+)]
+pub async fn get_by_hash(State(db): State<Db>, Path(sha256): Path<String>) -> AppResult<Json<Vec<Artifact>>> {
+    let artifacts = artifacts::find_by_hash(&db, &sha256).await?;
+    Ok(Json(artifacts))
+}
+
+/// `HEAD /api/v1/artifacts/by-hash/{sha256}` — cheap existence check: `200`
+/// with a `X-Registered-At` header and no body if something is already
+/// registered under this hash, `404` otherwise. Lets a client dedupe before
+/// uploading a large envelope or the content itself.
+#[utoipa::path(
+    head,
+    path = "/api/v1/artifacts/by-hash/{sha256}",
+    params(("sha256" = String, Path, description = "Content hash to check")),
+    responses(
+        (status = 200, description = "Already registered; see the X-Registered-At header"),
+        (status = 404, description = "Nothing registered under this hash"),
+    ),
+    tag = "artifacts"
+)]
+pub async fn check_by_hash(State(db): State<Db>, Path(sha256): Path<String>) -> AppResult<Response> {
+    let registered_at = artifacts::earliest_registration(&db, &sha256).await?;
+    let Some(registered_at) = registered_at else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    let mut response = StatusCode::OK.into_response();
+    response.headers_mut().insert(
+        "x-registered-at",
+        HeaderValue::from_str(&registered_at.to_rfc3339()).map_err(|e| AppError::Internal(e.into()))?,
+    );
+    Ok(response)
+}
+
+/// `GET /api/v1/artifacts/{id}/lineage` — ancestors and descendants of an
+/// artifact through `artifact_derivations`, with signer and bounty context
+/// per node, for rendering a derivation tree.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts/{id}/lineage",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    responses((status = 200, description = "Ancestors and descendants of the artifact", body = ArtifactLineage)),
+    tag = "artifacts"
+)]
+pub async fn get_lineage(Path(artifact_id): Path<Uuid>) -> AppResult<Json<ArtifactLineage>> {
+    let lineage = artifacts::lineage(crate::db::replica(), artifact_id).await?;
+    Ok(Json(lineage))
+}
+
+/// `GET /api/v1/artifacts/{id}/similar` — other registered artifacts whose
+/// uploaded content is a near-duplicate of this one (see
+/// `services::artifacts::find_similar`), for flagging trivially-modified
+/// resubmissions in quorum review and dispute evidence. Empty if this
+/// artifact's content hasn't been confirmed uploaded yet.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts/{id}/similar",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    responses((status = 200, description = "Near-duplicate artifacts, most similar first", body = Vec<SimilarArtifact>)),
+    tag = "artifacts"
+)]
+pub async fn get_similar(State(db): State<Db>, Path(artifact_id): Path<Uuid>) -> AppResult<Json<Vec<SimilarArtifact>>> {
+    let similar = artifacts::find_similar(&db, artifact_id).await?;
+    Ok(Json(similar))
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct PresignedUrlResponse {
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RevokeArtifactRequest {
+    pub reason: Option<String>,
+    /// Signature over `{action: "revoke_artifact", artifact_id, reason}`,
+    /// by the artifact's own signer DID.
+    pub signature: String,
+}
+
+/// `POST /api/v1/artifacts/{id}/revoke` — the signer yanks an artifact
+/// it registered, so lookups and lineage surface it as revoked without
+/// removing the row or any derivation edges it's part of.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artifacts/{id}/revoke",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    request_body = RevokeArtifactRequest,
+    responses((status = 200, description = "Revoked (or already-revoked) artifact", body = Artifact)),
+    tag = "artifacts"
+)]
+pub async fn revoke(
+    State(db): State<Db>,
+    Path(artifact_id): Path<Uuid>,
+    Json(req): Json<RevokeArtifactRequest>,
+) -> AppResult<Json<Artifact>> {
+    let artifact = artifacts::revoke(&db, artifact_id, req.reason, &req.signature).await?;
+    Ok(Json(artifact))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ResolveDerivationRequest {
+    pub child_artifact_id: Uuid,
+    pub status: DerivationStatus,
+}
+
+/// `POST /api/v1/artifacts/{id}/derivations/resolve` — the parent
+/// artifact's signer countersigns or waives a pending derivation claim
+/// made against it, so it starts counting as verified lineage (and, if
+/// configured, a royalty split). `id` is the parent artifact.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artifacts/{id}/derivations/resolve",
+    params(("id" = Uuid, Path, description = "Parent artifact ID")),
+    request_body = ResolveDerivationRequest,
+    responses((status = 200, description = "Resolved derivation edge", body = ArtifactDerivation)),
+    tag = "artifacts"
+)]
+pub async fn resolve_derivation(
+    State(db): State<Db>,
+    Path(parent_artifact_id): Path<Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<ResolveDerivationRequest>,
+) -> AppResult<Json<ArtifactDerivation>> {
+    let derivation = artifacts::resolve_derivation_claim(
+        &db,
+        parent_artifact_id,
+        req.child_artifact_id,
+        &caller_did,
+        req.status,
+    )
+    .await?;
+    Ok(Json(derivation))
+}
+
+async fn find_artifact(db: &Db, artifact_id: Uuid) -> AppResult<Artifact> {
+    sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE id = $1")
+        .bind(artifact_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+/// `POST /api/v1/artifacts/{id}/upload-url` — a presigned URL the signer
+/// can `PUT` the artifact's content to, keyed by its content hash.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artifacts/{id}/upload-url",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    responses((status = 200, description = "Presigned PUT URL", body = PresignedUrlResponse)),
+    tag = "artifacts"
+)]
+pub async fn get_upload_url(State(db): State<Db>, Path(artifact_id): Path<Uuid>) -> AppResult<Json<PresignedUrlResponse>> {
+    let artifact = find_artifact(&db, artifact_id).await?;
+    let url = storage::presigned_upload_url(&artifact.sha256)?;
+    Ok(Json(PresignedUrlResponse { url }))
+}
+
+/// `GET /api/v1/artifacts/{id}/download-url` — a presigned URL to fetch
+/// previously uploaded content. Errors if nothing has been confirmed
+/// uploaded for this artifact yet.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts/{id}/download-url",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    responses(
+        (status = 200, description = "Presigned GET URL", body = PresignedUrlResponse),
+        (status = 404, description = "No content has been confirmed uploaded for this artifact"),
+    ),
+    tag = "artifacts"
+)]
+pub async fn get_download_url(State(db): State<Db>, Path(artifact_id): Path<Uuid>) -> AppResult<Json<PresignedUrlResponse>> {
+    let artifact = find_artifact(&db, artifact_id).await?;
+    if !artifact.content_stored {
+        return Err(AppError::NotFound);
+    }
+    let url = storage::presigned_download_url(&artifact.sha256)?;
+    Ok(Json(PresignedUrlResponse { url }))
+}
+
+/// `POST /api/v1/artifacts/{id}/confirm-upload` — downloads the object the
+/// caller claims to have uploaded and verifies it actually hashes to the
+/// artifact's declared content hash before marking it stored.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artifacts/{id}/confirm-upload",
+    params(("id" = Uuid, Path, description = "Artifact ID")),
+    responses((status = 200, description = "Artifact with content_stored now true", body = Artifact)),
+    tag = "artifacts"
+)]
+pub async fn confirm_upload(State(db): State<Db>, Path(artifact_id): Path<Uuid>) -> AppResult<Json<Artifact>> {
+    let artifact = artifacts::confirm_content_uploaded(&db, artifact_id).await?;
+    Ok(Json(artifact))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListArtifactsQuery {
+    pub signer: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/artifacts?signer=<did>` — cursor-paginated, date-filterable
+/// view of everything attributed to a signer; the basis of a public
+/// portfolio page. `signer` is optional so the same endpoint can list
+/// everything registered, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artifacts",
+    params(ListArtifactsQuery),
+    responses((status = 200, description = "Page of artifacts, newest first", body = pagination::ArtifactPage)),
+    tag = "artifacts"
+)]
+pub async fn list_artifacts(Query(q): Query<ListArtifactsQuery>) -> AppResult<Json<pagination::Page<Artifact>>> {
+    let limit = pagination::clamp_limit(q.limit);
+    let cursor = q.cursor.as_deref().map(pagination::decode_cursor).transpose()?;
+    let replica = crate::db::replica();
+    // Resolve `signer` through its key-rotation chain so a DID that rotated
+    // keys still sees everything it signed under an earlier key.
+    let signers = match &q.signer {
+        Some(signer) => Some(key_rotation::resolve_chain(replica, signer).await?),
+        None => None,
+    };
+    let artifacts = sqlx::query_as::<_, Artifact>(
+        "SELECT * FROM artifacts
+         WHERE ($1::text[] IS NULL OR signer_did = ANY($1))
+           AND ($2::timestamptz IS NULL OR registered_at >= $2)
+           AND ($3::timestamptz IS NULL OR registered_at <= $3)
+           AND ($4::uuid IS NULL OR (registered_at, id) < (SELECT registered_at, id FROM artifacts WHERE id = $4))
+         ORDER BY registered_at DESC, id DESC
+         LIMIT $5",
+    )
+    .bind(&signers)
+    .bind(q.from)
+    .bind(q.to)
+    .bind(cursor)
+    .bind(limit)
+    .fetch_all(replica)
+    .await?;
+    Ok(Json(pagination::Page::new(artifacts, limit, |a| a.id)))
+}
@@ -0,0 +1,385 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde_json::json;
+use uuid::Uuid;
+
+use serde::{Deserialize, Serialize};
+
+use crate::auth::AuthenticatedDid;
+use crate::config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{Bounty, BountyEdit, BountyStatus, ClosureType, EscrowPurpose, PatchBountyRequest, TrustedRunner, UserPolicy};
+use crate::pagination;
+use crate::services::{approvals, audit, delegation, escrow, policy, spend_limits, tags, trusted_runners};
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CreateBountyRequest {
+    pub title: String,
+    pub description: String,
+    pub reward: rust_decimal::Decimal,
+    pub closure_type: ClosureType,
+    pub deadline: Option<chrono::DateTime<chrono::Utc>>,
+    pub metadata: Option<serde_json::Value>,
+    /// Must be drawn from `services::tags::TAXONOMY`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Posts as this operator instead of the caller, provided the caller is
+    /// one of the operator's `UserPolicy.allowed_delegates`.
+    pub on_behalf_of: Option<String>,
+}
+
+impl crate::validation::Validate for CreateBountyRequest {
+    fn validate(&self) -> AppResult<()> {
+        crate::validation::non_empty(&self.title, "title")?;
+        crate::validation::max_len(&self.title, 200, "title")?;
+        crate::validation::max_len(&self.description, 10_000, "description")?;
+        let cfg = config::current();
+        crate::validation::in_range(self.reward, cfg.min_bounty_reward, cfg.max_bounty_reward, "reward")?;
+        if let Some(on_behalf_of) = &self.on_behalf_of {
+            crate::validation::did(on_behalf_of, "on_behalf_of")?;
+        }
+        Ok(())
+    }
+}
+
+/// `POST /api/v1/bounties` — creates a bounty and escrows its reward from
+/// the poster's balance up front. A delegate (see `services::delegation`)
+/// can post on an operator's behalf by setting `on_behalf_of`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounties",
+    request_body = CreateBountyRequest,
+    responses((status = 200, description = "Bounty created", body = Bounty)),
+    tag = "bounties"
+)]
+pub async fn create_bounty(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<CreateBountyRequest>,
+) -> AppResult<Json<Bounty>> {
+    let poster_did = req.on_behalf_of.clone().unwrap_or_else(|| caller_did.clone());
+    let delegate_did = if poster_did == caller_did {
+        None
+    } else {
+        delegation::authorize(&db, &poster_did, &caller_did).await?;
+        Some(caller_did.as_str())
+    };
+
+    let bounty = create(&db, &poster_did, delegate_did, req).await?;
+    Ok(Json(bounty))
+}
+
+/// The creation logic behind `POST /api/v1/bounties`, factored out so
+/// `routes::bounty_templates::instantiate` can build a bounty from a saved
+/// template without going through the HTTP layer twice. `delegate_did` is
+/// set when a delegate is posting on `poster_did`'s behalf, so the escrow
+/// ledger entry records who actually spent the funds.
+pub async fn create(db: &Db, poster_did: &str, delegate_did: Option<&str>, req: CreateBountyRequest) -> AppResult<Bounty> {
+    use crate::validation::Validate;
+    req.validate()?;
+    policy::assert_not_frozen(db, poster_did).await?;
+    tags::validate(&req.tags)?;
+    spend_limits::check_daily_limit(db, poster_did, req.reward).await?;
+
+    let mut needs_approval = false;
+    if let Some(policy) = sqlx::query_as::<_, UserPolicy>("SELECT * FROM user_policies WHERE did = $1")
+        .bind(poster_did)
+        .fetch_optional(db)
+        .await?
+    {
+        if let Some(limit) = policy.max_spend_per_bounty {
+            if req.reward > limit {
+                needs_approval = true;
+            }
+        }
+    }
+
+    let status = if needs_approval { BountyStatus::PendingApproval } else { BountyStatus::Open };
+
+    let bounty = sqlx::query_as::<_, Bounty>(
+        "INSERT INTO bounties (id, poster_did, title, description, reward, closure_type, status, deadline, metadata, tags, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, now(), now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(poster_did)
+    .bind(&req.title)
+    .bind(&req.description)
+    .bind(req.reward)
+    .bind(req.closure_type)
+    .bind(status)
+    .bind(req.deadline)
+    .bind(req.metadata.unwrap_or_default())
+    .bind(&req.tags)
+    .fetch_one(db)
+    .await?;
+
+    audit::record(db, poster_did, "create_bounty", "bounty", bounty.id, None, Some(json!(&bounty))).await?;
+
+    if needs_approval {
+        // Over the poster's per-bounty limit: file a multi-approver request
+        // instead of a hard rejection. The bounty stays `pending_approval`
+        // — no escrow held, not listed, subscribers not notified — until
+        // enough of the matching tier's approvers sign off.
+        let tier = approvals::tier_for_amount(db, poster_did, req.reward).await?;
+        let (approvers, quorum) = match tier {
+            Some(tier) => (tier.approvers, tier.quorum),
+            None => (vec![poster_did.to_string()], 1),
+        };
+
+        approvals::request_approval(
+            db,
+            poster_did,
+            approvers,
+            quorum,
+            "create_bounty",
+            json!({ "bounty_id": bounty.id }),
+        )
+        .await?;
+
+        return Ok(bounty);
+    }
+
+    escrow::hold_escrow(db, bounty.id, poster_did, req.reward, delegate_did, EscrowPurpose::BountyReward).await?;
+
+    tags::notify_subscribers(db, &bounty.tags, json!(&bounty)).await?;
+
+    Ok(bounty)
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListBountiesQuery {
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    /// Comma-separated tags; bounties matching any of them are returned.
+    pub tags: Option<String>,
+}
+
+/// `GET /api/v1/bounties` — open bounties, newest first, optionally
+/// filtered to those matching any of the given `tags`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bounties",
+    params(ListBountiesQuery),
+    responses((status = 200, description = "Page of bounties, newest first", body = pagination::BountyPage)),
+    tag = "bounties"
+)]
+pub async fn list_bounties(Query(q): Query<ListBountiesQuery>) -> AppResult<Json<pagination::Page<Bounty>>> {
+    let limit = pagination::clamp_limit(q.limit);
+    let cursor = q.cursor.as_deref().map(pagination::decode_cursor).transpose()?;
+    let filter_tags: Vec<String> = q.tags.map(|t| t.split(',').map(|s| s.trim().to_string()).collect()).unwrap_or_default();
+    let bounties = list(crate::db::replica(), cursor, limit, &filter_tags).await?;
+    Ok(Json(pagination::Page::new(bounties, limit, |b| b.id)))
+}
+
+/// The query behind `GET /api/v1/bounties`, factored out so
+/// `grpc::AgentServiceImpl::poll_bounties` can page through open bounties
+/// without going through the HTTP layer.
+pub async fn list(db: &Db, cursor: Option<Uuid>, limit: i64, filter_tags: &[String]) -> AppResult<Vec<Bounty>> {
+    let bounties = sqlx::query_as::<_, Bounty>(
+        "SELECT * FROM bounties
+         WHERE status = 'open'
+           AND ($1::uuid IS NULL OR (created_at, id) < (SELECT created_at, id FROM bounties WHERE id = $1))
+           AND (array_length($3::text[], 1) IS NULL OR tags && $3::text[])
+         ORDER BY created_at DESC, id DESC
+         LIMIT $2",
+    )
+    .bind(cursor)
+    .bind(limit)
+    .bind(filter_tags)
+    .fetch_all(db)
+    .await?;
+
+    Ok(bounties)
+}
+
+/// `GET /api/v1/bounties/{id}` — a single bounty by ID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bounties/{id}",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    responses((status = 200, description = "The bounty", body = Bounty)),
+    tag = "bounties"
+)]
+pub async fn get_bounty(State(db): State<Db>, Path(id): Path<Uuid>) -> AppResult<Json<Bounty>> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(bounty))
+}
+
+/// `PATCH /api/v1/bounties/{id}` — lets the poster update an open bounty's
+/// description, deadline, and metadata. Reward increases open an additional
+/// escrow hold for the delta rather than mutating the original one, so the
+/// accounting for each hold stays simple. Every accepted field change is
+/// recorded in `bounty_edits` for the edit history.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/bounties/{id}",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    request_body = PatchBountyRequest,
+    responses((status = 200, description = "Updated bounty", body = Bounty)),
+    tag = "bounties"
+)]
+pub async fn patch_bounty(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+    Json(req): Json<PatchBountyRequest>,
+) -> AppResult<Json<Bounty>> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.poster_did != poster_did {
+        return Err(AppError::Forbidden("only the poster can edit this bounty".into()));
+    }
+    if bounty.status != BountyStatus::Open {
+        return Err(AppError::BadRequest("bounty must be open to edit".into()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    if let Some(description) = &req.description {
+        record_edit(&mut tx, id, &poster_did, "description", json!(bounty.description), json!(description)).await?;
+    }
+    if let Some(deadline) = &req.deadline {
+        record_edit(&mut tx, id, &poster_did, "deadline", json!(bounty.deadline), json!(deadline)).await?;
+    }
+    if let Some(metadata) = &req.metadata {
+        record_edit(&mut tx, id, &poster_did, "metadata", bounty.metadata.clone(), metadata.clone()).await?;
+    }
+    if let Some(new_reward) = req.reward {
+        if new_reward < bounty.reward {
+            return Err(AppError::BadRequest("reward cannot be decreased after posting".into()));
+        }
+        if new_reward > bounty.reward {
+            let delta = new_reward - bounty.reward;
+            record_edit(&mut tx, id, &poster_did, "reward", json!(bounty.reward), json!(new_reward)).await?;
+            tx.commit().await?;
+            escrow::hold_escrow(&db, id, &poster_did, delta, None, EscrowPurpose::BountyReward).await?;
+            tx = db.begin().await?;
+        }
+    }
+
+    let updated = sqlx::query_as::<_, Bounty>(
+        "UPDATE bounties SET
+            description = COALESCE($2, description),
+            deadline = COALESCE($3, deadline),
+            metadata = COALESCE($4, metadata),
+            reward = COALESCE($5, reward),
+            updated_at = now()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(id)
+    .bind(&req.description)
+    .bind(req.deadline)
+    .bind(&req.metadata)
+    .bind(req.reward)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(&db, &poster_did, "patch_bounty", "bounty", id, Some(json!(&bounty)), Some(json!(&updated))).await?;
+
+    Ok(Json(updated))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AddTrustedRunnerRequest {
+    pub runner_did: String,
+}
+
+/// `POST /api/v1/bounties/{id}/trusted-runners` — lets the poster of a
+/// test-closure bounty register a runner DID as authorized to report
+/// execution receipts for it. Only the poster can grant this, since a
+/// trusted runner can single-handedly accept a submission and release
+/// escrow.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounties/{id}/trusted-runners",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    request_body = AddTrustedRunnerRequest,
+    responses((status = 200, description = "Registered trusted runner", body = TrustedRunner)),
+    tag = "bounties"
+)]
+pub async fn add_trusted_runner(
+    State(db): State<Db>,
+    Path(bounty_id): Path<Uuid>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+    Json(req): Json<AddTrustedRunnerRequest>,
+) -> AppResult<Json<TrustedRunner>> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.poster_did != poster_did {
+        return Err(AppError::Forbidden("only the poster can register trusted runners".into()));
+    }
+    if bounty.closure_type != ClosureType::Test {
+        return Err(AppError::BadRequest("trusted runners only apply to test-closure bounties".into()));
+    }
+
+    let runner = trusted_runners::add(&db, bounty_id, &req.runner_did, &poster_did).await?;
+
+    audit::record(
+        &db,
+        &poster_did,
+        "add_trusted_runner",
+        "bounty",
+        bounty_id,
+        None,
+        Some(json!({ "runner_did": req.runner_did })),
+    )
+    .await?;
+
+    Ok(Json(runner))
+}
+
+/// `GET /api/v1/bounties/{id}/trusted-runners` — the runner DIDs currently
+/// authorized to report execution receipts for this bounty.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bounties/{id}/trusted-runners",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    responses((status = 200, description = "Trusted runners, oldest first", body = Vec<TrustedRunner>)),
+    tag = "bounties"
+)]
+pub async fn list_trusted_runners(State(db): State<Db>, Path(bounty_id): Path<Uuid>) -> AppResult<Json<Vec<TrustedRunner>>> {
+    let runners = trusted_runners::list(&db, bounty_id).await?;
+    Ok(Json(runners))
+}
+
+async fn record_edit(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    bounty_id: Uuid,
+    edited_by: &str,
+    field: &str,
+    previous_value: serde_json::Value,
+    new_value: serde_json::Value,
+) -> AppResult<()> {
+    sqlx::query_as::<_, BountyEdit>(
+        "INSERT INTO bounty_edits (id, bounty_id, edited_by, field, previous_value, new_value, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(bounty_id)
+    .bind(edited_by)
+    .bind(field)
+    .bind(previous_value)
+    .bind(new_value)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{Bounty, BountyTemplate, ClosureType};
+use crate::routes::bounties::{self, CreateBountyRequest};
+use crate::services::bounty_templates;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateBountyTemplateRequest {
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub closure_type: ClosureType,
+    #[serde(default)]
+    pub default_metadata: serde_json::Value,
+    #[serde(default)]
+    pub default_tags: Vec<String>,
+}
+
+/// `POST /api/v1/bounty-templates` — saves a title/description/closure-config
+/// scaffold the caller can instantiate into new bounties later.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounty-templates",
+    request_body = CreateBountyTemplateRequest,
+    responses((status = 200, description = "Template saved", body = BountyTemplate)),
+    tag = "bounties"
+)]
+pub async fn create_template(
+    State(db): State<Db>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+    Json(req): Json<CreateBountyTemplateRequest>,
+) -> AppResult<Json<BountyTemplate>> {
+    let template = bounty_templates::create(
+        &db,
+        &poster_did,
+        &req.name,
+        &req.title,
+        &req.description,
+        req.closure_type,
+        req.default_metadata,
+        req.default_tags,
+    )
+    .await?;
+    Ok(Json(template))
+}
+
+/// `GET /api/v1/bounty-templates` — the caller's saved templates.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bounty-templates",
+    responses((status = 200, description = "The caller's saved templates", body = Vec<BountyTemplate>)),
+    tag = "bounties"
+)]
+pub async fn list_templates(
+    State(db): State<Db>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<BountyTemplate>>> {
+    let templates = bounty_templates::list(&db, &poster_did).await?;
+    Ok(Json(templates))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct InstantiateBountyTemplateRequest {
+    pub reward: rust_decimal::Decimal,
+    pub deadline: Option<chrono::DateTime<chrono::Utc>>,
+    /// Overrides the template's title; defaults to the template's title.
+    pub title: Option<String>,
+    /// Overrides the template's description; defaults to the template's
+    /// description.
+    pub description: Option<String>,
+    /// Overrides the template's default metadata rather than merging with
+    /// it, same as `PatchBountyRequest::metadata`.
+    pub metadata: Option<serde_json::Value>,
+    /// Overrides the template's default tags.
+    pub tags: Option<Vec<String>>,
+}
+
+/// `POST /api/v1/bounty-templates/{id}/instantiate` — creates a bounty from
+/// a saved template, escrowing `reward` the same as `POST /api/v1/bounties`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounty-templates/{id}/instantiate",
+    params(("id" = Uuid, Path, description = "Template to instantiate")),
+    request_body = InstantiateBountyTemplateRequest,
+    responses((status = 200, description = "Bounty created from the template", body = Bounty)),
+    tag = "bounties"
+)]
+pub async fn instantiate(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+    Json(req): Json<InstantiateBountyTemplateRequest>,
+) -> AppResult<Json<Bounty>> {
+    let template = bounty_templates::get_owned(&db, id, &poster_did).await?;
+
+    let create_req = CreateBountyRequest {
+        title: req.title.unwrap_or(template.title),
+        description: req.description.unwrap_or(template.description),
+        reward: req.reward,
+        closure_type: template.closure_type,
+        deadline: req.deadline,
+        metadata: Some(req.metadata.unwrap_or(template.default_metadata)),
+        tags: req.tags.unwrap_or(template.default_tags),
+        on_behalf_of: None,
+    };
+
+    let bounty = bounties::create(&db, &poster_did, None, create_req).await?;
+    Ok(Json(bounty))
+}
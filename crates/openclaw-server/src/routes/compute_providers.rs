@@ -0,0 +1,150 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::ComputeProvider;
+use crate::services::{audit, compute_providers};
+
+fn is_admin(did: &str) -> bool {
+    std::env::var("ADMIN_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateComputeProviderRequest {
+    pub name: String,
+    pub conversion_rate: Decimal,
+    pub credential_ref: String,
+}
+
+/// `POST /api/v1/admin/compute-providers` — registers a provider
+/// `/credits/redeem` can allocate against. Previously these rows could
+/// only be inserted by hand.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/compute-providers",
+    request_body = CreateComputeProviderRequest,
+    responses((status = 200, description = "Provider registered", body = ComputeProvider)),
+    tag = "admin"
+)]
+pub async fn create(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<CreateComputeProviderRequest>,
+) -> AppResult<Json<ComputeProvider>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let provider = compute_providers::create(&db, &req.name, req.conversion_rate, &req.credential_ref).await?;
+    audit::record(&db, &caller_did, "create_compute_provider", "compute_provider", provider.id, None, Some(serde_json::json!(&provider))).await?;
+    Ok(Json(provider))
+}
+
+/// `GET /api/v1/admin/compute-providers` — every registered provider,
+/// active or not.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/compute-providers",
+    responses((status = 200, description = "Registered providers", body = Vec<ComputeProvider>)),
+    tag = "admin"
+)]
+pub async fn list(State(db): State<Db>, AuthenticatedDid(caller_did): AuthenticatedDid) -> AppResult<Json<Vec<ComputeProvider>>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let providers = compute_providers::list(&db).await?;
+    Ok(Json(providers))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateComputeProviderRequest {
+    pub name: Option<String>,
+    pub conversion_rate: Option<Decimal>,
+    pub credential_ref: Option<String>,
+}
+
+/// `PATCH /api/v1/admin/compute-providers/{id}` — updates a provider's
+/// conversion rate or credential reference without disrupting its history
+/// in `redemption_receipts`.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/admin/compute-providers/{id}",
+    params(("id" = Uuid, Path, description = "Provider to update")),
+    request_body = UpdateComputeProviderRequest,
+    responses((status = 200, description = "Provider updated", body = ComputeProvider)),
+    tag = "admin"
+)]
+pub async fn update(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<UpdateComputeProviderRequest>,
+) -> AppResult<Json<ComputeProvider>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let before = sqlx::query_as::<_, ComputeProvider>("SELECT * FROM compute_providers WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let provider = compute_providers::update(&db, id, req.name, req.conversion_rate, req.credential_ref).await?;
+    audit::record(&db, &caller_did, "update_compute_provider", "compute_provider", id, Some(serde_json::json!(&before)), Some(serde_json::json!(&provider))).await?;
+    Ok(Json(provider))
+}
+
+/// `POST /api/v1/admin/compute-providers/{id}/deactivate` — stops
+/// `/credits/redeem` from accepting new redemptions against this provider,
+/// without deleting it (past receipts still reference it by ID).
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/compute-providers/{id}/deactivate",
+    params(("id" = Uuid, Path, description = "Provider to deactivate")),
+    responses((status = 200, description = "Provider deactivated", body = ComputeProvider)),
+    tag = "admin"
+)]
+pub async fn deactivate(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<ComputeProvider>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let provider = compute_providers::set_active(&db, id, false).await?;
+    audit::record(&db, &caller_did, "deactivate_compute_provider", "compute_provider", id, None, Some(serde_json::json!(&provider))).await?;
+    Ok(Json(provider))
+}
+
+/// `POST /api/v1/admin/compute-providers/{id}/activate` — reverses
+/// `deactivate`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/compute-providers/{id}/activate",
+    params(("id" = Uuid, Path, description = "Provider to activate")),
+    responses((status = 200, description = "Provider activated", body = ComputeProvider)),
+    tag = "admin"
+)]
+pub async fn activate(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+) -> AppResult<Json<ComputeProvider>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let provider = compute_providers::set_active(&db, id, true).await?;
+    audit::record(&db, &caller_did, "activate_compute_provider", "compute_provider", id, None, Some(serde_json::json!(&provider))).await?;
+    Ok(Json(provider))
+}
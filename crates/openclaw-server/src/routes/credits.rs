@@ -0,0 +1,608 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ComputeProvider, Invoice, InvoiceStatus, KycVerification, PurchaseRequest, PurchaseResponse, RedemptionReceipt,
+    RedemptionReceiptStatus, ReserveAttestation, ReserveAttestationSnapshot,
+};
+use crate::services::{attestation, audit, delegation, fees, fraud, kyc, payment_providers, policy, provider_clients, spend_limits};
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct RedeemRequest {
+    pub provider_id: Uuid,
+    pub amount: Decimal,
+    /// Spends from this operator's balance instead of the caller's,
+    /// provided the caller is one of the operator's
+    /// `UserPolicy.allowed_delegates`.
+    pub on_behalf_of: Option<String>,
+}
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct RedeemResponse {
+    pub receipt_id: Uuid,
+}
+
+/// `POST /api/v1/credits/redeem` — burns `amount` credits in exchange for
+/// provider-allocated compute. See `services::provider_clients` for the
+/// part that actually talks to the provider. A delegate (see
+/// `services::delegation`) can redeem against an operator's balance by
+/// setting `on_behalf_of`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/redeem",
+    request_body = RedeemRequest,
+    responses((status = 200, description = "Redemption receipt filed", body = RedeemResponse)),
+    tag = "credits"
+)]
+pub async fn redeem_credits(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<RedeemRequest>,
+) -> AppResult<Json<RedeemResponse>> {
+    let did = req.on_behalf_of.clone().unwrap_or_else(|| caller_did.clone());
+    let delegate_did = if did == caller_did {
+        None
+    } else {
+        delegation::authorize(&db, &did, &caller_did).await?;
+        Some(caller_did.clone())
+    };
+
+    policy::assert_not_frozen(&db, &did).await?;
+    spend_limits::check_daily_limit(&db, &did, req.amount).await?;
+    kyc::assert_verified(&db, &did, req.amount).await?;
+
+    let provider = sqlx::query_as::<_, ComputeProvider>("SELECT * FROM compute_providers WHERE id = $1")
+        .bind(req.provider_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    if !provider.active {
+        return Err(AppError::BadRequest("compute provider is not active".into()));
+    }
+
+    let mut tx = db.begin().await?;
+    // Lock the account row before checking the balance, so a concurrent
+    // redemption or escrow hold against the same DID can't both pass a
+    // per-statement `balance >= $1` check and drive the balance negative.
+    let balance: Option<Decimal> = sqlx::query_scalar("SELECT balance FROM m_credits_accounts WHERE did = $1 FOR UPDATE")
+        .bind(&did)
+        .fetch_optional(&mut *tx)
+        .await?;
+    if balance.unwrap_or_default() < req.amount {
+        return Err(AppError::domain_with_details(
+            crate::error::ErrorCode::InsufficientBalance,
+            format!(
+                "insufficient balance: redemption needs {}, has {}",
+                req.amount,
+                balance.unwrap_or_default()
+            ),
+            serde_json::json!({ "needs": req.amount, "has": balance.unwrap_or_default() }),
+        ));
+    }
+    sqlx::query("UPDATE m_credits_accounts SET balance = balance - $1 WHERE did = $2")
+        .bind(req.amount)
+        .bind(&did)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, delegate_did, created_at)
+         VALUES ($1, $2, $3, 'redemption', $4, $5, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&did)
+    .bind(-req.amount)
+    .bind(req.provider_id)
+    .bind(&delegate_did)
+    .execute(&mut *tx)
+    .await?;
+    let receipt_id = Uuid::new_v4();
+    let (net_amount, fee) = fees::split(req.amount, &did);
+    fees::credit_treasury(&mut tx, fee, "platform_fee", receipt_id).await?;
+    sqlx::query(
+        "INSERT INTO redemption_receipts (id, did, provider_id, amount, status, created_at)
+         VALUES ($1, $2, $3, $4, 'pending', now())",
+    )
+    .bind(receipt_id)
+    .bind(&did)
+    .bind(req.provider_id)
+    .bind(req.amount)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    match provider_clients::allocate_with_provider(&provider, &did, net_amount).await {
+        Ok(allocation) => {
+            sqlx::query("UPDATE redemption_receipts SET status = 'completed', external_ref = $2 WHERE id = $1")
+                .bind(receipt_id)
+                .bind(&allocation.external_ref)
+                .execute(&db)
+                .await?;
+        }
+        Err(e) => {
+            // Compensate: the deduction, burn, and fee already committed
+            // before the provider call, so a failed allocation must re-mint
+            // the full gross amount and claw back the fee rather than leave
+            // credits stranded or the treasury keeping a fee for nothing.
+            let mut tx = db.begin().await?;
+            sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+                .bind(req.amount)
+                .bind(&did)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query(
+                "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, delegate_did, created_at)
+                 VALUES ($1, $2, $3, 'redemption_reversed', $4, $5, now())",
+            )
+            .bind(Uuid::new_v4())
+            .bind(&did)
+            .bind(req.amount)
+            .bind(receipt_id)
+            .bind(&delegate_did)
+            .execute(&mut *tx)
+            .await?;
+            if fee > Decimal::ZERO {
+                let treasury_did = &config::current().platform_treasury_did;
+                sqlx::query("UPDATE m_credits_accounts SET balance = balance - $1 WHERE did = $2")
+                    .bind(fee)
+                    .bind(treasury_did)
+                    .execute(&mut *tx)
+                    .await?;
+                sqlx::query(
+                    "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+                     VALUES ($1, $2, $3, 'platform_fee_reversed', $4, now())",
+                )
+                .bind(Uuid::new_v4())
+                .bind(treasury_did)
+                .bind(-fee)
+                .bind(receipt_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            sqlx::query("UPDATE redemption_receipts SET status = 'failed' WHERE id = $1")
+                .bind(receipt_id)
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await?;
+
+            audit::record(
+                &db,
+                &caller_did,
+                "reverse_redemption",
+                "redemption_receipt",
+                receipt_id,
+                None,
+                Some(serde_json::json!({ "provider_id": req.provider_id, "amount": req.amount, "did": did })),
+            )
+            .await?;
+
+            return Err(e);
+        }
+    }
+
+    audit::record(
+        &db,
+        &caller_did,
+        "redeem_credits",
+        "redemption_receipt",
+        receipt_id,
+        None,
+        Some(serde_json::json!({ "provider_id": req.provider_id, "amount": req.amount, "did": did })),
+    )
+    .await?;
+
+    Ok(Json(RedeemResponse { receipt_id }))
+}
+
+#[derive(Debug, serde::Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListRedemptionsQuery {
+    pub status: Option<RedemptionReceiptStatus>,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/credits/redemptions` — a DID's own redemption receipts,
+/// oldest first, so a caller can confirm what each `/credits/redeem` burn
+/// actually bought once `status` moves off `pending`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/redemptions",
+    params(ListRedemptionsQuery),
+    responses((status = 200, description = "Page of the caller's redemption receipts", body = Vec<RedemptionReceipt>)),
+    tag = "credits"
+)]
+pub async fn list_redemptions(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Query(q): Query<ListRedemptionsQuery>,
+) -> AppResult<Json<Vec<RedemptionReceipt>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+
+    let receipts = sqlx::query_as::<_, RedemptionReceipt>(
+        "SELECT * FROM redemption_receipts
+         WHERE did = $1
+           AND ($2::text IS NULL OR status = $2)
+           AND ($3::uuid IS NULL OR (created_at, id) > (SELECT created_at, id FROM redemption_receipts WHERE id = $3))
+         ORDER BY created_at ASC, id ASC
+         LIMIT $4",
+    )
+    .bind(&did)
+    .bind(q.status)
+    .bind(q.cursor)
+    .bind(limit)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(receipts))
+}
+
+#[derive(Debug, serde::Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListInvoicesQuery {
+    pub status: Option<InvoiceStatus>,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/credits/invoices` — a DID's own purchase invoices, oldest
+/// first, so the CLI purchase command (and any other client holding an
+/// invoice ID from `/credits/purchase`) has somewhere to poll status.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/invoices",
+    params(ListInvoicesQuery),
+    responses((status = 200, description = "Page of the caller's invoices", body = Vec<Invoice>)),
+    tag = "credits"
+)]
+pub async fn list_invoices(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Query(q): Query<ListInvoicesQuery>,
+) -> AppResult<Json<Vec<Invoice>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+
+    let invoices = sqlx::query_as::<_, Invoice>(
+        "SELECT * FROM invoices
+         WHERE did = $1
+           AND ($2::text IS NULL OR status = $2)
+           AND ($3::uuid IS NULL OR (created_at, id) > (SELECT created_at, id FROM invoices WHERE id = $3))
+         ORDER BY created_at ASC, id ASC
+         LIMIT $4",
+    )
+    .bind(&did)
+    .bind(q.status)
+    .bind(q.cursor)
+    .bind(limit)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(invoices))
+}
+
+/// `GET /api/v1/credits/invoices/{id}` — a single invoice, for polling its
+/// status after `/credits/purchase`. Self-only, same as the listing
+/// endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/invoices/{id}",
+    params(("id" = Uuid, Path, description = "Invoice to fetch")),
+    responses((status = 200, description = "The invoice", body = Invoice)),
+    tag = "credits"
+)]
+pub async fn get_invoice(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<Invoice>> {
+    let invoice = sqlx::query_as::<_, Invoice>("SELECT * FROM invoices WHERE id = $1 AND did = $2")
+        .bind(id)
+        .bind(&did)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(invoice))
+}
+
+/// `POST /api/v1/credits/kyc/refresh` — (re-)checks the caller's identity
+/// verification status with the configured `services::kyc` provider. Call
+/// this after completing a provider-hosted verification flow out-of-band;
+/// there's nothing to poll until the provider records a decision.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/kyc/refresh",
+    responses((status = 200, description = "Current verification status", body = KycVerification)),
+    tag = "credits"
+)]
+pub async fn refresh_kyc(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<KycVerification>> {
+    Ok(Json(kyc::refresh(&db, &did).await?))
+}
+
+/// `POST /api/v1/credits/purchase` — opens a Stripe Checkout Session for
+/// `amount_usd` and records a pending invoice. Credits are minted once the
+/// Stripe webhook confirms payment, not here.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/purchase",
+    request_body = PurchaseRequest,
+    responses((status = 200, description = "Checkout session opened", body = PurchaseResponse)),
+    tag = "credits"
+)]
+pub async fn purchase(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<PurchaseRequest>,
+) -> AppResult<Json<PurchaseResponse>> {
+    use crate::validation::Validate;
+    req.validate()?;
+    let currency = req.currency.unwrap_or_else(|| "usd".into());
+    let credits = req.amount_usd * config::current().credits_per_usd;
+    let credit_to = req.credit_to_did.clone().unwrap_or_else(|| did.clone());
+
+    kyc::assert_verified(&db, &credit_to, credits).await?;
+    let check = fraud::evaluate_purchase(&db, &did, req.card_fingerprint.as_deref(), credit_to != did).await?;
+
+    let status = if check.flagged() { crate::models::InvoiceStatus::PendingReview } else { crate::models::InvoiceStatus::Pending };
+    let invoice = sqlx::query_as::<_, Invoice>(
+        "INSERT INTO invoices (id, did, amount_usd, currency, credits, status, card_fingerprint, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&credit_to)
+    .bind(req.amount_usd)
+    .bind(&currency)
+    .bind(credits)
+    .bind(status)
+    .bind(&req.card_fingerprint)
+    .fetch_one(&db)
+    .await?;
+
+    if check.flagged() {
+        fraud::open_review(&db, invoice.id, &credit_to, check.reasons).await?;
+        return Ok(Json(PurchaseResponse {
+            invoice_id: invoice.id,
+            status: invoice.status,
+            checkout_url: None,
+        }));
+    }
+
+    let provider = payment_providers::current()?;
+    let session = provider.create_checkout(&invoice, &credit_to).await?;
+
+    sqlx::query("UPDATE invoices SET external_ref = $2 WHERE id = $1")
+        .bind(invoice.id)
+        .bind(&session.external_ref)
+        .execute(&db)
+        .await?;
+
+    Ok(Json(PurchaseResponse {
+        invoice_id: invoice.id,
+        status: invoice.status,
+        checkout_url: Some(session.url),
+    }))
+}
+
+/// `POST /api/v1/credits/webhooks/stripe` — Stripe's confirmation that a
+/// checkout session completed (or expired/failed). This is where
+/// `/credits/purchase`'s invoice actually turns into spendable credits;
+/// the purchase handler itself only ever opens the checkout session.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/webhooks/stripe",
+    responses((status = 200, description = "Webhook processed")),
+    tag = "credits"
+)]
+pub async fn stripe_webhook(
+    State(db): State<Db>,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> AppResult<Json<serde_json::Value>> {
+    let signature = headers
+        .get("Stripe-Signature")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::BadRequest("missing Stripe-Signature header".into()))?;
+
+    let provider = payment_providers::current()?;
+    match provider.verify_webhook(&body, signature).await? {
+        payment_providers::PaymentWebhookEvent::Completed { invoice_id, external_ref } => {
+            complete_invoice(&db, invoice_id, &external_ref).await?;
+        }
+        payment_providers::PaymentWebhookEvent::Failed { invoice_id } => {
+            sqlx::query("UPDATE invoices SET status = 'failed' WHERE id = $1")
+                .bind(invoice_id)
+                .execute(&db)
+                .await?;
+        }
+        payment_providers::PaymentWebhookEvent::Ignored => {}
+    }
+
+    Ok(Json(serde_json::json!({ "received": true })))
+}
+
+#[derive(Debug, serde::Deserialize, utoipa::ToSchema)]
+pub struct ValidateMerchantRequest {
+    /// The validation URL `ApplePaySession.onvalidatemerchant` received
+    /// from Apple; single-use and short-lived.
+    pub validation_url: String,
+    pub display_name: String,
+}
+
+/// `POST /api/v1/credits/apple-pay/validate-merchant` — proxies Apple's
+/// merchant validation handshake, since it requires presenting our
+/// merchant identity certificate, which only the server holds.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/apple-pay/validate-merchant",
+    request_body = ValidateMerchantRequest,
+    responses((status = 200, description = "Opaque Apple merchant session, relayed to the client as-is")),
+    tag = "credits"
+)]
+pub async fn apple_pay_validate_merchant(
+    Json(req): Json<ValidateMerchantRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    let provider = payment_providers::ApplePayPaymentProvider::from_env()?;
+    let session = provider.validate_merchant(&req.validation_url, &req.display_name).await?;
+    Ok(Json(session))
+}
+
+#[derive(Debug, serde::Deserialize, utoipa::ToSchema)]
+pub struct ApplePayChargeRequest {
+    pub amount_usd: Decimal,
+    pub currency: Option<String>,
+    /// The Apple Pay payment token from `ApplePaySession.onpaymentauthorized`,
+    /// relayed verbatim.
+    pub payment_token: serde_json::Value,
+}
+
+/// `POST /api/v1/credits/apple-pay/charge` — charges an Apple Pay token
+/// and, unlike the Stripe Checkout path, mints credits immediately rather
+/// than waiting on a webhook: the token is already user-authorized and
+/// single-use, so there's nothing left to confirm asynchronously.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/apple-pay/charge",
+    request_body = ApplePayChargeRequest,
+    responses((status = 200, description = "Charge result and updated invoice", body = PurchaseResponse)),
+    tag = "credits"
+)]
+pub async fn apple_pay_charge(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<ApplePayChargeRequest>,
+) -> AppResult<Json<PurchaseResponse>> {
+    let currency = req.currency.unwrap_or_else(|| "usd".into());
+    let credits = req.amount_usd * config::current().credits_per_usd;
+
+    kyc::assert_verified(&db, &did, credits).await?;
+    policy::assert_not_frozen(&db, &did).await?;
+
+    let invoice = sqlx::query_as::<_, Invoice>(
+        "INSERT INTO invoices (id, did, amount_usd, currency, credits, status, created_at)
+         VALUES ($1, $2, $3, $4, $5, 'pending', now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&did)
+    .bind(req.amount_usd)
+    .bind(&currency)
+    .bind(credits)
+    .fetch_one(&db)
+    .await?;
+
+    let provider = payment_providers::ApplePayPaymentProvider::from_env()?;
+    let charge = provider.charge(&req.payment_token, req.amount_usd, &currency).await?;
+
+    if !charge.succeeded {
+        sqlx::query("UPDATE invoices SET status = 'failed', external_ref = $2 WHERE id = $1")
+            .bind(invoice.id)
+            .bind(&charge.external_ref)
+            .execute(&db)
+            .await?;
+        return Err(AppError::BadRequest("apple pay charge did not succeed".into()));
+    }
+
+    complete_invoice(&db, invoice.id, &charge.external_ref).await?;
+
+    Ok(Json(PurchaseResponse {
+        invoice_id: invoice.id,
+        status: crate::models::InvoiceStatus::Completed,
+        checkout_url: None,
+    }))
+}
+
+/// Mints an invoice's credits into its DID's balance and marks it
+/// completed. Idempotent against Stripe's at-least-once webhook delivery:
+/// only an invoice still `pending` is updated, so a duplicate event is a
+/// no-op rather than a double mint.
+async fn complete_invoice(db: &Db, invoice_id: Uuid, external_ref: &str) -> AppResult<()> {
+    let mut tx = db.begin().await?;
+
+    let invoice = sqlx::query_as::<_, Invoice>(
+        "UPDATE invoices SET status = 'completed', external_ref = $2
+         WHERE id = $1 AND status = 'pending'
+         RETURNING *",
+    )
+    .bind(invoice_id)
+    .bind(external_ref)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(invoice) = invoice else {
+        tx.rollback().await?;
+        return Ok(());
+    };
+
+    sqlx::query(
+        "INSERT INTO m_credits_accounts (did, balance) VALUES ($1, $2)
+         ON CONFLICT (did) DO UPDATE SET balance = m_credits_accounts.balance + excluded.balance",
+    )
+    .bind(&invoice.did)
+    .bind(invoice.credits)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, 'purchase', $4, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&invoice.did)
+    .bind(invoice.credits)
+    .bind(invoice.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// `GET /api/v1/credits/reserves/attestation` — a freshly computed, signed
+/// statement of total outstanding credits versus total USD collected for
+/// them, signed with the server's own identity so it can be verified
+/// offline via `/api/v1/credits/reserves/verify` or `/.well-known/did.json`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/reserves/attestation",
+    responses((status = 200, description = "Freshly signed reserve attestation", body = ReserveAttestation)),
+    tag = "credits"
+)]
+pub async fn get_reserve_attestation(State(db): State<Db>) -> AppResult<Json<ReserveAttestation>> {
+    Ok(Json(attestation::sign_attestation(&db).await?))
+}
+
+/// `POST /api/v1/credits/reserves/verify` — checks that a reserve
+/// attestation's signature matches the DID it claims to be signed by.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/reserves/verify",
+    request_body = ReserveAttestation,
+    responses((status = 200, description = "Signature is valid")),
+    tag = "credits"
+)]
+pub async fn verify_reserve_attestation(Json(att): Json<ReserveAttestation>) -> AppResult<Json<serde_json::Value>> {
+    attestation::verify_attestation(&att)?;
+    Ok(Json(serde_json::json!({ "valid": true })))
+}
+
+/// `GET /api/v1/credits/reserves/history` — the hash-chained history of
+/// previously recorded attestations, oldest first, so an auditor can walk
+/// the chain and confirm no snapshot was skipped or rewritten.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/reserves/history",
+    responses((status = 200, description = "Attestation history, oldest first", body = Vec<ReserveAttestationSnapshot>)),
+    tag = "credits"
+)]
+pub async fn reserve_attestation_history(
+    State(db): State<Db>,
+) -> AppResult<Json<Vec<ReserveAttestationSnapshot>>> {
+    Ok(Json(attestation::list_history(&db).await?))
+}
+
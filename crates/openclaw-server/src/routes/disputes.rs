@@ -0,0 +1,311 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    AttachEvidenceRequest, Bounty, Dispute, DisputeEvidence, DisputeOutcome, DisputeStatus,
+    EscrowPurpose, JurorVote, OpenDisputeRequest, StakeJurorVoteRequest, Submission, SubmissionStatus,
+};
+use crate::services::{audit, disputes, escrow, jurors};
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ResolveDisputeRequest {
+    pub outcome: DisputeOutcome,
+}
+
+fn is_arbiter(did: &str) -> bool {
+    std::env::var("ARBITER_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+/// `POST /api/v1/disputes` — either the submitter (contesting a quorum
+/// rejection) or the bounty's poster (contesting a quorum acceptance) opens
+/// a dispute over `submission_id`'s outcome, staking credits behind their
+/// side the same way a juror does (see `services::jurors::stake_vote`).
+/// `services::disputes::settle` — run by `resolve_dispute` or, once
+/// `dispute_deadline` passes, by `jobs::dispute_resolution` — is what
+/// actually moves funds; this just opens the case.
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes",
+    request_body = OpenDisputeRequest,
+    responses((status = 200, description = "Opened dispute", body = Dispute)),
+    tag = "disputes"
+)]
+pub async fn create_dispute(
+    State(db): State<Db>,
+    AuthenticatedDid(initiator_did): AuthenticatedDid,
+    Json(req): Json<OpenDisputeRequest>,
+) -> AppResult<Json<Dispute>> {
+    if req.stake <= Decimal::ZERO {
+        return Err(AppError::BadRequest("stake must be positive".into()));
+    }
+    if req.reason.trim().is_empty() {
+        return Err(AppError::BadRequest("reason must not be empty".into()));
+    }
+
+    let submission = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1")
+        .bind(req.submission_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(submission.bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let respondent_did = if initiator_did == submission.submitter_did {
+        bounty.poster_did.clone()
+    } else if initiator_did == bounty.poster_did {
+        submission.submitter_did.clone()
+    } else {
+        return Err(AppError::Forbidden("caller is neither the submitter nor the bounty poster".into()));
+    };
+
+    if !matches!(submission.status, SubmissionStatus::Accepted | SubmissionStatus::Rejected) {
+        return Err(AppError::BadRequest(
+            "only an accepted or rejected submission's outcome can be disputed".into(),
+        ));
+    }
+
+    let already_open: Option<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM disputes WHERE submission_id = $1 AND status = 'open'",
+    )
+    .bind(req.submission_id)
+    .fetch_optional(&db)
+    .await?;
+    if already_open.is_some() {
+        return Err(AppError::BadRequest("submission already has an open dispute".into()));
+    }
+
+    escrow::hold_escrow(&db, bounty.id, &initiator_did, req.stake, None, EscrowPurpose::DisputeStake).await?;
+
+    let dispute_deadline =
+        chrono::Utc::now() + chrono::Duration::hours(crate::config::current().dispute_window_hours);
+    let dispute = sqlx::query_as::<_, Dispute>(
+        "INSERT INTO disputes (id, bounty_id, submission_id, initiator_did, respondent_did, reason, initiator_stake, status, dispute_deadline, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, 'open', $8, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(bounty.id)
+    .bind(req.submission_id)
+    .bind(&initiator_did)
+    .bind(&respondent_did)
+    .bind(&req.reason)
+    .bind(req.stake)
+    .bind(dispute_deadline)
+    .fetch_one(&db)
+    .await?;
+
+    audit::record(&db, &initiator_did, "open_dispute", "dispute", dispute.id, None, Some(serde_json::json!(&dispute))).await?;
+
+    Ok(Json(dispute))
+}
+
+/// `POST /api/v1/disputes/{id}/resolve` — an arbiter decides a dispute's
+/// outcome. Delegates the actual fund movement to `services::disputes::settle`
+/// so this endpoint and the deadline auto-resolution worker can't drift.
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/resolve",
+    params(("id" = Uuid, Path, description = "Dispute ID")),
+    request_body = ResolveDisputeRequest,
+    responses((status = 200, description = "Resolved dispute", body = Dispute)),
+    tag = "disputes"
+)]
+pub async fn resolve_dispute(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(arbiter_did): AuthenticatedDid,
+    Json(req): Json<ResolveDisputeRequest>,
+) -> AppResult<Json<Dispute>> {
+    if !is_arbiter(&arbiter_did) {
+        return Err(AppError::Forbidden("caller is not an arbiter".into()));
+    }
+
+    let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if dispute.status != DisputeStatus::Open {
+        return Err(AppError::BadRequest("dispute is already resolved".into()));
+    }
+
+    disputes::settle(&db, &dispute, req.outcome).await?;
+
+    let resolved = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(id)
+        .fetch_one(&db)
+        .await?;
+
+    audit::record(
+        &db,
+        &arbiter_did,
+        "resolve_dispute",
+        "dispute",
+        id,
+        Some(serde_json::json!(&dispute)),
+        Some(serde_json::json!(&resolved)),
+    )
+    .await?;
+
+    Ok(Json(resolved))
+}
+
+/// `POST /api/v1/disputes/{id}/evidence` — either party (or the arbiter)
+/// attaches a signed evidence envelope instead of cramming everything into
+/// the dispute's free-text reason.
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/evidence",
+    params(("id" = Uuid, Path, description = "Dispute ID")),
+    request_body = AttachEvidenceRequest,
+    responses((status = 200, description = "Evidence attached", body = DisputeEvidence)),
+    tag = "disputes"
+)]
+pub async fn attach_evidence(
+    State(db): State<Db>,
+    Path(dispute_id): Path<Uuid>,
+    AuthenticatedDid(submitted_by): AuthenticatedDid,
+    Json(req): Json<AttachEvidenceRequest>,
+) -> AppResult<Json<DisputeEvidence>> {
+    let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(dispute_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let allowed = submitted_by == dispute.initiator_did
+        || submitted_by == dispute.respondent_did
+        || is_arbiter(&submitted_by);
+    if !allowed {
+        return Err(AppError::Forbidden("not a party to this dispute".into()));
+    }
+
+    let evidence = sqlx::query_as::<_, DisputeEvidence>(
+        "INSERT INTO dispute_evidence (id, dispute_id, submitted_by, kind, envelope, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(dispute_id)
+    .bind(&submitted_by)
+    .bind(&req.kind)
+    .bind(&req.envelope)
+    .fetch_one(&db)
+    .await?;
+
+    Ok(Json(evidence))
+}
+
+/// `GET /api/v1/disputes/{id}/evidence` — visible to both parties and the
+/// arbiter; access is enforced the same way as attaching evidence.
+#[utoipa::path(
+    get,
+    path = "/api/v1/disputes/{id}/evidence",
+    params(("id" = Uuid, Path, description = "Dispute ID")),
+    responses((status = 200, description = "Evidence attached to this dispute", body = Vec<DisputeEvidence>)),
+    tag = "disputes"
+)]
+pub async fn list_evidence(
+    State(db): State<Db>,
+    Path(dispute_id): Path<Uuid>,
+    AuthenticatedDid(requester_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<DisputeEvidence>>> {
+    let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(dispute_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let allowed = requester_did == dispute.initiator_did
+        || requester_did == dispute.respondent_did
+        || is_arbiter(&requester_did);
+    if !allowed {
+        return Err(AppError::Forbidden("not a party to this dispute".into()));
+    }
+
+    let evidence = sqlx::query_as::<_, DisputeEvidence>(
+        "SELECT * FROM dispute_evidence WHERE dispute_id = $1 ORDER BY created_at",
+    )
+    .bind(dispute_id)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(evidence))
+}
+
+/// `POST /api/v1/disputes/{id}/jurors/vote` — any DID can join the juror
+/// pool for an open dispute by staking credits behind an outcome. The
+/// stake is held in escrow until the dispute resolves, at which point
+/// `services::jurors::distribute_stakes` settles it.
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/jurors/vote",
+    params(("id" = Uuid, Path, description = "Dispute ID")),
+    request_body = StakeJurorVoteRequest,
+    responses((status = 200, description = "Recorded juror vote", body = JurorVote)),
+    tag = "disputes"
+)]
+pub async fn stake_juror_vote(
+    State(db): State<Db>,
+    Path(dispute_id): Path<Uuid>,
+    AuthenticatedDid(juror_did): AuthenticatedDid,
+    Json(req): Json<StakeJurorVoteRequest>,
+) -> AppResult<Json<JurorVote>> {
+    let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(dispute_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let vote = jurors::stake_vote(&db, &dispute, &juror_did, req).await?;
+    Ok(Json(vote))
+}
+
+/// `GET /api/v1/disputes/{id}/jurors` — every staked juror vote on a
+/// dispute, for rendering how the juror pool is leaning before it closes.
+/// Gated the same as `list_evidence`: only the dispute's two parties and
+/// arbiters can see it, so a juror staking late can't see which side is
+/// ahead before deciding where to pile on.
+#[utoipa::path(
+    get,
+    path = "/api/v1/disputes/{id}/jurors",
+    params(("id" = Uuid, Path, description = "Dispute ID")),
+    responses((status = 200, description = "Staked juror votes on this dispute", body = Vec<JurorVote>)),
+    tag = "disputes"
+)]
+pub async fn list_jurors(
+    State(db): State<Db>,
+    Path(dispute_id): Path<Uuid>,
+    AuthenticatedDid(requester_did): AuthenticatedDid,
+) -> AppResult<Json<Vec<JurorVote>>> {
+    let dispute = sqlx::query_as::<_, Dispute>("SELECT * FROM disputes WHERE id = $1")
+        .bind(dispute_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let allowed = requester_did == dispute.initiator_did
+        || requester_did == dispute.respondent_did
+        || is_arbiter(&requester_did);
+    if !allowed {
+        return Err(AppError::Forbidden("not a party to this dispute".into()));
+    }
+
+    let votes = sqlx::query_as::<_, JurorVote>("SELECT * FROM juror_votes WHERE dispute_id = $1 ORDER BY created_at")
+        .bind(dispute_id)
+        .fetch_all(&db)
+        .await?;
+    Ok(Json(votes))
+}
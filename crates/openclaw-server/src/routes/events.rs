@@ -0,0 +1,44 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::stream::{self, Stream};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt as _;
+
+use crate::auth::AuthenticatedDid;
+use crate::services::event_bus;
+
+/// `GET /api/v1/events/stream` — a per-DID server-sent events feed of
+/// approval requests, submission decisions, dispute updates, and credit
+/// events, so CLIs and UIs can subscribe instead of polling. Backed by the
+/// shared `services::event_bus`, which owns the one `LISTEN/NOTIFY`
+/// connection this process needs; each notification carries the target DID
+/// so we can filter before re-emitting to the client.
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/stream",
+    responses((status = 200, description = "text/event-stream of events for the authenticated DID")),
+    tag = "events"
+)]
+pub async fn stream_events(
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(event_bus::subscribe()).filter_map(move |msg| {
+        let event = msg.ok()?;
+        if !matches!(
+            event.channel.as_str(),
+            "bounty_events" | "dispute_events" | "credit_events" | "approval_events"
+        ) {
+            return None;
+        }
+        if event.payload.get("did").and_then(|v| v.as_str()) != Some(did.as_str()) {
+            return None;
+        }
+        Some(Ok(Event::default().event(event.channel.clone()).json_data(event.payload).unwrap()))
+    });
+
+    let heartbeat = stream::repeat_with(|| Ok(Event::default().comment("keep-alive")))
+        .throttle(Duration::from_secs(30));
+    Sse::new(stream.merge(heartbeat)).keep_alive(KeepAlive::default())
+}
@@ -0,0 +1,39 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::db::Db;
+
+/// `GET /healthz` — liveness: the process is up and serving requests.
+/// Deliberately checks nothing else, so a slow dependency doesn't get a
+/// healthy pod killed by the kubelet.
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses((status = 200, description = "Process is up")),
+    tag = "health"
+)]
+pub async fn healthz() -> StatusCode {
+    StatusCode::OK
+}
+
+/// `GET /readyz` — readiness: the server can actually serve traffic right
+/// now. Checks the database is reachable; a pod failing this is pulled out
+/// of the load balancer without being restarted.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "Database is reachable"),
+        (status = 503, description = "Database is unreachable"),
+    ),
+    tag = "health"
+)]
+pub async fn readyz(State(db): State<Db>) -> StatusCode {
+    match sqlx::query("SELECT 1").execute(&db).await {
+        Ok(_) => StatusCode::OK,
+        Err(err) => {
+            tracing::warn!(?err, "readiness check failed: database unreachable");
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
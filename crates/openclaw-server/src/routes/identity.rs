@@ -0,0 +1,150 @@
+use axum::extract::State;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{DidBinding, DidKeyRotation, DidRebinding};
+use crate::services::{did_binding, key_rotation};
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct BindChallengeRequest {
+    pub did: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct BindChallengeResponse {
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// `POST /api/v1/identity/bind/challenge` — issues a nonce for `did` to
+/// sign, the first half of proving control before a binding is recorded.
+#[utoipa::path(
+    post,
+    path = "/api/v1/identity/bind/challenge",
+    request_body = BindChallengeRequest,
+    responses((status = 200, description = "Nonce to sign", body = BindChallengeResponse)),
+    tag = "identity"
+)]
+pub async fn bind_challenge(
+    State(db): State<Db>,
+    Json(req): Json<BindChallengeRequest>,
+) -> AppResult<Json<BindChallengeResponse>> {
+    let challenge = did_binding::issue_challenge(&db, &req.did).await?;
+    Ok(Json(BindChallengeResponse {
+        nonce: challenge.nonce,
+        expires_at: challenge.expires_at,
+    }))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct BindRequest {
+    pub did: String,
+    pub nonce: String,
+    pub signature: String,
+}
+
+/// `POST /api/v1/identity/bind` — completes the challenge-response and
+/// records the binding. `signature` must be over `{did, nonce}` using
+/// `openclaw-crypto`'s canonical JSON signing, proving the caller holds the
+/// DID's private key rather than just knowing the public identifier.
+#[utoipa::path(
+    post,
+    path = "/api/v1/identity/bind",
+    request_body = BindRequest,
+    responses((status = 200, description = "Binding recorded", body = DidBinding)),
+    tag = "identity"
+)]
+pub async fn bind(State(db): State<Db>, Json(req): Json<BindRequest>) -> AppResult<Json<DidBinding>> {
+    let binding = did_binding::verify_and_bind(&db, &req.did, &req.nonce, &req.signature).await?;
+    Ok(Json(binding))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RevokeBindingRequest {
+    pub did: String,
+    pub signature: String,
+}
+
+/// `POST /api/v1/identity/bind/revoke` — revokes `did`'s binding.
+/// `signature` must be over `{action: "revoke_did_binding", did}`, signed
+/// by `did` itself.
+#[utoipa::path(
+    post,
+    path = "/api/v1/identity/bind/revoke",
+    request_body = RevokeBindingRequest,
+    responses((status = 200, description = "Binding revoked", body = DidBinding)),
+    tag = "identity"
+)]
+pub async fn revoke_binding(
+    State(db): State<Db>,
+    Json(req): Json<RevokeBindingRequest>,
+) -> AppResult<Json<DidBinding>> {
+    let binding = did_binding::revoke(&db, &req.did, &req.signature).await?;
+    Ok(Json(binding))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RebindRequest {
+    pub old_did: String,
+    pub new_did: String,
+    pub new_nonce: String,
+    pub new_signature: String,
+    /// Signature over `{action: "rebind_did", old_did, new_did}` from
+    /// `old_did`, when its key is still available. Skips the cooldown on
+    /// propagating the balance and open bounties to `new_did`.
+    pub old_signature: Option<String>,
+}
+
+/// `POST /api/v1/identity/rebind` — revokes `old_did`'s binding and binds
+/// `new_did` in its place. `new_did` must complete the same nonce
+/// challenge as a fresh binding; an optional signed confirmation from
+/// `old_did` skips the cooldown before its balance and open bounties move
+/// over (see `services::did_binding::request_rebinding`).
+#[utoipa::path(
+    post,
+    path = "/api/v1/identity/rebind",
+    request_body = RebindRequest,
+    responses((status = 200, description = "Rebinding scheduled", body = DidRebinding)),
+    tag = "identity"
+)]
+pub async fn rebind(State(db): State<Db>, Json(req): Json<RebindRequest>) -> AppResult<Json<DidRebinding>> {
+    let rebinding = did_binding::request_rebinding(
+        &db,
+        &req.old_did,
+        &req.new_did,
+        &req.new_nonce,
+        &req.new_signature,
+        req.old_signature.as_deref(),
+    )
+    .await?;
+    Ok(Json(rebinding))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RotateKeyRequest {
+    pub old_did: String,
+    pub new_did: String,
+    /// Signature over `{action: "rotate_key", old_did, new_did}` from
+    /// `old_did`, endorsing `new_did` as its successor.
+    pub signature: String,
+}
+
+/// `POST /api/v1/identity/rotate` — records a signed key-rotation statement.
+/// Unlike `/identity/rebind`, this moves no funds and has no cooldown; it
+/// only extends `old_did`'s attribution history so artifact, bounty, and
+/// reputation lookups can resolve across the rotation (see
+/// `services::key_rotation::resolve_chain`).
+#[utoipa::path(
+    post,
+    path = "/api/v1/identity/rotate",
+    request_body = RotateKeyRequest,
+    responses((status = 200, description = "Key rotation recorded", body = DidKeyRotation)),
+    tag = "identity"
+)]
+pub async fn rotate(State(db): State<Db>, Json(req): Json<RotateKeyRequest>) -> AppResult<Json<DidKeyRotation>> {
+    let rotation = key_rotation::rotate(&db, &req.old_did, &req.new_did, &req.signature).await?;
+    Ok(Json(rotation))
+}
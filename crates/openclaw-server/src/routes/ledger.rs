@@ -0,0 +1,148 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::extract::Query;
+use axum::http::header;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt as _;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::error::AppResult;
+use crate::pagination;
+use crate::services::event_bus;
+
+#[derive(Debug, Clone, Serialize, FromRow, utoipa::ToSchema)]
+pub struct LedgerEntry {
+    pub id: Uuid,
+    pub did: String,
+    pub amount: Decimal,
+    pub event_type: String,
+    pub reference_id: Option<Uuid>,
+    /// Set when a delegate (see `services::delegation`) acted on `did`'s
+    /// behalf, so delegated spend is distinguishable from self-spend.
+    pub delegate_did: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct LedgerQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub event_type: Option<String>,
+    pub counterparty: Option<String>,
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub format: Option<String>,
+}
+
+/// `GET /api/v1/credits/ledger` — paginated, filterable view of a DID's
+/// ledger, replacing the hard-capped-at-10 `recent_transactions` field that
+/// was useless for audits. `format=csv` streams the same rows as CSV.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/ledger",
+    params(LedgerQuery),
+    responses((status = 200, description = "Page of ledger entries (JSON by default, or CSV if format=csv)", body = pagination::LedgerEntryPage)),
+    tag = "credits"
+)]
+pub async fn query_ledger(
+    AuthenticatedDid(did): AuthenticatedDid,
+    Query(q): Query<LedgerQuery>,
+) -> AppResult<Response> {
+    let limit = pagination::clamp_limit(q.limit);
+    let cursor = q.cursor.as_deref().map(pagination::decode_cursor).transpose()?;
+
+    let entries = sqlx::query_as::<_, LedgerEntry>(
+        "SELECT * FROM m_credits_ledger
+         WHERE did = $1
+           AND ($2::timestamptz IS NULL OR created_at >= $2)
+           AND ($3::timestamptz IS NULL OR created_at <= $3)
+           AND ($4::text IS NULL OR event_type = $4)
+           AND ($5::uuid IS NULL OR id > $5)
+         ORDER BY id ASC
+         LIMIT $6",
+    )
+    .bind(&did)
+    .bind(q.from)
+    .bind(q.to)
+    .bind(&q.event_type)
+    .bind(cursor)
+    .bind(limit)
+    .fetch_all(crate::db::replica())
+    .await?;
+
+    if q.format.as_deref() == Some("csv") {
+        let mut csv = String::from("id,amount,event_type,reference_id,delegate_did,created_at\n");
+        for e in &entries {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                e.id,
+                e.amount,
+                e.event_type,
+                e.reference_id.map(|r| r.to_string()).unwrap_or_default(),
+                e.delegate_did.clone().unwrap_or_default(),
+                e.created_at.to_rfc3339(),
+            ));
+        }
+        return Ok((
+            [(header::CONTENT_TYPE, "text/csv")],
+            csv,
+        )
+            .into_response());
+    }
+
+    Ok(Json(pagination::Page::new(entries, limit, |e| e.id)).into_response())
+}
+
+/// A ledger event or reserve attestation with anything that identifies the
+/// account it's about stripped out, for `stream_public_ledger` — auditors
+/// get issuance/escrow movement in aggregate, not who moved what.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct PublicLedgerEvent {
+    pub id: Uuid,
+    pub amount: Decimal,
+    pub event_type: String,
+    pub reference_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// `GET /api/v1/credits/ledger/stream` — an unauthenticated SSE firehose of
+/// every new ledger event and reserve attestation, in order, so an external
+/// auditor can maintain an independent mirror of credit issuance and
+/// escrow movement without trusting our own `/reserves` summaries. Backed
+/// by the shared `services::event_bus`, same as `events::stream_events`,
+/// but with no per-DID filter (it's public) and the `did`/`delegate_did`
+/// fields stripped before re-emission instead.
+#[utoipa::path(
+    get,
+    path = "/api/v1/credits/ledger/stream",
+    responses((status = 200, description = "text/event-stream of privacy-filtered ledger events and reserve attestations")),
+    tag = "credits"
+)]
+pub async fn stream_public_ledger() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(event_bus::subscribe()).filter_map(|msg| {
+        let mut event = msg.ok()?;
+        if !matches!(event.channel.as_str(), "ledger_events" | "reserve_attestation_events") {
+            return None;
+        }
+        if let Some(obj) = event.payload.as_object_mut() {
+            obj.remove("did");
+            obj.remove("delegate_did");
+        }
+        Some(Ok(Event::default().event(event.channel.clone()).json_data(event.payload).unwrap()))
+    });
+
+    let heartbeat = stream::repeat_with(|| Ok(Event::default().comment("keep-alive")))
+        .throttle(Duration::from_secs(30));
+    Sse::new(stream.merge(heartbeat)).keep_alive(KeepAlive::default())
+}
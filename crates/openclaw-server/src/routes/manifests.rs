@@ -0,0 +1,36 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use openclaw_crypto::types::ManifestV1;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::StoredManifest;
+use crate::services::manifests;
+
+/// `POST /api/v1/manifests` — accepts a signed contribution manifest,
+/// verifies the outer signature and registers each referenced artifact,
+/// and stores the manifest linked to the signer DID.
+#[utoipa::path(
+    post,
+    path = "/api/v1/manifests",
+    request_body = ManifestV1,
+    responses((status = 200, description = "Stored manifest", body = StoredManifest)),
+    tag = "manifests"
+)]
+pub async fn submit_manifest(State(db): State<Db>, Json(manifest): Json<ManifestV1>) -> AppResult<Json<StoredManifest>> {
+    let stored = manifests::submit(&db, manifest).await?;
+    Ok(Json(stored))
+}
+
+/// `GET /api/v1/manifests/{id}` — retrieves a previously stored manifest.
+#[utoipa::path(
+    get,
+    path = "/api/v1/manifests/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Manifest ID")),
+    responses((status = 200, description = "The stored manifest", body = StoredManifest)),
+    tag = "manifests"
+)]
+pub async fn get_manifest(State(db): State<Db>, Path(id): Path<uuid::Uuid>) -> AppResult<Json<StoredManifest>> {
+    let manifest = manifests::get(&db, id).await?.ok_or(AppError::NotFound)?;
+    Ok(Json(manifest))
+}
@@ -0,0 +1,299 @@
+pub mod admin;
+pub mod approvals;
+pub mod artifacts;
+pub mod bounties;
+pub mod bounty_templates;
+pub mod compute_providers;
+pub mod credits;
+pub mod disputes;
+pub mod events;
+pub mod health;
+pub mod identity;
+pub mod ledger;
+pub mod manifests;
+pub mod notifications;
+pub mod policies;
+pub mod posts;
+pub mod profile;
+pub mod promo;
+pub mod reputation;
+pub mod runners;
+pub mod submissions;
+pub mod tags;
+pub mod usage;
+pub mod well_known;
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::routing::{get, patch, post, put};
+use axum::Router;
+use metrics_exporter_prometheus::PrometheusHandle;
+use tower::ServiceBuilder;
+use tower_http::trace::TraceLayer;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::db::Db;
+use crate::metrics;
+use crate::openapi::ApiDoc;
+use crate::request_id;
+use crate::security;
+
+/// `/api/v1/credits/webhooks/stripe` only ever carries a small signed JSON
+/// event; a tight limit here keeps a misbehaving or malicious sender from
+/// tying up a worker on a huge body before signature verification even
+/// runs.
+fn webhook_routes() -> Router<Db> {
+    Router::new()
+        .route("/api/v1/credits/webhooks/stripe", post(credits::stripe_webhook))
+        .layer(security::body_limit(security::WEBHOOK_BODY_LIMIT_BYTES))
+}
+
+/// Routes that can legitimately carry a large JSON body: artifact
+/// registration embeds a full signature envelope, and manifests can list
+/// many contributions at once.
+fn large_body_routes() -> Router<Db> {
+    Router::new()
+        .route(
+            "/api/v1/artifacts",
+            get(artifacts::list_artifacts).post(artifacts::register_artifact),
+        )
+        .route("/api/v1/artifacts/:id/confirm-upload", post(artifacts::confirm_upload))
+        .route("/api/v1/manifests", post(manifests::submit_manifest))
+        .route("/api/v1/manifests/:id", get(manifests::get_manifest))
+        .layer(security::body_limit(security::LARGE_BODY_LIMIT_BYTES))
+}
+
+/// Records an `ApiRequest` usage event for the caller's DID and rejects
+/// the request once it's over `config::current().max_api_requests_per_day`.
+/// Requests with no `X-Protocol-M-Did` header aren't metered here; they'll
+/// fail their own `AuthenticatedDid` extraction downstream instead.
+async fn track_usage(State(db): State<Db>, req: Request, next: Next) -> Result<Response, crate::error::AppError> {
+    if let Some(did) = req
+        .headers()
+        .get("x-protocol-m-did")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+    {
+        crate::services::usage::check_quota(
+            &db,
+            &did,
+            crate::models::UsageMetric::ApiRequest,
+            crate::config::current().max_api_requests_per_day,
+        )
+        .await?;
+        crate::services::usage::record(&db, &did, crate::models::UsageMetric::ApiRequest).await?;
+    }
+    Ok(next.run(req).await)
+}
+
+pub fn create_router(db: Db, metrics_handle: PrometheusHandle) -> Router {
+    let metrics_router = Router::new()
+        .route("/metrics", get(metrics::serve_metrics))
+        .with_state(metrics_handle);
+
+    let rest = Router::new()
+        .merge(SwaggerUi::new("/swagger-ui").url("/api/v1/openapi.json", ApiDoc::openapi()))
+        .merge(metrics_router)
+        .route(
+            "/api/v1/bounties/:id",
+            get(bounties::get_bounty).patch(bounties::patch_bounty),
+        )
+        .route(
+            "/api/v1/bounty-templates",
+            get(bounty_templates::list_templates).post(bounty_templates::create_template),
+        )
+        .route(
+            "/api/v1/bounty-templates/:id/instantiate",
+            post(bounty_templates::instantiate),
+        )
+        .route("/api/v1/tags", get(tags::list_taxonomy))
+        .route("/api/v1/tags/subscriptions", get(tags::list_subscriptions))
+        .route("/api/v1/tags/:tag/subscribe", post(tags::subscribe))
+        .route("/api/v1/tags/:tag/unsubscribe", post(tags::unsubscribe))
+        .route(
+            "/api/v1/bounties/:id/submissions",
+            get(submissions::list_bounty_submissions).post(submissions::create_submission),
+        )
+        .route("/api/v1/submissions", get(submissions::list_submissions))
+        .route(
+            "/api/v1/bounties/:id/select-winner",
+            post(submissions::select_winner),
+        )
+        .route(
+            "/api/v1/bounties/:id/trusted-runners",
+            get(bounties::list_trusted_runners).post(bounties::add_trusted_runner),
+        )
+        .route(
+            "/api/v1/submissions/:id/review",
+            post(submissions::review_submission),
+        )
+        .route(
+            "/api/v1/submissions/:id/withdraw",
+            post(submissions::withdraw),
+        )
+        .route(
+            "/api/v1/submissions/:id/similarity-report",
+            get(submissions::get_similarity_report),
+        )
+        .route(
+            "/api/v1/submissions/:id/execution-receipt",
+            post(submissions::submit_execution_receipt),
+        )
+        .route("/api/v1/approvals", get(approvals::list).post(approvals::create))
+        .route("/api/v1/approvals/:id/decide", post(approvals::decide))
+        .route("/api/v1/disputes", post(disputes::create_dispute))
+        .route(
+            "/api/v1/disputes/:id/resolve",
+            post(disputes::resolve_dispute),
+        )
+        .route(
+            "/api/v1/disputes/:id/evidence",
+            get(disputes::list_evidence).post(disputes::attach_evidence),
+        )
+        .route("/api/v1/disputes/:id/jurors", get(disputes::list_jurors))
+        .route("/api/v1/disputes/:id/jurors/vote", post(disputes::stake_juror_vote))
+        .route("/api/v1/events/stream", get(events::stream_events))
+        .route(
+            "/api/v1/admin/webhooks/failures",
+            get(admin::list_webhook_failures),
+        )
+        .route(
+            "/api/v1/admin/webhooks/failures/:id/replay",
+            post(admin::replay_webhook_failure),
+        )
+        .route(
+            "/api/v1/admin/notifications/failures",
+            get(admin::list_notification_failures),
+        )
+        .route(
+            "/api/v1/admin/notifications/failures/:id/replay",
+            post(admin::replay_notification_failure),
+        )
+        .route(
+            "/api/v1/admin/ledger/discrepancies",
+            get(admin::list_ledger_discrepancies),
+        )
+        .route(
+            "/api/v1/admin/ledger/rebuild",
+            post(admin::rebuild_ledger_projection),
+        )
+        .route("/api/v1/admin/config", get(admin::get_config))
+        .route("/api/v1/admin/treasury", get(admin::get_treasury_balance))
+        .route("/api/v1/admin/audit-log", get(admin::list_audit_log))
+        .route(
+            "/api/v1/admin/purchase-reviews",
+            get(admin::list_purchase_reviews),
+        )
+        .route(
+            "/api/v1/admin/purchase-reviews/:id/decide",
+            post(admin::decide_purchase_review),
+        )
+        .route(
+            "/api/v1/admin/compute-providers",
+            get(compute_providers::list).post(compute_providers::create),
+        )
+        .route("/api/v1/admin/compute-providers/:id", patch(compute_providers::update))
+        .route("/api/v1/admin/compute-providers/:id/deactivate", post(compute_providers::deactivate))
+        .route("/api/v1/admin/compute-providers/:id/activate", post(compute_providers::activate))
+        .route(
+            "/api/v1/admin/runners/:id/status",
+            post(runners::set_status),
+        )
+        .route(
+            "/api/v1/runners",
+            get(runners::list).post(runners::register),
+        )
+        .route(
+            "/api/v1/notifications/preferences",
+            get(notifications::get_preferences).put(notifications::put_preferences),
+        )
+        .route("/api/v1/credits/purchase", post(credits::purchase))
+        .route("/api/v1/credits/redeem", post(credits::redeem_credits))
+        .route("/api/v1/credits/redemptions", get(credits::list_redemptions))
+        .route("/api/v1/credits/grant-promo", post(promo::grant_promo))
+        .route("/api/v1/credits/grant-promo/batch", post(promo::grant_promo_batch))
+        .route("/api/v1/credits/kyc/refresh", post(credits::refresh_kyc))
+        .route("/api/v1/credits/invoices", get(credits::list_invoices))
+        .route("/api/v1/credits/invoices/:id", get(credits::get_invoice))
+        .route(
+            "/api/v1/credits/apple-pay/validate-merchant",
+            post(credits::apple_pay_validate_merchant),
+        )
+        .route("/api/v1/credits/apple-pay/charge", post(credits::apple_pay_charge))
+        .route(
+            "/api/v1/bounties",
+            get(bounties::list_bounties).post(bounties::create_bounty),
+        )
+        .route("/api/v1/credits/ledger", get(ledger::query_ledger))
+        .route("/api/v1/credits/ledger/stream", get(ledger::stream_public_ledger))
+        .route(
+            "/api/v1/credits/reserves/attestation",
+            get(credits::get_reserve_attestation),
+        )
+        .route(
+            "/api/v1/credits/reserves/verify",
+            post(credits::verify_reserve_attestation),
+        )
+        .route(
+            "/api/v1/credits/reserves/history",
+            get(credits::reserve_attestation_history),
+        )
+        .route("/.well-known/did.json", get(well_known::did_document))
+        .route("/.well-known/did/:did", get(well_known::resolve_did))
+        .route("/api/v1/identity/bind/challenge", post(identity::bind_challenge))
+        .route("/api/v1/identity/bind", post(identity::bind))
+        .route("/api/v1/identity/bind/revoke", post(identity::revoke_binding))
+        .route("/api/v1/identity/rebind", post(identity::rebind))
+        .route("/api/v1/identity/rotate", post(identity::rotate))
+        .route(
+            "/api/v1/policies/:did",
+            get(policies::get_policy).put(policies::set_policy),
+        )
+        .route("/api/v1/policies/:did/history", get(policies::get_policy_history))
+        .route("/api/v1/policies/:did/rollback", post(policies::rollback_policy))
+        .route("/api/v1/policies/:did/freeze", post(policies::freeze))
+        .route("/api/v1/policies/:did/unfreeze", post(policies::unfreeze))
+        .route(
+            "/api/v1/artifacts/by-hash/:sha256",
+            get(artifacts::get_by_hash).head(artifacts::check_by_hash),
+        )
+        .route("/api/v1/artifacts/:id/lineage", get(artifacts::get_lineage))
+        .route("/api/v1/artifacts/:id/similar", get(artifacts::get_similar))
+        .route("/api/v1/artifacts/:id/derivations/resolve", post(artifacts::resolve_derivation))
+        .route("/api/v1/artifacts/:id/revoke", post(artifacts::revoke))
+        .route("/api/v1/artifacts/:id/upload-url", post(artifacts::get_upload_url))
+        .route("/api/v1/artifacts/:id/download-url", get(artifacts::get_download_url))
+        .route("/api/v1/reputation/leaderboard", get(reputation::leaderboard))
+        .route("/api/v1/reputation/:did/history", get(reputation::history))
+        .route("/api/v1/posts", get(posts::list_posts).post(posts::create_post))
+        .route("/api/v1/posts/:id/comments", get(posts::list_comments).post(posts::create_comment))
+        .route("/api/v1/profile", put(profile::put_profile))
+        .route("/api/v1/profile/:did", get(profile::get_profile))
+        .route("/api/v1/usage", get(usage::get_usage))
+        .route("/healthz", get(health::healthz))
+        .route("/readyz", get(health::readyz))
+        .layer(security::body_limit(security::DEFAULT_BODY_LIMIT_BYTES));
+
+    let mut router = rest.merge(webhook_routes()).merge(large_body_routes());
+    for header_layer in security::security_headers() {
+        router = router.layer(header_layer);
+    }
+
+    router
+        .route_layer(axum::middleware::from_fn_with_state(db.clone(), track_usage))
+        .route_layer(axum::middleware::from_fn(metrics::track_http_metrics))
+        .layer(
+            ServiceBuilder::new()
+                .layer(security::cors_layer())
+                .layer(request_id::set_request_id_layer())
+                .layer(request_id::propagate_request_id_layer())
+                .layer(
+                    TraceLayer::new_for_http()
+                        .make_span_with(request_id::make_span)
+                        .on_response(request_id::on_response),
+                ),
+        )
+        .with_state(db)
+}
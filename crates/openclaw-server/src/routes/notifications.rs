@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::Json;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{NotificationPreferences, UpdateNotificationPreferencesRequest};
+
+/// `GET /api/v1/notifications/preferences` — the caller's notification
+/// preferences, defaulting to email on every event type if never set.
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications/preferences",
+    responses((status = 200, description = "Notification preferences", body = NotificationPreferences)),
+    tag = "notifications"
+)]
+pub async fn get_preferences(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<NotificationPreferences>> {
+    let prefs = sqlx::query_as::<_, NotificationPreferences>(
+        "SELECT * FROM notification_preferences WHERE did = $1",
+    )
+    .bind(&did)
+    .fetch_optional(&db)
+    .await?
+    .unwrap_or(NotificationPreferences {
+        did,
+        channels: vec!["email".into()],
+        event_types: vec!["approvals".into(), "submissions".into(), "credits".into(), "disputes".into()],
+        updated_at: chrono::Utc::now(),
+    });
+    Ok(Json(prefs))
+}
+
+/// `PUT /api/v1/notifications/preferences` — replaces the caller's
+/// notification preferences.
+#[utoipa::path(
+    put,
+    path = "/api/v1/notifications/preferences",
+    request_body = UpdateNotificationPreferencesRequest,
+    responses((status = 200, description = "Updated notification preferences", body = NotificationPreferences)),
+    tag = "notifications"
+)]
+pub async fn put_preferences(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<UpdateNotificationPreferencesRequest>,
+) -> AppResult<Json<NotificationPreferences>> {
+    let prefs = sqlx::query_as::<_, NotificationPreferences>(
+        "INSERT INTO notification_preferences (did, channels, event_types, updated_at)
+         VALUES ($1, $2, $3, now())
+         ON CONFLICT (did) DO UPDATE SET channels = excluded.channels, event_types = excluded.event_types, updated_at = now()
+         RETURNING *",
+    )
+    .bind(&did)
+    .bind(&req.channels)
+    .bind(&req.event_types)
+    .fetch_one(&db)
+    .await?;
+    Ok(Json(prefs))
+}
@@ -0,0 +1,162 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde::Deserialize;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{AccountFreezeEvent, PolicyRevision, UserPolicy};
+use crate::services::policy::{self, SetPolicyPayload};
+
+fn is_admin(did: &str) -> bool {
+    std::env::var("ADMIN_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetPolicyRequest {
+    pub document: SetPolicyPayload,
+    /// Signature over `{action: "set_policy", did, ...document}`, signed by
+    /// `did`.
+    pub signature: String,
+}
+
+/// `PUT /api/v1/policies/{did}` — syncs the CLI's local `policy set` to the
+/// server-side policy that `services::spend_limits`, `services::delegation`,
+/// and `services::approvals::tier_for_amount` actually enforce. See
+/// `services::policy::set` for signature verification, tier validation, and
+/// the optimistic-concurrency `version` check.
+#[utoipa::path(
+    put,
+    path = "/api/v1/policies/{did}",
+    params(("did" = String, Path, description = "DID the policy governs")),
+    request_body = SetPolicyRequest,
+    responses((status = 200, description = "Policy stored", body = UserPolicy)),
+    tag = "policies"
+)]
+pub async fn set_policy(
+    State(db): State<Db>,
+    Path(did): Path<String>,
+    Json(req): Json<SetPolicyRequest>,
+) -> AppResult<Json<UserPolicy>> {
+    let policy = policy::set(&db, &did, req.document, &req.signature).await?;
+    Ok(Json(policy))
+}
+
+/// `GET /api/v1/policies/{did}` — the policy currently enforced for `did`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/policies/{did}",
+    params(("did" = String, Path, description = "DID the policy governs")),
+    responses((status = 200, description = "Current policy", body = UserPolicy)),
+    tag = "policies"
+)]
+pub async fn get_policy(State(db): State<Db>, Path(did): Path<String>) -> AppResult<Json<UserPolicy>> {
+    let policy = policy::get(&db, &did).await?;
+    Ok(Json(policy))
+}
+
+/// `GET /api/v1/policies/{did}/history` — every revision `did`'s policy has
+/// gone through, newest first, each with the signing envelope that
+/// authorized it, so an operator can audit who loosened an approval
+/// threshold and when.
+#[utoipa::path(
+    get,
+    path = "/api/v1/policies/{did}/history",
+    params(("did" = String, Path, description = "DID the policy governs")),
+    responses((status = 200, description = "Policy revisions, newest first", body = Vec<PolicyRevision>)),
+    tag = "policies"
+)]
+pub async fn get_policy_history(State(db): State<Db>, Path(did): Path<String>) -> AppResult<Json<Vec<PolicyRevision>>> {
+    let revisions = policy::history(&db, &did).await?;
+    Ok(Json(revisions))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RollbackPolicyRequest {
+    pub target_version: i32,
+    /// Signature over `{action: "rollback_policy", did, target_version}`,
+    /// signed by `did`.
+    pub signature: String,
+}
+
+/// `POST /api/v1/policies/{did}/rollback` — re-applies `target_version`'s
+/// document as a brand-new revision rather than rewriting history in place,
+/// so a loosened approval threshold can be reverted without losing the
+/// record that it was ever loosened.
+#[utoipa::path(
+    post,
+    path = "/api/v1/policies/{did}/rollback",
+    params(("did" = String, Path, description = "DID the policy governs")),
+    request_body = RollbackPolicyRequest,
+    responses((status = 200, description = "Policy rolled back", body = UserPolicy)),
+    tag = "policies"
+)]
+pub async fn rollback_policy(
+    State(db): State<Db>,
+    Path(did): Path<String>,
+    Json(req): Json<RollbackPolicyRequest>,
+) -> AppResult<Json<UserPolicy>> {
+    let policy = policy::rollback(&db, &did, req.target_version, &req.signature).await?;
+    Ok(Json(policy))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct FreezeAccountRequest {
+    pub reason: String,
+}
+
+/// `POST /api/v1/policies/{did}/freeze` — immediately blocks new bounties
+/// and redemptions for `did` (see `services::policy::assert_not_frozen`)
+/// until unfrozen. Callable by `did` itself, or by an operator who has
+/// verified the emergency contact out of band — `ADMIN_DIDS` stands in for
+/// that verification the same way it gates `routes::runners::set_status`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/policies/{did}/freeze",
+    params(("did" = String, Path, description = "DID to freeze")),
+    request_body = FreezeAccountRequest,
+    responses((status = 200, description = "Account frozen", body = AccountFreezeEvent)),
+    tag = "policies"
+)]
+pub async fn freeze(
+    State(db): State<Db>,
+    Path(did): Path<String>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<FreezeAccountRequest>,
+) -> AppResult<Json<AccountFreezeEvent>> {
+    if caller_did != did && !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("only the account owner or an operator can freeze this account".into()));
+    }
+    let event = policy::set_frozen(&db, &did, true, &req.reason, &caller_did).await?;
+    Ok(Json(event))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UnfreezeAccountRequest {
+    pub reason: String,
+}
+
+/// `POST /api/v1/policies/{did}/unfreeze` — lifts a freeze placed by
+/// `POST /api/v1/policies/{did}/freeze`. Same callers as freezing.
+#[utoipa::path(
+    post,
+    path = "/api/v1/policies/{did}/unfreeze",
+    params(("did" = String, Path, description = "DID to unfreeze")),
+    request_body = UnfreezeAccountRequest,
+    responses((status = 200, description = "Account unfrozen", body = AccountFreezeEvent)),
+    tag = "policies"
+)]
+pub async fn unfreeze(
+    State(db): State<Db>,
+    Path(did): Path<String>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<UnfreezeAccountRequest>,
+) -> AppResult<Json<AccountFreezeEvent>> {
+    if caller_did != did && !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("only the account owner or an operator can unfreeze this account".into()));
+    }
+    let event = policy::set_frozen(&db, &did, false, &req.reason, &caller_did).await?;
+    Ok(Json(event))
+}
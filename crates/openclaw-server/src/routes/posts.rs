@@ -0,0 +1,110 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{Comment, Post};
+use crate::services::posts;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreatePostRequest {
+    pub body: String,
+    /// Signature over `{action: "create_post", body}` from the caller's DID,
+    /// proving authorship rather than just trusting the caller's header.
+    pub signature: String,
+}
+
+/// `POST /api/v1/posts` — creates a post attributed to the caller's DID.
+/// `signature` must verify against the post body; see
+/// `services::posts::create_post`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/posts",
+    request_body = CreatePostRequest,
+    responses((status = 200, description = "Post created", body = Post)),
+    tag = "posts"
+)]
+pub async fn create_post(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<CreatePostRequest>,
+) -> AppResult<Json<Post>> {
+    let post = posts::create_post(&db, &did, &req.body, &req.signature).await?;
+    Ok(Json(post))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListPostsQuery {
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/posts` — the feed, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts",
+    params(ListPostsQuery),
+    responses((status = 200, description = "Page of posts, newest first", body = Vec<Post>)),
+    tag = "posts"
+)]
+pub async fn list_posts(State(db): State<Db>, Query(q): Query<ListPostsQuery>) -> AppResult<Json<Vec<Post>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let posts = posts::list_posts(&db, q.cursor, limit).await?;
+    Ok(Json(posts))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateCommentRequest {
+    pub body: String,
+    /// Signature over `{action: "create_comment", post_id, body}` from the
+    /// caller's DID.
+    pub signature: String,
+}
+
+/// `POST /api/v1/posts/{id}/comments` — comments on a post, attributed to
+/// the caller's DID. `signature` must verify against the post ID and
+/// comment body; see `services::posts::create_comment`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/posts/{id}/comments",
+    params(("id" = Uuid, Path, description = "Post to comment on")),
+    request_body = CreateCommentRequest,
+    responses((status = 200, description = "Comment created", body = Comment)),
+    tag = "posts"
+)]
+pub async fn create_comment(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<CreateCommentRequest>,
+) -> AppResult<Json<Comment>> {
+    let comment = posts::create_comment(&db, id, &did, &req.body, &req.signature).await?;
+    Ok(Json(comment))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListCommentsQuery {
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/posts/{id}/comments` — a post's comments, oldest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts/{id}/comments",
+    params(("id" = Uuid, Path, description = "Post to fetch comments for"), ListCommentsQuery),
+    responses((status = 200, description = "Page of comments, oldest first", body = Vec<Comment>)),
+    tag = "posts"
+)]
+pub async fn list_comments(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    Query(q): Query<ListCommentsQuery>,
+) -> AppResult<Json<Vec<Comment>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let comments = posts::list_comments(&db, id, q.cursor, limit).await?;
+    Ok(Json(comments))
+}
@@ -0,0 +1,43 @@
+use axum::extract::{Path, State};
+use axum::Json;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{Profile, ProfileView, UpdateProfileRequest};
+use crate::services::profiles;
+
+/// `PUT /api/v1/profile` — upserts the caller's profile. `signature` must
+/// verify against the new `display_name`/`links`/`avatar_artifact_id`; see
+/// `services::profiles::upsert`.
+#[utoipa::path(
+    put,
+    path = "/api/v1/profile",
+    request_body = UpdateProfileRequest,
+    responses((status = 200, description = "Profile updated", body = Profile)),
+    tag = "profile"
+)]
+pub async fn put_profile(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Json(req): Json<UpdateProfileRequest>,
+) -> AppResult<Json<Profile>> {
+    let profile =
+        profiles::upsert(&db, &did, req.display_name, req.links, req.avatar_artifact_id, &req.signature).await?;
+    Ok(Json(profile))
+}
+
+/// `GET /api/v1/profile/{did}` — a DID's public profile: its self-asserted
+/// metadata, a verified-artifact count, its reputation score, and a
+/// resolvable public-key record. See `services::profiles::view`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/profile/{did}",
+    params(("did" = String, Path, description = "DID to look up")),
+    responses((status = 200, description = "The DID's public profile", body = ProfileView)),
+    tag = "profile"
+)]
+pub async fn get_profile(State(db): State<Db>, Path(did): Path<String>) -> AppResult<Json<ProfileView>> {
+    let view = profiles::view(&db, &did).await?;
+    Ok(Json(view))
+}
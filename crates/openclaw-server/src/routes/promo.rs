@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use axum::extract::State;
+use axum::Json;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::AuthenticatedDid;
+use crate::config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::PromoGrant;
+use crate::services::promo;
+
+fn is_admin(did: &str) -> bool {
+    std::env::var("ADMIN_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct GrantPromoRequest {
+    pub did: String,
+    pub amount: Decimal,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// `POST /api/v1/credits/grant-promo` — grants one DID a promo credit,
+/// for a single hand-placed grant. See `grant_promo_batch` for
+/// marketing/hackathon campaigns granting many DIDs at once.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/grant-promo",
+    request_body = GrantPromoRequest,
+    responses((status = 200, description = "Promo grant created", body = PromoGrant)),
+    tag = "credits"
+)]
+pub async fn grant_promo(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<GrantPromoRequest>,
+) -> AppResult<Json<PromoGrant>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+
+    let grant = promo::grant(&db, &req.did, req.amount, req.expires_at, &caller_did).await?;
+    Ok(Json(grant))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct GrantPromoBatchRequest {
+    pub grants: Vec<GrantPromoRequest>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct GrantPromoBatchResult {
+    pub did: String,
+    pub amount: Decimal,
+    pub grant: Option<PromoGrant>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct GrantPromoBatchResponse {
+    pub results: Vec<GrantPromoBatchResult>,
+}
+
+/// `POST /api/v1/credits/grant-promo/batch` — grants promo credit to many
+/// DIDs in one call, for marketing campaigns and hackathon payouts.
+///
+/// The aggregate cap check happens up front, against the batch's total
+/// requested amount, before any row is applied: a campaign that asks for
+/// more than `RuntimeConfig::promo_credit_cap` in total is rejected
+/// outright rather than partially applied. Each row is still its own
+/// transaction via `services::promo::grant` (which also enforces the same
+/// cap per-DID against that DID's pre-existing outstanding balance), so
+/// one row's failure — insufficient headroom, a bad DID — doesn't roll
+/// back the rows around it; the response reports success or failure per
+/// row rather than all-or-nothing for the whole batch.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credits/grant-promo/batch",
+    request_body = GrantPromoBatchRequest,
+    responses((status = 200, description = "Per-row grant results", body = GrantPromoBatchResponse)),
+    tag = "credits"
+)]
+pub async fn grant_promo_batch(
+    State(db): State<Db>,
+    AuthenticatedDid(caller_did): AuthenticatedDid,
+    Json(req): Json<GrantPromoBatchRequest>,
+) -> AppResult<Json<GrantPromoBatchResponse>> {
+    if !is_admin(&caller_did) {
+        return Err(AppError::Forbidden("caller is not an admin".into()));
+    }
+
+    let batch_total: Decimal = req.grants.iter().map(|g| g.amount).sum();
+    let cap = config::current().promo_credit_cap;
+    if batch_total > cap {
+        return Err(AppError::BadRequest(format!(
+            "batch total {batch_total} exceeds the promo credit cap of {cap}"
+        )));
+    }
+
+    let mut results = Vec::with_capacity(req.grants.len());
+    for row in req.grants {
+        let outcome = promo::grant(&db, &row.did, row.amount, row.expires_at, &caller_did).await;
+        results.push(match outcome {
+            Ok(grant) => GrantPromoBatchResult {
+                did: row.did,
+                amount: row.amount,
+                grant: Some(grant),
+                error: None,
+            },
+            Err(e) => GrantPromoBatchResult {
+                did: row.did,
+                amount: row.amount,
+                grant: None,
+                error: Some(e.to_string()),
+            },
+        });
+    }
+
+    Ok(Json(GrantPromoBatchResponse { results }))
+}
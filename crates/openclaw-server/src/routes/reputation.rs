@@ -0,0 +1,139 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::{ClosureType, ReputationEvent};
+
+#[derive(Debug, Clone, Serialize, FromRow, utoipa::ToSchema)]
+pub struct ClosureTypeBreakdown {
+    /// `None` covers reputation events not tied to a bounty closure (manual
+    /// slashes, decay).
+    pub closure_type: Option<ClosureType>,
+    pub score: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct LeaderboardEntry {
+    pub did: String,
+    pub score: Decimal,
+    pub breakdown: Vec<ClosureTypeBreakdown>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct LeaderboardQuery {
+    /// `all_time` (default) or `30d`.
+    pub period: Option<String>,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/reputation/leaderboard` — public contributors page, ranked
+/// by reputation score. `period=30d` restricts to reputation events from
+/// the last 30 days instead of the all-time running score; either way each
+/// entry is broken down by the closure type of the bounties it earned
+/// reputation from.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reputation/leaderboard",
+    params(LeaderboardQuery),
+    responses((status = 200, description = "Leaderboard page, highest score first", body = Vec<LeaderboardEntry>)),
+    tag = "reputation"
+)]
+pub async fn leaderboard(
+    State(db): State<Db>,
+    Query(q): Query<LeaderboardQuery>,
+) -> AppResult<Json<Vec<LeaderboardEntry>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let offset = q.offset.unwrap_or(0).max(0);
+    let since = if q.period.as_deref() == Some("30d") {
+        Some(chrono::Utc::now() - chrono::Duration::days(30))
+    } else {
+        None
+    };
+
+    let rows: Vec<(String, Decimal)> = if let Some(since) = since {
+        sqlx::query_as(
+            "SELECT did, SUM(amount) AS score FROM reputation_events
+             WHERE created_at >= $1
+             GROUP BY did
+             ORDER BY score DESC
+             LIMIT $2 OFFSET $3",
+        )
+        .bind(since)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&db)
+        .await?
+    } else {
+        sqlx::query_as(
+            "SELECT did, score FROM reputation_scores
+             ORDER BY score DESC
+             LIMIT $1 OFFSET $2",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&db)
+        .await?
+    };
+
+    let mut entries = Vec::with_capacity(rows.len());
+    for (did, score) in rows {
+        let breakdown = sqlx::query_as::<_, ClosureTypeBreakdown>(
+            "SELECT b.closure_type AS closure_type, SUM(e.amount) AS score
+             FROM reputation_events e
+             LEFT JOIN bounties b ON b.id = e.bounty_id
+             WHERE e.did = $1 AND ($2::timestamptz IS NULL OR e.created_at >= $2)
+             GROUP BY b.closure_type",
+        )
+        .bind(&did)
+        .bind(since)
+        .fetch_all(&db)
+        .await?;
+
+        entries.push(LeaderboardEntry { did, score, breakdown });
+    }
+
+    Ok(Json(entries))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ReputationHistoryQuery {
+    pub cursor: Option<uuid::Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/reputation/{did}/history` — the individual mint/decay/slash
+/// events behind a DID's score, newest first, each with the bounty and
+/// submission it's linked to (when there is one).
+#[utoipa::path(
+    get,
+    path = "/api/v1/reputation/{did}/history",
+    params(("did" = String, Path, description = "DID to fetch reputation history for"), ReputationHistoryQuery),
+    responses((status = 200, description = "Page of reputation events, newest first", body = Vec<ReputationEvent>)),
+    tag = "reputation"
+)]
+pub async fn history(
+    State(db): State<Db>,
+    Path(did): Path<String>,
+    Query(q): Query<ReputationHistoryQuery>,
+) -> AppResult<Json<Vec<ReputationEvent>>> {
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let events = sqlx::query_as::<_, ReputationEvent>(
+        "SELECT * FROM reputation_events
+         WHERE did = $1
+           AND ($2::uuid IS NULL OR (created_at, id) < (SELECT created_at, id FROM reputation_events WHERE id = $2))
+         ORDER BY created_at DESC, id DESC
+         LIMIT $3",
+    )
+    .bind(&did)
+    .bind(q.cursor)
+    .bind(limit)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(events))
+}
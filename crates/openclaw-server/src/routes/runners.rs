@@ -0,0 +1,110 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{RunnerRegistration, RunnerRegistrationStatus};
+use crate::services::{audit, runner_registry};
+
+fn is_admin(did: &str) -> bool {
+    std::env::var("ADMIN_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RegisterRunnerRequest {
+    pub harness_types: Vec<String>,
+    #[serde(default)]
+    pub attestation: Value,
+}
+
+/// `POST /api/v1/runners` — a runner operator applies (or re-applies) for
+/// the global registry. Starts `pending` until an operator approves it via
+/// `POST /api/v1/admin/runners/{id}/status`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/runners",
+    request_body = RegisterRunnerRequest,
+    responses((status = 200, description = "Runner registration", body = RunnerRegistration)),
+    tag = "runners"
+)]
+pub async fn register(
+    State(db): State<Db>,
+    AuthenticatedDid(runner_did): AuthenticatedDid,
+    Json(req): Json<RegisterRunnerRequest>,
+) -> AppResult<Json<RunnerRegistration>> {
+    let registration = runner_registry::register(&db, &runner_did, req.harness_types, req.attestation).await?;
+
+    audit::record(
+        &db,
+        &runner_did,
+        "register_runner",
+        "runner_registration",
+        registration.id,
+        None,
+        Some(serde_json::json!(&registration)),
+    )
+    .await?;
+
+    Ok(Json(registration))
+}
+
+/// `GET /api/v1/runners` — the public directory of approved runners, so
+/// submitters know whose execution receipts a test-closure bounty is
+/// likely to accept.
+#[utoipa::path(
+    get,
+    path = "/api/v1/runners",
+    responses((status = 200, description = "Approved runners, oldest first", body = Vec<RunnerRegistration>)),
+    tag = "runners"
+)]
+pub async fn list(State(db): State<Db>) -> AppResult<Json<Vec<RunnerRegistration>>> {
+    let registrations = runner_registry::list_approved(&db).await?;
+    Ok(Json(registrations))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetRunnerStatusRequest {
+    pub status: RunnerRegistrationStatus,
+}
+
+/// `POST /api/v1/admin/runners/{id}/status` — an operator approves,
+/// rejects, or suspends a runner's registry application.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/runners/{id}/status",
+    params(("id" = Uuid, Path, description = "Runner registration ID")),
+    request_body = SetRunnerStatusRequest,
+    responses((status = 200, description = "Updated runner registration", body = RunnerRegistration)),
+    tag = "admin"
+)]
+pub async fn set_status(
+    State(db): State<Db>,
+    Path(id): Path<Uuid>,
+    AuthenticatedDid(operator_did): AuthenticatedDid,
+    Json(req): Json<SetRunnerStatusRequest>,
+) -> AppResult<Json<RunnerRegistration>> {
+    if !is_admin(&operator_did) {
+        return Err(AppError::Forbidden("caller is not an operator".into()));
+    }
+
+    let registration = runner_registry::set_status(&db, id, req.status).await?;
+
+    audit::record(
+        &db,
+        &operator_did,
+        "set_runner_status",
+        "runner_registration",
+        id,
+        None,
+        Some(serde_json::json!(&registration)),
+    )
+    .await?;
+
+    Ok(Json(registration))
+}
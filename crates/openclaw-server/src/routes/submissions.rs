@@ -0,0 +1,510 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    Artifact, Bounty, BountyStatus, ClosureType, ExecutionReceipt, Submission, SubmissionReview,
+    SubmissionStatus, SubmissionSimilarityReport, SubmitReviewRequest,
+};
+use crate::services::{audit, escrow, execution_receipts, plagiarism, quorum, reviewer_pool};
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ReviewResponse {
+    pub review: SubmissionReview,
+    pub bounty_closed: bool,
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct CreateSubmissionRequest {
+    pub bounty_id: Uuid,
+    pub artifact_id: Option<Uuid>,
+}
+
+/// `POST /bounties/{id}/submissions` — records a submission and, for
+/// quorum-closure bounties, immediately kicks off reviewer assignment so
+/// the quorum can start accumulating votes.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounties/{id}/submissions",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    request_body = CreateSubmissionRequest,
+    responses((status = 200, description = "Recorded submission", body = Submission)),
+    tag = "submissions"
+)]
+pub async fn create_submission(
+    State(db): State<Db>,
+    Path(bounty_id): Path<Uuid>,
+    AuthenticatedDid(submitter_did): AuthenticatedDid,
+    Json(req): Json<CreateSubmissionRequest>,
+) -> AppResult<Json<Submission>> {
+    let submission = create(&db, bounty_id, &submitter_did, req).await?;
+    Ok(Json(submission))
+}
+
+/// The creation logic behind `POST /bounties/{id}/submissions`, factored
+/// out so `grpc::AgentServiceImpl::submit_work` can record a submission
+/// without going through the HTTP layer.
+pub async fn create(db: &Db, bounty_id: Uuid, submitter_did: &str, req: CreateSubmissionRequest) -> AppResult<Submission> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(bounty_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.status != BountyStatus::Open {
+        return Err(AppError::BadRequest("bounty is not open for submissions".into()));
+    }
+
+    let submission = sqlx::query_as::<_, Submission>(
+        "INSERT INTO submissions (id, bounty_id, submitter_did, artifact_id, status, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, 'pending', now(), now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(bounty_id)
+    .bind(submitter_did)
+    .bind(req.artifact_id)
+    .fetch_one(db)
+    .await?;
+
+    if bounty.closure_type == ClosureType::Quorum {
+        reviewer_pool::assign_reviewers(db, &bounty, submission.id, submitter_did).await?;
+    }
+
+    if let Some(artifact_id) = submission.artifact_id {
+        if let Some(artifact) = sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE id = $1")
+            .bind(artifact_id)
+            .fetch_optional(db)
+            .await?
+        {
+            plagiarism::check_submission(db, submission.id, &artifact).await?;
+        }
+    }
+
+    crate::services::usage::record(db, submitter_did, crate::models::UsageMetric::SubmissionCreated).await?;
+
+    Ok(submission)
+}
+
+/// `POST /submissions/{id}/review` — records a reviewer's approve/reject
+/// vote on a quorum-closure submission and releases escrow automatically
+/// once enough votes have come in to satisfy the bounty's quorum config.
+#[utoipa::path(
+    post,
+    path = "/api/v1/submissions/{id}/review",
+    params(("id" = Uuid, Path, description = "Submission ID")),
+    request_body = SubmitReviewRequest,
+    responses((status = 200, description = "Recorded review", body = ReviewResponse)),
+    tag = "submissions"
+)]
+pub async fn review_submission(
+    State(db): State<Db>,
+    Path(submission_id): Path<Uuid>,
+    AuthenticatedDid(reviewer_did): AuthenticatedDid,
+    Json(req): Json<SubmitReviewRequest>,
+) -> AppResult<Json<ReviewResponse>> {
+    let submission = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1")
+        .bind(submission_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(submission.bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.closure_type != ClosureType::Quorum {
+        return Err(AppError::BadRequest("bounty is not quorum-reviewed".into()));
+    }
+    if reviewer_did == bounty.poster_did || reviewer_did == submission.submitter_did {
+        return Err(AppError::Forbidden("poster and submitter cannot review their own submission".into()));
+    }
+
+    let assigned: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM reviewer_assignments WHERE submission_id = $1 AND reviewer_did = $2)",
+    )
+    .bind(submission_id)
+    .bind(&reviewer_did)
+    .fetch_one(&db)
+    .await?;
+    if !assigned {
+        return Err(AppError::Forbidden("not assigned as a reviewer for this submission".into()));
+    }
+
+    let review = sqlx::query_as::<_, SubmissionReview>(
+        "INSERT INTO submission_reviews (id, submission_id, reviewer_did, vote, comment, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         ON CONFLICT (submission_id, reviewer_did) DO UPDATE SET vote = excluded.vote, comment = excluded.comment
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(submission_id)
+    .bind(&reviewer_did)
+    .bind(req.vote)
+    .bind(&req.comment)
+    .fetch_one(&db)
+    .await?;
+
+    let bounty_closed =
+        quorum::tally_and_maybe_release(&db, &bounty, submission_id, &submission.submitter_did).await?;
+
+    Ok(Json(ReviewResponse { review, bounty_closed }))
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct SelectWinnerRequest {
+    pub submission_id: Uuid,
+}
+
+/// `POST /api/v1/bounties/{id}/select-winner` — for requester-closure
+/// bounties with several competing submissions, lets the poster pick one:
+/// the rest are rejected and escrow releases only to the winner. Quorum
+/// closure never needs this since `services::quorum::tally_and_maybe_release`
+/// already picks a winner from votes.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bounties/{id}/select-winner",
+    params(("id" = Uuid, Path, description = "Bounty ID")),
+    request_body = SelectWinnerRequest,
+    responses((status = 200, description = "Winning submission", body = Submission)),
+    tag = "submissions"
+)]
+pub async fn select_winner(
+    State(db): State<Db>,
+    Path(bounty_id): Path<Uuid>,
+    AuthenticatedDid(poster_did): AuthenticatedDid,
+    Json(req): Json<SelectWinnerRequest>,
+) -> AppResult<Json<Submission>> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.poster_did != poster_did {
+        return Err(AppError::Forbidden("only the poster can select a winner".into()));
+    }
+    if bounty.closure_type != ClosureType::Requester {
+        return Err(AppError::BadRequest("select-winner only applies to requester-closure bounties".into()));
+    }
+    if bounty.status != BountyStatus::Open && bounty.status != BountyStatus::InReview {
+        return Err(AppError::BadRequest("bounty is not open for a winner to be selected".into()));
+    }
+
+    let winner = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1 AND bounty_id = $2")
+        .bind(req.submission_id)
+        .bind(bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if winner.status != SubmissionStatus::Pending && winner.status != SubmissionStatus::InReview {
+        return Err(AppError::BadRequest("submission is not eligible to win".into()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query("UPDATE submissions SET status = 'accepted', updated_at = now() WHERE id = $1")
+        .bind(winner.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "UPDATE submissions SET status = 'rejected', updated_at = now()
+         WHERE bounty_id = $1 AND id != $2 AND status IN ('pending', 'in_review')",
+    )
+    .bind(bounty_id)
+    .bind(winner.id)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("UPDATE bounties SET status = 'closed', updated_at = now() WHERE id = $1")
+        .bind(bounty_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    escrow::release_to_with_royalties(&db, bounty_id, &winner.submitter_did, bounty.reward, crate::models::EscrowPurpose::BountyReward, winner.artifact_id).await?;
+
+    audit::record(
+        &db,
+        &poster_did,
+        "select_winner",
+        "bounty",
+        bounty_id,
+        None,
+        Some(serde_json::json!({ "submission_id": winner.id, "submitter_did": winner.submitter_did })),
+    )
+    .await?;
+
+    let winner = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1")
+        .bind(winner.id)
+        .fetch_one(&db)
+        .await?;
+
+    Ok(Json(winner))
+}
+
+/// `POST /api/v1/submissions/{id}/withdraw` — lets a submitter retract a
+/// submission before any reviewer has voted on it, reverting the bounty to
+/// `open` and clearing the submission's artifact linkage so a withdrawn
+/// submission's artifact doesn't still show up as tied to the bounty.
+#[utoipa::path(
+    post,
+    path = "/api/v1/submissions/{id}/withdraw",
+    params(("id" = Uuid, Path, description = "Submission ID")),
+    responses((status = 200, description = "Withdrawn submission", body = Submission)),
+    tag = "submissions"
+)]
+pub async fn withdraw(
+    State(db): State<Db>,
+    Path(submission_id): Path<Uuid>,
+    AuthenticatedDid(submitter_did): AuthenticatedDid,
+) -> AppResult<Json<Submission>> {
+    let submission = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1")
+        .bind(submission_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if submission.submitter_did != submitter_did {
+        return Err(AppError::Forbidden("only the submitter can withdraw this submission".into()));
+    }
+    if submission.status != SubmissionStatus::Pending && submission.status != SubmissionStatus::InReview {
+        return Err(AppError::BadRequest("submission has already been reviewed or withdrawn".into()));
+    }
+
+    let reviewed: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM submission_reviews WHERE submission_id = $1)")
+        .bind(submission_id)
+        .fetch_one(&db)
+        .await?;
+    if reviewed {
+        return Err(AppError::BadRequest("submission already has reviews in progress".into()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let withdrawn = sqlx::query_as::<_, Submission>(
+        "UPDATE submissions SET status = 'withdrawn', artifact_id = NULL, updated_at = now()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(submission_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE bounties SET status = 'open', updated_at = now() WHERE id = $1 AND status = 'in_review'")
+        .bind(submission.bounty_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        &db,
+        &submitter_did,
+        "withdraw_submission",
+        "submission",
+        submission_id,
+        Some(serde_json::json!(&submission)),
+        Some(serde_json::json!(&withdrawn)),
+    )
+    .await?;
+
+    Ok(Json(withdrawn))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ExecutionReceiptRequest {
+    pub runner_did: String,
+    pub passed: bool,
+    #[serde(default)]
+    pub detail: serde_json::Value,
+    pub signature: String,
+}
+
+/// `POST /api/v1/submissions/{id}/execution-receipt` — a trusted runner's
+/// signed report of running a test-closure submission's test suite. Not
+/// `AuthenticatedDid`-gated: the signature over the request itself proves
+/// the caller controls `runner_did`, the same way identity binding and
+/// key-rotation requests are verified.
+#[utoipa::path(
+    post,
+    path = "/api/v1/submissions/{id}/execution-receipt",
+    params(("id" = Uuid, Path, description = "Submission ID")),
+    request_body = ExecutionReceiptRequest,
+    responses((status = 200, description = "Recorded execution receipt", body = ExecutionReceipt)),
+    tag = "submissions"
+)]
+pub async fn submit_execution_receipt(
+    State(db): State<Db>,
+    Path(submission_id): Path<Uuid>,
+    Json(req): Json<ExecutionReceiptRequest>,
+) -> AppResult<Json<ExecutionReceipt>> {
+    let submission = sqlx::query_as::<_, Submission>("SELECT * FROM submissions WHERE id = $1")
+        .bind(submission_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(submission.bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let receipt = execution_receipts::record(
+        &db,
+        &bounty,
+        &submission,
+        &req.runner_did,
+        req.passed,
+        req.detail,
+        &req.signature,
+    )
+    .await?;
+
+    Ok(Json(receipt))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListBountySubmissionsQuery {
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+    pub status: Option<SubmissionStatus>,
+}
+
+/// `GET /api/v1/bounties/{id}/submissions` — a bounty's submissions, newest
+/// first. Restricted to the poster and anyone assigned to review one of
+/// the bounty's submissions, since competing submissions shouldn't be
+/// visible to other workers.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bounties/{id}/submissions",
+    params(("id" = Uuid, Path, description = "Bounty ID"), ListBountySubmissionsQuery),
+    responses((status = 200, description = "Page of submissions, newest first", body = Vec<Submission>)),
+    tag = "submissions"
+)]
+pub async fn list_bounty_submissions(
+    State(db): State<Db>,
+    Path(bounty_id): Path<Uuid>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Query(q): Query<ListBountySubmissionsQuery>,
+) -> AppResult<Json<Vec<Submission>>> {
+    let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+        .bind(bounty_id)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if bounty.poster_did != did {
+        let is_reviewer: bool = sqlx::query_scalar(
+            "SELECT EXISTS(
+                 SELECT 1 FROM reviewer_assignments ra
+                 JOIN submissions s ON s.id = ra.submission_id
+                 WHERE s.bounty_id = $1 AND ra.reviewer_did = $2
+             )",
+        )
+        .bind(bounty_id)
+        .bind(&did)
+        .fetch_one(&db)
+        .await?;
+        if !is_reviewer {
+            return Err(AppError::Forbidden("only the poster or an assigned reviewer can list these submissions".into()));
+        }
+    }
+
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let submissions = sqlx::query_as::<_, Submission>(
+        "SELECT * FROM submissions
+         WHERE bounty_id = $1
+           AND ($2::uuid IS NULL OR (created_at, id) < (SELECT created_at, id FROM submissions WHERE id = $2))
+           AND ($3::text IS NULL OR status = $3)
+         ORDER BY created_at DESC, id DESC
+         LIMIT $4",
+    )
+    .bind(bounty_id)
+    .bind(q.cursor)
+    .bind(q.status)
+    .bind(limit)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(submissions))
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ListSubmissionsQuery {
+    pub submitter: String,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+    pub status: Option<SubmissionStatus>,
+}
+
+/// `GET /api/v1/submissions?submitter={did}` — a worker's own submissions
+/// across every bounty, newest first. Callers can only list their own
+/// submissions; `submitter` must match the caller's DID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/submissions",
+    params(ListSubmissionsQuery),
+    responses((status = 200, description = "Page of submissions, newest first", body = Vec<Submission>)),
+    tag = "submissions"
+)]
+pub async fn list_submissions(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+    Query(q): Query<ListSubmissionsQuery>,
+) -> AppResult<Json<Vec<Submission>>> {
+    if q.submitter != did {
+        return Err(AppError::Forbidden("can only list your own submissions".into()));
+    }
+
+    let limit = q.limit.unwrap_or(50).clamp(1, 500);
+    let submissions = sqlx::query_as::<_, Submission>(
+        "SELECT * FROM submissions
+         WHERE submitter_did = $1
+           AND ($2::uuid IS NULL OR (created_at, id) < (SELECT created_at, id FROM submissions WHERE id = $2))
+           AND ($3::text IS NULL OR status = $3)
+         ORDER BY created_at DESC, id DESC
+         LIMIT $4",
+    )
+    .bind(&q.submitter)
+    .bind(q.cursor)
+    .bind(q.status)
+    .bind(limit)
+    .fetch_all(&db)
+    .await?;
+
+    Ok(Json(submissions))
+}
+
+/// `GET /api/v1/submissions/{id}/similarity-report` — the plagiarism check
+/// `services::plagiarism::check_submission` ran against the rest of the
+/// registry when this submission was created, for quorum reviewers and the
+/// requester to weigh before voting or releasing escrow. `404` if the
+/// submission had no artifact attached at creation time.
+#[utoipa::path(
+    get,
+    path = "/api/v1/submissions/{id}/similarity-report",
+    params(("id" = Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 200, description = "Similarity report", body = SubmissionSimilarityReport),
+        (status = 404, description = "No similarity report for this submission"),
+    ),
+    tag = "submissions"
+)]
+pub async fn get_similarity_report(
+    State(db): State<Db>,
+    Path(submission_id): Path<Uuid>,
+) -> AppResult<Json<SubmissionSimilarityReport>> {
+    let report = plagiarism::report_for_submission(&db, submission_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(report))
+}
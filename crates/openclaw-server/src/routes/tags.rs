@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::Json;
+
+use crate::auth::AuthenticatedDid;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::TagSubscription;
+use crate::services::tags;
+
+/// `GET /api/v1/tags` — the managed bounty category taxonomy.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tags",
+    responses((status = 200, description = "The managed tag taxonomy", body = Vec<String>)),
+    tag = "tags"
+)]
+pub async fn list_taxonomy() -> Json<Vec<String>> {
+    Json(tags::TAXONOMY.iter().map(|t| t.to_string()).collect())
+}
+
+/// `GET /api/v1/tags/subscriptions` — the caller's tag subscriptions.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tags/subscriptions",
+    responses((status = 200, description = "The caller's tag subscriptions", body = Vec<TagSubscription>)),
+    tag = "tags"
+)]
+pub async fn list_subscriptions(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<Vec<TagSubscription>>> {
+    let subscriptions = tags::list_subscriptions(&db, &did).await?;
+    Ok(Json(subscriptions))
+}
+
+/// `POST /api/v1/tags/{tag}/subscribe` — subscribes the caller to new
+/// bounties tagged `tag`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tags/{tag}/subscribe",
+    params(("tag" = String, Path, description = "Tag to subscribe to")),
+    responses((status = 200, description = "Subscription created", body = TagSubscription)),
+    tag = "tags"
+)]
+pub async fn subscribe(
+    State(db): State<Db>,
+    Path(tag): Path<String>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<TagSubscription>> {
+    let subscription = tags::subscribe(&db, &did, &tag).await?;
+    Ok(Json(subscription))
+}
+
+/// `POST /api/v1/tags/{tag}/unsubscribe` — removes the caller's
+/// subscription to `tag`, if any. Returns the caller's remaining
+/// subscriptions.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tags/{tag}/unsubscribe",
+    params(("tag" = String, Path, description = "Tag to unsubscribe from")),
+    responses((status = 200, description = "Remaining tag subscriptions", body = Vec<TagSubscription>)),
+    tag = "tags"
+)]
+pub async fn unsubscribe(
+    State(db): State<Db>,
+    Path(tag): Path<String>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<Vec<TagSubscription>>> {
+    tags::unsubscribe(&db, &did, &tag).await?;
+    let subscriptions = tags::list_subscriptions(&db, &did).await?;
+    Ok(Json(subscriptions))
+}
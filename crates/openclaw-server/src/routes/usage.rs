@@ -0,0 +1,51 @@
+use axum::extract::State;
+use axum::Json;
+use serde::Serialize;
+
+use crate::auth::AuthenticatedDid;
+use crate::config;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::models::UsageMetric;
+use crate::services::usage;
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct UsageResponse {
+    pub did: String,
+    pub api_requests_24h: i64,
+    pub artifacts_registered_24h: i64,
+    pub submissions_created_24h: i64,
+    /// `None` means the deployment has no `MAX_API_REQUESTS_PER_DAY` quota
+    /// configured.
+    pub api_request_quota: Option<i64>,
+}
+
+/// `GET /api/v1/usage` — the caller's own rolling-24h usage across the
+/// metrics `services::usage` tracks, plus the request quota (if any) it's
+/// being measured against. There's no cross-DID view here; an operator
+/// wanting fleet-wide numbers reads `usage_events` directly or via the
+/// Prometheus `/metrics` endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v1/usage",
+    responses((status = 200, description = "Caller's rolling-24h usage", body = UsageResponse)),
+    tag = "usage"
+)]
+pub async fn get_usage(
+    State(db): State<Db>,
+    AuthenticatedDid(did): AuthenticatedDid,
+) -> AppResult<Json<UsageResponse>> {
+    let api_requests_24h = usage::rolling_24h_count(&db, &did, UsageMetric::ApiRequest).await?;
+    let artifacts_registered_24h =
+        usage::rolling_24h_count(&db, &did, UsageMetric::ArtifactRegistered).await?;
+    let submissions_created_24h =
+        usage::rolling_24h_count(&db, &did, UsageMetric::SubmissionCreated).await?;
+
+    Ok(Json(UsageResponse {
+        did,
+        api_requests_24h,
+        artifacts_registered_24h,
+        submissions_created_24h,
+        api_request_quota: config::current().max_api_requests_per_day,
+    }))
+}
@@ -0,0 +1,110 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use serde_json::{json, Value};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::identity;
+use crate::models::{DidBinding, DidKeyRotation};
+
+/// `GET /.well-known/did.json` — the server's own DID document, so anyone
+/// verifying a signed attestation or receipt can fetch the public key out
+/// of band instead of trusting whatever DID the payload claims.
+#[utoipa::path(
+    get,
+    path = "/.well-known/did.json",
+    responses((status = 200, description = "The server's DID document")),
+    tag = "identity"
+)]
+pub async fn did_document() -> Json<Value> {
+    let identity = identity::current();
+    // `active_keys` includes `identity` itself plus any key that's still
+    // inside its rotation overlap window, so a verifier checking an
+    // attestation signed moments before a rotation can still find its
+    // signer here instead of only the newest key.
+    let active_keys = identity::active_keys();
+    let verification_methods: Vec<Value> = active_keys
+        .iter()
+        .map(|key| {
+            json!({
+                "id": format!("{}#controller", key.did),
+                "type": "Ed25519VerificationKey2020",
+                "controller": key.did,
+                "publicKeyMultibase": key.did.strip_prefix("did:key:").unwrap_or(&key.did),
+                "keyId": key.key_id,
+                "retiredAt": key.retired_at,
+            })
+        })
+        .collect();
+
+    Json(json!({
+        "id": identity.did,
+        "verificationMethod": verification_methods,
+        "authentication": [format!("{}#controller", identity.did)],
+        "assertionMethod": [format!("{}#controller", identity.did)],
+    }))
+}
+
+/// `GET /.well-known/did/{did}` — a DID document for a `did:key` identity
+/// with an active `services::did_binding` binding, listing its rotation
+/// history as `alsoKnownAs` entries and a couple of service endpoints for
+/// discovering what it's signed. Since `did` is itself a `did:key`, the
+/// verification method is recoverable straight from the identifier (see
+/// `openclaw_crypto::did::verifying_key_from_did_key`) — this endpoint
+/// only resolves DIDs the server has recorded a binding for, it isn't a
+/// general-purpose `did:web` host.
+#[utoipa::path(
+    get,
+    path = "/.well-known/did/{did}",
+    params(("did" = String, Path, description = "did:key identifier")),
+    responses(
+        (status = 200, description = "DID document"),
+        (status = 404, description = "No active binding for this DID"),
+    ),
+    tag = "identity"
+)]
+pub async fn resolve_did(State(db): State<Db>, Path(did): Path<String>) -> AppResult<Json<Value>> {
+    let binding = sqlx::query_as::<_, DidBinding>("SELECT * FROM did_bindings WHERE did = $1 AND revoked_at IS NULL")
+        .bind(&did)
+        .fetch_optional(&db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let rotations: Vec<DidKeyRotation> =
+        sqlx::query_as("SELECT * FROM did_key_rotations WHERE old_did = $1 OR new_did = $1 ORDER BY rotated_at")
+            .bind(&did)
+            .fetch_all(&db)
+            .await?;
+    let mut also_known_as: Vec<String> = rotations
+        .iter()
+        .flat_map(|r| [r.old_did.clone(), r.new_did.clone()])
+        .filter(|d| d != &did)
+        .collect();
+    also_known_as.dedup();
+
+    Ok(Json(json!({
+        "id": did,
+        "verificationMethod": [{
+            "id": format!("{did}#controller"),
+            "type": "Ed25519VerificationKey2020",
+            "controller": did,
+            "publicKeyMultibase": did.strip_prefix("did:key:").unwrap_or(&did),
+        }],
+        "authentication": [format!("{did}#controller")],
+        "assertionMethod": [format!("{did}#controller")],
+        "alsoKnownAs": also_known_as,
+        "service": [
+            {
+                "id": format!("{did}#profile"),
+                "type": "ProtocolMProfile",
+                "serviceEndpoint": format!("/api/v1/profile/{did}"),
+            },
+            {
+                "id": format!("{did}#artifacts"),
+                "type": "ProtocolMArtifacts",
+                "serviceEndpoint": format!("/api/v1/artifacts?signer={did}"),
+            },
+        ],
+        "boundAt": binding.bound_at,
+    })))
+}
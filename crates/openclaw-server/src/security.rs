@@ -0,0 +1,69 @@
+//! CORS policy, standard security response headers, and request body size
+//! limits — none of which `create_router` shipped with before, so every
+//! deployment was relying on whatever a fronting proxy happened to add.
+
+use axum::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::set_header::SetResponseHeaderLayer;
+
+/// Default cap for routes that don't override it with their own
+/// `RequestBodyLimitLayer` (see `routes::mod::create_router`). Envelopes and
+/// manifests are allowed much larger; webhook receivers much smaller.
+pub const DEFAULT_BODY_LIMIT_BYTES: usize = 256 * 1024;
+pub const LARGE_BODY_LIMIT_BYTES: usize = 16 * 1024 * 1024;
+pub const WEBHOOK_BODY_LIMIT_BYTES: usize = 64 * 1024;
+
+/// CORS policy read from `CORS_ALLOWED_ORIGINS` (comma-separated), the same
+/// convention `admin::is_admin` uses for `ADMIN_DIDS`. Unset means no
+/// browser-based caller is allowed to read responses cross-origin; it does
+/// not affect server-to-server calls, which don't send an `Origin` header.
+pub fn cors_layer() -> CorsLayer {
+    let layer = CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::PUT, Method::HEAD])
+        .allow_headers(tower_http::cors::Any)
+        .allow_origin(allowed_origins());
+    layer
+}
+
+fn allowed_origins() -> AllowOrigin {
+    match std::env::var("CORS_ALLOWED_ORIGINS") {
+        Ok(list) if list.trim() == "*" => AllowOrigin::any(),
+        Ok(list) => {
+            let origins: Vec<HeaderValue> = list
+                .split(',')
+                .filter_map(|o| HeaderValue::from_str(o.trim()).ok())
+                .collect();
+            AllowOrigin::list(origins)
+        }
+        Err(_) => AllowOrigin::list(Vec::<HeaderValue>::new()),
+    }
+}
+
+/// Standard defensive headers every response should carry regardless of
+/// route: no MIME sniffing, no framing by other origins, and a
+/// conservative referrer policy. HSTS is left to the TLS-terminating proxy,
+/// which knows whether the connection is actually HTTPS.
+pub fn security_headers() -> Vec<SetResponseHeaderLayer<HeaderValue>> {
+    vec![
+        SetResponseHeaderLayer::if_not_present(
+            HeaderName::from_static("x-content-type-options"),
+            HeaderValue::from_static("nosniff"),
+        ),
+        SetResponseHeaderLayer::if_not_present(
+            HeaderName::from_static("x-frame-options"),
+            HeaderValue::from_static("DENY"),
+        ),
+        SetResponseHeaderLayer::if_not_present(
+            HeaderName::from_static("referrer-policy"),
+            HeaderValue::from_static("no-referrer"),
+        ),
+    ]
+}
+
+/// Request body size limit for a route group. Apply with
+/// `.layer(body_limit(...))` on the `Router` covering just that group, so a
+/// global default doesn't force every route to the same ceiling.
+pub fn body_limit(max_bytes: usize) -> RequestBodyLimitLayer {
+    RequestBodyLimitLayer::new(max_bytes)
+}
@@ -0,0 +1,329 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ApprovalDecision, ApprovalRequest, ApprovalStatus, ApprovalTier, ApprovalVote, Bounty,
+    BountyStatus,
+};
+use crate::services::{audit, escrow, notifications};
+
+const DEFAULT_EXPIRY_HOURS: i64 = 48;
+
+#[derive(Serialize)]
+struct ApprovalDecisionPayload<'a> {
+    action: &'a str,
+    approval_request_id: Uuid,
+    vote: ApprovalVote,
+    expires_at: DateTime<Utc>,
+}
+
+/// The tightest tier governing a spend of `amount` by `did`: the
+/// highest-`threshold` tier at or below `amount`, or `None` when no tier
+/// applies. `bounties::create` treats `None` as needing no approval, so
+/// posters without an approvals setup keep working the way they always
+/// did; `routes::approvals` rejects the request instead.
+pub async fn tier_for_amount(db: &PgPool, did: &str, amount: Decimal) -> AppResult<Option<ApprovalTier>> {
+    let tier = sqlx::query_as::<_, ApprovalTier>(
+        "SELECT * FROM approval_tiers WHERE did = $1 AND threshold <= $2 ORDER BY threshold DESC LIMIT 1",
+    )
+    .bind(did)
+    .bind(amount)
+    .fetch_optional(db)
+    .await?;
+    Ok(tier)
+}
+
+/// Creates a pending approval request for `approvers` to decide on, `quorum`
+/// of whom must approve before `action_type` is carried out.
+pub async fn request_approval(
+    db: &PgPool,
+    requester_did: &str,
+    approvers: Vec<String>,
+    quorum: i32,
+    action_type: &str,
+    action_payload: Value,
+) -> AppResult<ApprovalRequest> {
+    let request = sqlx::query_as::<_, ApprovalRequest>(
+        "INSERT INTO approval_requests (id, requester_did, approvers, quorum, action_type, action_payload, status, created_at, expires_at)
+         VALUES ($1, $2, $3, $4, $5, $6, 'pending', now(), $7)
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(requester_did)
+    .bind(&approvers)
+    .bind(quorum)
+    .bind(action_type)
+    .bind(action_payload)
+    .bind(chrono::Utc::now() + Duration::hours(DEFAULT_EXPIRY_HOURS))
+    .fetch_one(db)
+    .await?;
+
+    audit::record(
+        db,
+        requester_did,
+        "request_approval",
+        "approval_request",
+        request.id,
+        None,
+        Some(serde_json::json!(&request)),
+    )
+    .await?;
+
+    Ok(request)
+}
+
+/// Records `approver_did`'s vote on `request_id`. `signature` must be over
+/// `{action: "approval_decision", approval_request_id, vote, expires_at}`,
+/// signed by `approver_did` — the caller's identity is proven by the
+/// signature itself, not trusted from the request body, and `expires_at`
+/// bounds how long a signed decision stays replayable. Once enough approve
+/// votes are in to satisfy the request's quorum, carries out the underlying
+/// action via `apply`; once enough reject votes are in that quorum can
+/// never be reached, rejects the request without waiting on the rest.
+pub async fn decide(
+    db: &PgPool,
+    request_id: Uuid,
+    approver_did: &str,
+    vote: ApprovalVote,
+    expires_at: DateTime<Utc>,
+    signature: &str,
+) -> AppResult<ApprovalRequest> {
+    if expires_at <= Utc::now() {
+        return Err(AppError::BadRequest("approval decision signature has expired".into()));
+    }
+
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(approver_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid approver did: {e}")))?;
+    let payload = ApprovalDecisionPayload { action: "approval_decision", approval_request_id: request_id, vote, expires_at };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("approval decision signature invalid: {e}")))?;
+
+    let request = sqlx::query_as::<_, ApprovalRequest>("SELECT * FROM approval_requests WHERE id = $1")
+        .bind(request_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if request.status != ApprovalStatus::Pending {
+        return Err(AppError::BadRequest("approval request is already resolved".into()));
+    }
+    if !request.approvers.iter().any(|a| a == approver_did) {
+        return Err(AppError::Forbidden("caller is not a designated approver for this request".into()));
+    }
+
+    sqlx::query_as::<_, ApprovalDecision>(
+        "INSERT INTO approval_decisions (id, approval_request_id, approver_did, vote, created_at)
+         VALUES ($1, $2, $3, $4, now())
+         ON CONFLICT (approval_request_id, approver_did) DO UPDATE SET vote = excluded.vote
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(request_id)
+    .bind(approver_did)
+    .bind(vote)
+    .fetch_one(db)
+    .await?;
+
+    let approve_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM approval_decisions WHERE approval_request_id = $1 AND vote = 'approve'",
+    )
+    .bind(request_id)
+    .fetch_one(db)
+    .await?;
+    let reject_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM approval_decisions WHERE approval_request_id = $1 AND vote = 'reject'",
+    )
+    .bind(request_id)
+    .fetch_one(db)
+    .await?;
+
+    let quorum = request.quorum as i64;
+    let approvers_left = request.approvers.len() as i64 - reject_count;
+
+    if approve_count >= quorum {
+        return finalize(db, request, ApprovalStatus::Approved).await;
+    }
+    if approvers_left < quorum {
+        return finalize(db, request, ApprovalStatus::Rejected).await;
+    }
+
+    Ok(request)
+}
+
+/// Lists approval requests soonest-expiring first, so an operator's inbox
+/// surfaces what needs attention before it lapses. `operator`, if set,
+/// restricts to requests where the DID is one of the (possibly several)
+/// `approvers` rather than the sole `requester_did`.
+pub async fn list(
+    db: &PgPool,
+    operator: Option<&str>,
+    status: Option<ApprovalStatus>,
+    cursor: Option<Uuid>,
+    limit: Option<i64>,
+) -> AppResult<Vec<ApprovalRequest>> {
+    let limit = limit.unwrap_or(50).clamp(1, 500);
+
+    let requests = sqlx::query_as::<_, ApprovalRequest>(
+        "SELECT * FROM approval_requests
+         WHERE ($1::text IS NULL OR $1 = ANY(approvers))
+           AND ($2::text IS NULL OR status = $2)
+           AND ($3::uuid IS NULL OR (expires_at, id) > (SELECT expires_at, id FROM approval_requests WHERE id = $3))
+         ORDER BY expires_at ASC, id ASC
+         LIMIT $4",
+    )
+    .bind(operator)
+    .bind(status)
+    .bind(cursor)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    Ok(requests)
+}
+
+/// Expires pending approval requests whose `expires_at` has passed without
+/// reaching quorum, and cleans up whatever they were blocking (see
+/// `cancel_pending_action`). Returns how many were expired, for
+/// `jobs::approval_expiry` to log.
+pub async fn expire_stale(db: &PgPool) -> AppResult<usize> {
+    let expired = sqlx::query_as::<_, ApprovalRequest>(
+        "UPDATE approval_requests SET status = 'expired'
+         WHERE status = 'pending' AND expires_at < now()
+         RETURNING *",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for request in &expired {
+        cancel_pending_action(db, request, "approval request expired before quorum was reached").await?;
+        audit::record(
+            db,
+            &request.requester_did,
+            "expire_approval_request",
+            "approval_request",
+            request.id,
+            None,
+            Some(serde_json::json!(request)),
+        )
+        .await?;
+    }
+
+    Ok(expired.len())
+}
+
+async fn finalize(db: &PgPool, before: ApprovalRequest, status: ApprovalStatus) -> AppResult<ApprovalRequest> {
+    // Guarded the same way `expire_stale`'s UPDATE is: without `AND status
+    // = 'pending'`, a decision racing the expiry sweep could overwrite an
+    // already-`expired` (and already cleaned-up-via-`cancel_pending_action`)
+    // row back to `approved`/`rejected`, leaving `approval_requests` and the
+    // audit log lying about what actually happened.
+    let resolved = sqlx::query_as::<_, ApprovalRequest>(
+        "UPDATE approval_requests SET status = $2 WHERE id = $1 AND status = 'pending' RETURNING *",
+    )
+    .bind(before.id)
+    .bind(status)
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| AppError::BadRequest("approval request is already resolved".into()))?;
+
+    if status == ApprovalStatus::Approved {
+        apply(db, &resolved).await?;
+    } else if status == ApprovalStatus::Rejected {
+        cancel_pending_action(db, &resolved, "rejected by a quorum of approvers").await?;
+    }
+
+    audit::record(
+        db,
+        &resolved.requester_did,
+        "resolve_approval_request",
+        "approval_request",
+        resolved.id,
+        Some(serde_json::json!(&before)),
+        Some(serde_json::json!(&resolved)),
+    )
+    .await?;
+
+    Ok(resolved)
+}
+
+/// Carries out the action an approved request was filed for. Only
+/// `create_bounty` exists today: moves the bounty that was provisionally
+/// created in `pending_approval` into `open` and holds its escrow.
+async fn apply(db: &PgPool, request: &ApprovalRequest) -> AppResult<()> {
+    match request.action_type.as_str() {
+        "create_bounty" => {
+            let bounty_id: Uuid = request
+                .action_payload
+                .get("bounty_id")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| AppError::Internal(anyhow::anyhow!("approval request is missing bounty_id")))?;
+
+            let bounty = sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE id = $1")
+                .bind(bounty_id)
+                .fetch_optional(db)
+                .await?
+                .ok_or(AppError::NotFound)?;
+            if bounty.status != BountyStatus::PendingApproval {
+                return Ok(());
+            }
+
+            sqlx::query("UPDATE bounties SET status = 'open', updated_at = now() WHERE id = $1")
+                .bind(bounty_id)
+                .execute(db)
+                .await?;
+            escrow::hold_escrow(db, bounty_id, &bounty.poster_did, bounty.reward, None, crate::models::EscrowPurpose::BountyReward).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Cleans up after a request that didn't make it to `Approved` — called
+/// from `finalize` on an outright rejection, and from
+/// `jobs::approval_expiry` once `expires_at` passes with quorum never
+/// reached. Only `create_bounty` exists today: a `pending_approval` bounty
+/// never had escrow held (see `bounties::create`), so there's nothing to
+/// refund — it just needs to leave `pending_approval` instead of sitting
+/// there forever, with `reason` recorded for the poster to see.
+pub(crate) async fn cancel_pending_action(db: &PgPool, request: &ApprovalRequest, reason: &str) -> AppResult<()> {
+    match request.action_type.as_str() {
+        "create_bounty" => {
+            let Some(bounty_id) = request
+                .action_payload
+                .get("bounty_id")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<Uuid>().ok())
+            else {
+                return Ok(());
+            };
+
+            let bounty = sqlx::query_as::<_, Bounty>(
+                "UPDATE bounties SET status = 'cancelled', cancellation_reason = $2, updated_at = now()
+                 WHERE id = $1 AND status = 'pending_approval'
+                 RETURNING *",
+            )
+            .bind(bounty_id)
+            .bind(reason)
+            .fetch_optional(db)
+            .await?;
+
+            if let Some(bounty) = bounty {
+                notifications::notify(
+                    db,
+                    &bounty.poster_did,
+                    "bounty.approval_cancelled",
+                    serde_json::json!({ "bounty_id": bounty.id, "reason": reason }),
+                )
+                .await;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
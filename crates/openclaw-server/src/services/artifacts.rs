@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use openclaw_crypto::fuzzy_hash;
+use openclaw_crypto::types::SignatureEnvelopeV1;
+use openclaw_crypto::verifying_key_from_did_key;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::AppError;
+use crate::models::{
+    Artifact, ArtifactContextRow, ArtifactDerivation, ArtifactLineage, DerivationStatus, LineageNode, SimilarArtifact,
+};
+use crate::AppResult;
+
+/// Below this, two fingerprints are treated as unrelated rather than a
+/// near-duplicate worth flagging — simhashes of genuinely different content
+/// still agree on roughly half their bits by chance.
+pub(crate) const SIMILARITY_THRESHOLD: f64 = 0.85;
+
+/// How many hops out to walk when computing ancestors/descendants. Deep
+/// lineages still exist beyond this; callers that need the full graph
+/// should paginate by re-requesting from the deepest returned node.
+const MAX_LINEAGE_DEPTH: i32 = 10;
+
+/// Verifies `envelope`'s signature and registers it, deduplicating by
+/// (content hash, signer) so the same signer submitting the same bytes
+/// twice (e.g. once via a bounty's auto-approval and once through this
+/// endpoint directly) resolves to one row. Two different signers attesting
+/// to the same bytes are kept as separate artifacts.
+pub async fn register(db: &Db, envelope: SignatureEnvelopeV1, derived_from: Vec<String>) -> AppResult<Artifact> {
+    let verifying_key = verifying_key_from_did_key(&envelope.did)
+        .map_err(|e| AppError::BadRequest(format!("invalid signer DID: {e}")))?;
+    openclaw_crypto::verify::verify_envelope_signature(&verifying_key, envelope.clone())
+        .map_err(|e| AppError::BadRequest(format!("signature invalid: {e}")))?;
+
+    if let Some(existing) =
+        sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE sha256 = $1 AND signer_did = $2")
+            .bind(&envelope.hash.value)
+            .bind(&envelope.did)
+            .fetch_optional(db)
+            .await?
+    {
+        return Ok(existing);
+    }
+
+    let envelope_json = serde_json::to_value(&envelope).map_err(|e| AppError::Internal(e.into()))?;
+    let artifact = sqlx::query_as::<_, Artifact>(
+        "INSERT INTO artifacts (id, sha256, signer_did, signature_envelope, derived_from, content_stored, ipfs_cid, registered_at)
+         VALUES ($1, $2, $3, $4, $5, false, NULL, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&envelope.hash.value)
+    .bind(&envelope.did)
+    .bind(envelope_json)
+    .bind(&derived_from)
+    .fetch_one(db)
+    .await?;
+
+    for parent_hash in &derived_from {
+        let parent: Option<Uuid> = sqlx::query_scalar("SELECT id FROM artifacts WHERE sha256 = $1 LIMIT 1")
+            .bind(parent_hash)
+            .fetch_optional(db)
+            .await?;
+        if let Some(parent_id) = parent {
+            sqlx::query(
+                "INSERT INTO artifact_derivations (parent_artifact_id, child_artifact_id, status, created_at)
+                 VALUES ($1, $2, 'pending', now())
+                 ON CONFLICT DO NOTHING",
+            )
+            .bind(parent_id)
+            .bind(artifact.id)
+            .execute(db)
+            .await?;
+        }
+    }
+
+    Ok(artifact)
+}
+
+/// Walks `artifact_derivations` outward from `artifact_id` in both
+/// directions up to `MAX_LINEAGE_DEPTH` hops using a single recursive CTE
+/// per direction. The CTE carries the path walked so far and stops
+/// expanding any branch that would revisit a node already on its own
+/// path, so a derivation cycle is cut cleanly and reported back via
+/// `*_cycle_detected` instead of silently disappearing into the depth
+/// limit like the node-by-node BFS this replaced.
+pub async fn lineage(db: &Db, artifact_id: Uuid) -> AppResult<ArtifactLineage> {
+    let (ancestors, ancestors_cycle_detected) = walk(db, artifact_id, Direction::Ancestors).await?;
+    let (descendants, descendants_cycle_detected) = walk(db, artifact_id, Direction::Descendants).await?;
+    Ok(ArtifactLineage {
+        ancestors,
+        ancestors_cycle_detected,
+        descendants,
+        descendants_cycle_detected,
+    })
+}
+
+enum Direction {
+    Ancestors,
+    Descendants,
+}
+
+async fn walk(db: &Db, start: Uuid, direction: Direction) -> AppResult<(Vec<LineageNode>, bool)> {
+    let (from_col, to_col) = match direction {
+        Direction::Ancestors => ("child_artifact_id", "parent_artifact_id"),
+        Direction::Descendants => ("parent_artifact_id", "child_artifact_id"),
+    };
+
+    // Only `acknowledged`/`waived` edges count as verified lineage — a
+    // `pending` claim is just an unverified assertion by the child's
+    // signer until the parent's signer resolves it.
+    let query = format!(
+        "WITH RECURSIVE chain AS (
+             SELECT {to} AS node_id, ARRAY[$1::uuid, {to}] AS path, 1 AS depth,
+                    ({to} = $1::uuid) AS is_cycle
+             FROM artifact_derivations
+             WHERE {from} = $1 AND status != 'pending'
+             UNION ALL
+             SELECT d.{to}, c.path || d.{to}, c.depth + 1,
+                    (d.{to} = ANY(c.path))
+             FROM artifact_derivations d
+             JOIN chain c ON d.{from} = c.node_id
+             WHERE c.depth < $2 AND NOT c.is_cycle AND d.status != 'pending'
+         )
+         SELECT DISTINCT ON (node_id) node_id, depth, is_cycle
+         FROM chain
+         ORDER BY node_id, depth ASC",
+        from = from_col,
+        to = to_col,
+    );
+
+    let rows: Vec<(Uuid, i32, bool)> = sqlx::query_as(&query)
+        .bind(start)
+        .bind(MAX_LINEAGE_DEPTH)
+        .fetch_all(db)
+        .await?;
+
+    let cycle_detected = rows.iter().any(|(_, _, is_cycle)| *is_cycle);
+    let depth_by_id: HashMap<Uuid, i32> = rows.iter().map(|(id, depth, _)| (*id, *depth)).collect();
+    let node_ids: Vec<Uuid> = rows.iter().map(|(id, ..)| *id).collect();
+
+    if node_ids.is_empty() {
+        return Ok((Vec::new(), cycle_detected));
+    }
+
+    let context_rows: Vec<ArtifactContextRow> = sqlx::query_as(
+        "SELECT a.id AS artifact_id, a.sha256, a.signer_did, s.bounty_id, a.revoked_at
+         FROM artifacts a
+         LEFT JOIN submissions s ON s.artifact_id = a.id
+         WHERE a.id = ANY($1)",
+    )
+    .bind(&node_ids)
+    .fetch_all(db)
+    .await?;
+
+    let nodes = context_rows
+        .into_iter()
+        .map(|row| LineageNode {
+            depth: depth_by_id.get(&row.artifact_id).copied().unwrap_or_default(),
+            artifact_id: row.artifact_id,
+            sha256: row.sha256,
+            signer_did: row.signer_did,
+            bounty_id: row.bounty_id,
+            revoked: row.revoked_at.is_some(),
+        })
+        .collect();
+
+    Ok((nodes, cycle_detected))
+}
+
+/// Confirms the bytes uploaded for `artifact_id` actually hash to its
+/// declared sha256, marks it as content-stored, and opportunistically pins
+/// the content to IPFS if pinning is configured. No-op (and errors) if the
+/// artifact isn't found or nothing was uploaded for it yet.
+pub async fn confirm_content_uploaded(db: &Db, artifact_id: Uuid) -> AppResult<Artifact> {
+    let artifact = sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE id = $1")
+        .bind(artifact_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = crate::services::storage::fetch_uploaded_content(&artifact.sha256).await?;
+    if !crate::services::storage::hash_matches(&content, &artifact.sha256) {
+        return Err(AppError::BadRequest("uploaded content does not match artifact hash".into()));
+    }
+
+    let fuzzy = fuzzy_hash::simhash(&content);
+    let cid = crate::services::ipfs::pin(content, &artifact.sha256).await?;
+
+    let updated = sqlx::query_as::<_, Artifact>(
+        "UPDATE artifacts SET content_stored = true, ipfs_cid = COALESCE($2, ipfs_cid), fuzzy_hash = $3 WHERE id = $1 RETURNING *",
+    )
+    .bind(artifact_id)
+    .bind(cid)
+    .bind(fuzzy)
+    .fetch_one(db)
+    .await?;
+    Ok(updated)
+}
+
+/// Artifacts (other than `artifact_id` itself) whose fuzzy hash agrees by
+/// at least `SIMILARITY_THRESHOLD`, most similar first. A naive full scan against
+/// every fingerprinted artifact — fine at this table's current scale, and
+/// avoids standing up a vector/LSH index for what's still a small registry.
+/// Returns an empty list if `artifact_id` has no content confirmed yet.
+pub async fn find_similar(db: &Db, artifact_id: Uuid) -> AppResult<Vec<SimilarArtifact>> {
+    let Some(target_hash): Option<String> =
+        sqlx::query_scalar("SELECT fuzzy_hash FROM artifacts WHERE id = $1")
+            .bind(artifact_id)
+            .fetch_optional(db)
+            .await?
+            .flatten()
+    else {
+        return Ok(Vec::new());
+    };
+
+    let candidates = sqlx::query_as::<_, Artifact>(
+        "SELECT * FROM artifacts WHERE id != $1 AND fuzzy_hash IS NOT NULL ORDER BY registered_at DESC",
+    )
+    .bind(artifact_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut similar: Vec<SimilarArtifact> = candidates
+        .into_iter()
+        .filter_map(|artifact| {
+            let candidate_hash = artifact.fuzzy_hash.as_deref()?;
+            let score = fuzzy_hash::similarity(&target_hash, candidate_hash)?;
+            (score >= SIMILARITY_THRESHOLD).then_some(SimilarArtifact { artifact, similarity: score })
+        })
+        .collect();
+    similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    Ok(similar)
+}
+
+#[derive(serde::Serialize)]
+struct RevocationPayload<'a> {
+    action: &'a str,
+    artifact_id: Uuid,
+    reason: &'a Option<String>,
+}
+
+/// Marks `artifact_id` revoked after verifying `signature` is over
+/// `{action: "revoke_artifact", artifact_id, reason}`, signed by the
+/// artifact's own `signer_did` — revocation isn't transferable even to a
+/// rotated successor key, since `openclaw_crypto::verify` checks against
+/// the DID on the artifact itself. A no-op (returns the unchanged row) if
+/// it's already revoked.
+pub async fn revoke(db: &Db, artifact_id: Uuid, reason: Option<String>, signature: &str) -> AppResult<Artifact> {
+    let artifact = sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE id = $1")
+        .bind(artifact_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    if artifact.revoked_at.is_some() {
+        return Ok(artifact);
+    }
+
+    let verifying_key = verifying_key_from_did_key(&artifact.signer_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid signer DID: {e}")))?;
+    let payload = RevocationPayload { action: "revoke_artifact", artifact_id, reason: &reason };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("revocation signature invalid: {e}")))?;
+
+    let revoked = sqlx::query_as::<_, Artifact>(
+        "UPDATE artifacts SET revoked_at = now(), revocation_reason = $2 WHERE id = $1 RETURNING *",
+    )
+    .bind(artifact_id)
+    .bind(&reason)
+    .fetch_one(db)
+    .await?;
+
+    Ok(revoked)
+}
+
+/// The registration timestamp of the oldest artifact registered under
+/// `sha256`, if any, without fetching the full row(s) — lets a client check
+/// "has someone already registered this?" before uploading a large envelope
+/// or the content itself.
+pub async fn earliest_registration(db: &Db, sha256: &str) -> AppResult<Option<DateTime<Utc>>> {
+    let registered_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT MIN(registered_at) FROM artifacts WHERE sha256 = $1",
+    )
+    .bind(sha256)
+    .fetch_one(db)
+    .await?;
+    Ok(registered_at)
+}
+
+/// The parent artifact's signer countersigns (`Acknowledged`) or declines
+/// to claim (`Waived`) a pending derivation made against it by
+/// `child_artifact_id`, moving the edge out of `pending` so it starts
+/// counting as verified lineage. Errors if `caller_did` isn't the parent
+/// artifact's signer, or no pending claim exists between the two.
+pub async fn resolve_derivation_claim(
+    db: &Db,
+    parent_artifact_id: Uuid,
+    child_artifact_id: Uuid,
+    caller_did: &str,
+    status: DerivationStatus,
+) -> AppResult<ArtifactDerivation> {
+    if status == DerivationStatus::Pending {
+        return Err(AppError::BadRequest("status must be acknowledged or waived".into()));
+    }
+
+    let parent = sqlx::query_as::<_, Artifact>("SELECT * FROM artifacts WHERE id = $1")
+        .bind(parent_artifact_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    if parent.signer_did != caller_did {
+        return Err(AppError::Forbidden(
+            "only the parent artifact's signer can resolve a derivation claim".into(),
+        ));
+    }
+
+    sqlx::query_as::<_, ArtifactDerivation>(
+        "UPDATE artifact_derivations SET status = $3
+         WHERE parent_artifact_id = $1 AND child_artifact_id = $2 AND status = 'pending'
+         RETURNING *",
+    )
+    .bind(parent_artifact_id)
+    .bind(child_artifact_id)
+    .bind(status)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)
+}
+
+/// All artifacts registered under `sha256`, newest first.
+pub async fn find_by_hash(db: &Db, sha256: &str) -> AppResult<Vec<Artifact>> {
+    let artifacts = sqlx::query_as::<_, Artifact>(
+        "SELECT * FROM artifacts WHERE sha256 = $1 ORDER BY registered_at DESC",
+    )
+    .bind(sha256)
+    .fetch_all(db)
+    .await?;
+    Ok(artifacts)
+}
+
@@ -0,0 +1,113 @@
+use openclaw_crypto::verifying_key_from_did_key;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::identity;
+use crate::models::{ReserveAttestation, ReserveAttestationSnapshot, UnsignedReserveAttestation};
+use crate::AppResult;
+
+/// Computes and signs a fresh reserve attestation from current balances.
+/// Nothing is persisted here; `snapshot` is what stores attestations in a
+/// table.
+pub async fn sign_attestation(db: &Db) -> AppResult<ReserveAttestation> {
+    let total_credits_liability: Decimal =
+        sqlx::query_scalar("SELECT COALESCE(SUM(balance), 0) FROM m_credits_accounts")
+            .fetch_one(db)
+            .await?;
+    let total_usd_reserves: Decimal = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(amount_usd), 0) FROM invoices WHERE status = 'completed'",
+    )
+    .fetch_one(db)
+    .await?;
+
+    let key = identity::current();
+    let unsigned = UnsignedReserveAttestation {
+        did: key.did.clone(),
+        key_id: key.key_id.clone(),
+        total_credits_liability,
+        total_usd_reserves,
+        attested_at: chrono::Utc::now(),
+    };
+    let signature = openclaw_crypto::sign::sign_canonical(&key.signing_key, &unsigned)?;
+
+    Ok(ReserveAttestation {
+        did: unsigned.did,
+        key_id: unsigned.key_id,
+        total_credits_liability: unsigned.total_credits_liability,
+        total_usd_reserves: unsigned.total_usd_reserves,
+        attested_at: unsigned.attested_at,
+        signature,
+    })
+}
+
+/// Verifies a reserve attestation's signature against the DID it claims to
+/// be signed by. Does not re-check the figures against current balances —
+/// it only proves the attestation wasn't forged or altered in transit.
+/// Does not require `key_id` to resolve via `identity::find` — the DID
+/// itself carries the public key — but a caller that wants to confirm the
+/// claimed key generation actually existed can cross-check it there.
+pub fn verify_attestation(attestation: &ReserveAttestation) -> AppResult<()> {
+    let verifying_key = verifying_key_from_did_key(&attestation.did)
+        .map_err(|e| crate::AppError::BadRequest(format!("invalid attestation DID: {e}")))?;
+    let unsigned = UnsignedReserveAttestation {
+        did: attestation.did.clone(),
+        key_id: attestation.key_id.clone(),
+        total_credits_liability: attestation.total_credits_liability,
+        total_usd_reserves: attestation.total_usd_reserves,
+        attested_at: attestation.attested_at,
+    };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &unsigned, &attestation.signature)
+        .map_err(|e| crate::AppError::BadRequest(format!("attestation signature invalid: {e}")))
+}
+
+/// Signs a fresh attestation and persists it chained to the previous
+/// snapshot's hash, so the `reserve_attestations` table forms an append-only
+/// audit trail: deleting or editing any row breaks the chain for every row
+/// after it.
+pub async fn snapshot(db: &Db) -> AppResult<ReserveAttestationSnapshot> {
+    let attestation = sign_attestation(db).await?;
+
+    let prev_hash: Option<String> =
+        sqlx::query_scalar("SELECT hash FROM reserve_attestations ORDER BY attested_at DESC LIMIT 1")
+            .fetch_optional(db)
+            .await?;
+
+    let hash = openclaw_crypto::hash::sha256_hex(
+        format!(
+            "{}|{}",
+            prev_hash.as_deref().unwrap_or(""),
+            serde_json::to_string(&attestation).map_err(|e| crate::AppError::Internal(e.into()))?
+        )
+        .as_bytes(),
+    );
+
+    let row = sqlx::query_as::<_, ReserveAttestationSnapshot>(
+        "INSERT INTO reserve_attestations
+            (id, did, key_id, total_credits_liability, total_usd_reserves, signature, prev_hash, hash, attested_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&attestation.did)
+    .bind(&attestation.key_id)
+    .bind(attestation.total_credits_liability)
+    .bind(attestation.total_usd_reserves)
+    .bind(&attestation.signature)
+    .bind(&prev_hash)
+    .bind(&hash)
+    .bind(attestation.attested_at)
+    .fetch_one(db)
+    .await?;
+
+    Ok(row)
+}
+
+pub async fn list_history(db: &Db) -> AppResult<Vec<ReserveAttestationSnapshot>> {
+    let rows = sqlx::query_as::<_, ReserveAttestationSnapshot>(
+        "SELECT * FROM reserve_attestations ORDER BY attested_at ASC",
+    )
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
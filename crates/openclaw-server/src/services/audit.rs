@@ -0,0 +1,57 @@
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::AuditLogEntry;
+
+/// Appends one entry to the audit log. Called by routes and services after
+/// a mutation commits, never before — a logged action that then fails to
+/// commit would be a false record, which is worse than a missed one.
+pub async fn record(
+    db: &PgPool,
+    actor_did: &str,
+    action: &str,
+    entity_type: &str,
+    entity_id: Uuid,
+    before: Option<Value>,
+    after: Option<Value>,
+) -> AppResult<()> {
+    sqlx::query(
+        "INSERT INTO audit_log (id, actor_did, action, entity_type, entity_id, before, after, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(actor_did)
+    .bind(action)
+    .bind(entity_type)
+    .bind(entity_id)
+    .bind(before)
+    .bind(after)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Lists audit entries, newest first, optionally filtered by entity type
+/// and/or actor. Used by the admin-facing listing endpoint.
+pub async fn list(
+    db: &PgPool,
+    entity_type: Option<&str>,
+    actor_did: Option<&str>,
+    limit: i64,
+) -> AppResult<Vec<AuditLogEntry>> {
+    let entries = sqlx::query_as::<_, AuditLogEntry>(
+        "SELECT * FROM audit_log
+         WHERE ($1::text IS NULL OR entity_type = $1)
+           AND ($2::text IS NULL OR actor_did = $2)
+         ORDER BY created_at DESC
+         LIMIT $3",
+    )
+    .bind(entity_type)
+    .bind(actor_did)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+    Ok(entries)
+}
@@ -0,0 +1,57 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{BountyTemplate, ClosureType};
+
+pub async fn create(
+    db: &PgPool,
+    poster_did: &str,
+    name: &str,
+    title: &str,
+    description: &str,
+    closure_type: ClosureType,
+    default_metadata: serde_json::Value,
+    default_tags: Vec<String>,
+) -> AppResult<BountyTemplate> {
+    crate::services::tags::validate(&default_tags)?;
+
+    let template = sqlx::query_as::<_, BountyTemplate>(
+        "INSERT INTO bounty_templates (id, poster_did, name, title, description, closure_type, default_metadata, default_tags, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(poster_did)
+    .bind(name)
+    .bind(title)
+    .bind(description)
+    .bind(closure_type)
+    .bind(default_metadata)
+    .bind(&default_tags)
+    .fetch_one(db)
+    .await?;
+
+    Ok(template)
+}
+
+pub async fn list(db: &PgPool, poster_did: &str) -> AppResult<Vec<BountyTemplate>> {
+    let templates = sqlx::query_as::<_, BountyTemplate>(
+        "SELECT * FROM bounty_templates WHERE poster_did = $1 ORDER BY created_at DESC",
+    )
+    .bind(poster_did)
+    .fetch_all(db)
+    .await?;
+    Ok(templates)
+}
+
+/// Fetches a template, scoped to `poster_did` so one poster can't instantiate
+/// or inspect another's saved scaffolds.
+pub async fn get_owned(db: &PgPool, id: Uuid, poster_did: &str) -> AppResult<BountyTemplate> {
+    sqlx::query_as::<_, BountyTemplate>("SELECT * FROM bounty_templates WHERE id = $1 AND poster_did = $2")
+        .bind(id)
+        .bind(poster_did)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)
+}
@@ -0,0 +1,15 @@
+use serde_json::json;
+
+/// Posts an approval request or bounty event to a Slack or Discord incoming
+/// webhook. Both accept the same `{"text": ...}` shape closely enough that
+/// one function covers both, keeping the link to the resource actionable.
+pub async fn post(webhook_url: &str, text: &str, link: &str) -> anyhow::Result<()> {
+    let body = json!({ "text": format!("{text}\n{link}") });
+    reqwest::Client::new()
+        .post(webhook_url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
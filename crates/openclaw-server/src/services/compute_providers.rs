@@ -0,0 +1,68 @@
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::ComputeProvider;
+
+pub async fn create(db: &PgPool, name: &str, conversion_rate: Decimal, credential_ref: &str) -> AppResult<ComputeProvider> {
+    let provider = sqlx::query_as::<_, ComputeProvider>(
+        "INSERT INTO compute_providers (id, name, conversion_rate, credential_ref, active, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, true, now(), now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(name)
+    .bind(conversion_rate)
+    .bind(credential_ref)
+    .fetch_one(db)
+    .await?;
+    Ok(provider)
+}
+
+pub async fn list(db: &PgPool) -> AppResult<Vec<ComputeProvider>> {
+    let providers = sqlx::query_as::<_, ComputeProvider>("SELECT * FROM compute_providers ORDER BY name ASC")
+        .fetch_all(db)
+        .await?;
+    Ok(providers)
+}
+
+pub async fn update(
+    db: &PgPool,
+    id: Uuid,
+    name: Option<String>,
+    conversion_rate: Option<Decimal>,
+    credential_ref: Option<String>,
+) -> AppResult<ComputeProvider> {
+    let provider = sqlx::query_as::<_, ComputeProvider>(
+        "UPDATE compute_providers SET
+             name = COALESCE($2, name),
+             conversion_rate = COALESCE($3, conversion_rate),
+             credential_ref = COALESCE($4, credential_ref),
+             updated_at = now()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(id)
+    .bind(name)
+    .bind(conversion_rate)
+    .bind(credential_ref)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)?;
+    Ok(provider)
+}
+
+/// Flips `active` rather than deleting the row, since past
+/// `redemption_receipts` reference it by `provider_id`.
+pub async fn set_active(db: &PgPool, id: Uuid, active: bool) -> AppResult<ComputeProvider> {
+    let provider = sqlx::query_as::<_, ComputeProvider>(
+        "UPDATE compute_providers SET active = $2, updated_at = now() WHERE id = $1 RETURNING *",
+    )
+    .bind(id)
+    .bind(active)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)?;
+    Ok(provider)
+}
@@ -0,0 +1,27 @@
+use sqlx::PgPool;
+
+use crate::error::{AppError, AppResult};
+use crate::models::UserPolicy;
+
+/// Verifies `delegate_did` may act on `operator_did`'s behalf: either
+/// they're the same DID, or `operator_did`'s policy lists `delegate_did` in
+/// `allowed_delegates`. A delegate otherwise inherits the operator's own
+/// spend limits — there's no separate delegate-level policy to configure.
+pub async fn authorize(db: &PgPool, operator_did: &str, delegate_did: &str) -> AppResult<()> {
+    if operator_did == delegate_did {
+        return Ok(());
+    }
+
+    let policy = sqlx::query_as::<_, UserPolicy>("SELECT * FROM user_policies WHERE did = $1")
+        .bind(operator_did)
+        .fetch_optional(db)
+        .await?;
+
+    let allowed = policy.map(|p| p.allowed_delegates.iter().any(|d| d == delegate_did)).unwrap_or(false);
+    if !allowed {
+        return Err(AppError::Forbidden(format!(
+            "{delegate_did} is not a registered delegate for {operator_did}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use chrono::{Duration, Utc};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::models::{DidBinding, DidChallenge, DidRebinding};
+use crate::services::audit;
+
+const CHALLENGE_TTL_MINUTES: i64 = 10;
+
+/// How long a rebinding waits before `jobs::did_rebinding` is allowed to
+/// move the balance and open bounties over to `new_did`, when the old DID
+/// couldn't confirm the transition itself (lost key, compromised key,
+/// etc). A signed confirmation from the old DID skips the wait entirely,
+/// since that's the strongest proof of authorized intent we can get.
+const UNCONFIRMED_REBIND_COOLDOWN_HOURS: i64 = 72;
+
+/// What the client signs: binds the nonce to the specific DID it was
+/// issued for, so a signature can't be replayed against a different DID.
+#[derive(Serialize)]
+struct ChallengePayload<'a> {
+    did: &'a str,
+    nonce: &'a str,
+}
+
+/// Issues a fresh nonce for `did` to sign, proving control of the
+/// corresponding private key before `verify_and_bind` records the binding.
+pub async fn issue_challenge(db: &Db, did: &str) -> AppResult<DidChallenge> {
+    let mut nonce_bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = hex::encode(nonce_bytes);
+
+    let challenge = sqlx::query_as::<_, DidChallenge>(
+        "INSERT INTO did_challenges (id, did, nonce, expires_at, created_at)
+         VALUES ($1, $2, $3, $4, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(&nonce)
+    .bind(Utc::now() + Duration::minutes(CHALLENGE_TTL_MINUTES))
+    .fetch_one(db)
+    .await?;
+
+    Ok(challenge)
+}
+
+/// Consumes the outstanding challenge for `did`/`nonce` and, if `signature`
+/// verifies against it, records the binding. The challenge is consumed
+/// whether or not the signature checks out, so a leaked nonce can't be
+/// retried against a forged signature.
+pub async fn verify_and_bind(db: &Db, did: &str, nonce: &str, signature: &str) -> AppResult<DidBinding> {
+    let mut tx = db.begin().await?;
+
+    let challenge = sqlx::query_as::<_, DidChallenge>(
+        "UPDATE did_challenges SET used_at = now()
+         WHERE did = $1 AND nonce = $2 AND used_at IS NULL AND expires_at > now()
+         RETURNING *",
+    )
+    .bind(did)
+    .bind(nonce)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::BadRequest("no outstanding challenge for this DID and nonce".into()))?;
+
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid DID: {e}")))?;
+    let payload = ChallengePayload { did, nonce: &challenge.nonce };
+    if let Err(e) = openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature) {
+        // Commit the consumed challenge before rejecting, so the nonce
+        // can't be tried again.
+        tx.commit().await?;
+        return Err(AppError::BadRequest(format!("challenge signature invalid: {e}")));
+    }
+
+    let binding = sqlx::query_as::<_, DidBinding>(
+        "INSERT INTO did_bindings (id, did, bound_at)
+         VALUES ($1, $2, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(db, did, "bind_did", "did_binding", binding.id, None, Some(serde_json::json!(&binding))).await?;
+
+    Ok(binding)
+}
+
+#[derive(Serialize)]
+struct RevokePayload<'a> {
+    action: &'a str,
+    did: &'a str,
+}
+
+/// Revokes `did`'s active binding. `signature` must be over
+/// `{action: "revoke_did_binding", did}`, signed by `did` itself — only the
+/// bound DID can revoke its own binding.
+pub async fn revoke(db: &Db, did: &str, signature: &str) -> AppResult<DidBinding> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid DID: {e}")))?;
+    let payload = RevokePayload { action: "revoke_did_binding", did };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("revocation signature invalid: {e}")))?;
+
+    let binding = sqlx::query_as::<_, DidBinding>(
+        "UPDATE did_bindings SET revoked_at = now()
+         WHERE did = $1 AND revoked_at IS NULL
+         RETURNING *",
+    )
+    .bind(did)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    audit::record(db, did, "revoke_did_binding", "did_binding", binding.id, None, Some(serde_json::json!(&binding))).await?;
+
+    Ok(binding)
+}
+
+#[derive(Serialize)]
+struct RebindPayload<'a> {
+    action: &'a str,
+    old_did: &'a str,
+    new_did: &'a str,
+}
+
+/// Revokes `old_did`'s binding, binds `new_did` in its place (subject to
+/// the same nonce challenge as a fresh binding), and schedules the balance
+/// and open-bounty propagation described on the request.
+///
+/// `old_signature`, when present, must be over
+/// `{action: "rebind_did", old_did, new_did}` signed by `old_did` — the
+/// strongest available proof the poster, not an attacker, initiated the
+/// switch. Without it, propagation is held back by
+/// `UNCONFIRMED_REBIND_COOLDOWN_HOURS` so a hijacked account has a window
+/// to be caught before funds move.
+pub async fn request_rebinding(
+    db: &Db,
+    old_did: &str,
+    new_did: &str,
+    new_nonce: &str,
+    new_signature: &str,
+    old_signature: Option<&str>,
+) -> AppResult<DidRebinding> {
+    sqlx::query_as::<_, DidBinding>("SELECT * FROM did_bindings WHERE did = $1 AND revoked_at IS NULL")
+        .bind(old_did)
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| AppError::BadRequest("no active binding for old_did".into()))?;
+
+    let confirmed_by_old_did = match old_signature {
+        Some(sig) => {
+            let verifying_key = openclaw_crypto::verifying_key_from_did_key(old_did)
+                .map_err(|e| AppError::BadRequest(format!("invalid old_did: {e}")))?;
+            let payload = RebindPayload { action: "rebind_did", old_did, new_did };
+            openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, sig)
+                .map_err(|e| AppError::BadRequest(format!("old_did confirmation signature invalid: {e}")))?;
+            true
+        }
+        None => false,
+    };
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query("UPDATE did_bindings SET revoked_at = now() WHERE did = $1 AND revoked_at IS NULL")
+        .bind(old_did)
+        .execute(&mut *tx)
+        .await?;
+
+    let new_challenge = sqlx::query_as::<_, DidChallenge>(
+        "UPDATE did_challenges SET used_at = now()
+         WHERE did = $1 AND nonce = $2 AND used_at IS NULL AND expires_at > now()
+         RETURNING *",
+    )
+    .bind(new_did)
+    .bind(new_nonce)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::BadRequest("no outstanding challenge for new_did and nonce".into()))?;
+
+    let new_verifying_key = openclaw_crypto::verifying_key_from_did_key(new_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid new_did: {e}")))?;
+    let new_payload = ChallengePayload { did: new_did, nonce: &new_challenge.nonce };
+    openclaw_crypto::verify::verify_canonical(&new_verifying_key, &new_payload, new_signature)
+        .map_err(|e| AppError::BadRequest(format!("new_did challenge signature invalid: {e}")))?;
+
+    sqlx::query("INSERT INTO did_bindings (id, did, bound_at) VALUES ($1, $2, now())")
+        .bind(Uuid::new_v4())
+        .bind(new_did)
+        .execute(&mut *tx)
+        .await?;
+
+    let cooldown_until = if confirmed_by_old_did {
+        Utc::now()
+    } else {
+        Utc::now() + Duration::hours(UNCONFIRMED_REBIND_COOLDOWN_HOURS)
+    };
+
+    let rebinding = sqlx::query_as::<_, DidRebinding>(
+        "INSERT INTO did_rebindings (id, old_did, new_did, confirmed_by_old_did, requested_at, cooldown_until)
+         VALUES ($1, $2, $3, $4, now(), $5)
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(old_did)
+    .bind(new_did)
+    .bind(confirmed_by_old_did)
+    .bind(cooldown_until)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        old_did,
+        "rebind_did",
+        "did_rebinding",
+        rebinding.id,
+        None,
+        Some(serde_json::json!(&rebinding)),
+    )
+    .await?;
+
+    Ok(rebinding)
+}
+
+/// Moves the balance and any open bounties/escrow holds from `old_did` to
+/// `new_did` once a rebinding's cooldown has elapsed. Called by
+/// `jobs::did_rebinding`; never by a request handler directly, since the
+/// whole point of the cooldown is that nothing propagates synchronously.
+pub async fn finalize_due_rebindings(db: &Db) -> AppResult<Vec<DidRebinding>> {
+    let due = sqlx::query_as::<_, DidRebinding>(
+        "SELECT * FROM did_rebindings WHERE completed_at IS NULL AND cooldown_until <= now()",
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut finalized = Vec::with_capacity(due.len());
+    for rebinding in due {
+        let mut tx = db.begin().await?;
+
+        sqlx::query("UPDATE m_credits_accounts SET did = $1 WHERE did = $2")
+            .bind(&rebinding.new_did)
+            .bind(&rebinding.old_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "UPDATE bounties SET poster_did = $1 WHERE poster_did = $2
+             AND status IN ('pending_approval', 'open', 'in_review')",
+        )
+        .bind(&rebinding.new_did)
+        .bind(&rebinding.old_did)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE escrow_holds SET held_did = $1 WHERE held_did = $2 AND status = 'held'")
+            .bind(&rebinding.new_did)
+            .bind(&rebinding.old_did)
+            .execute(&mut *tx)
+            .await?;
+
+        let completed = sqlx::query_as::<_, DidRebinding>(
+            "UPDATE did_rebindings SET completed_at = now() WHERE id = $1 RETURNING *",
+        )
+        .bind(rebinding.id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        audit::record(
+            db,
+            &completed.new_did,
+            "finalize_rebind_did",
+            "did_rebinding",
+            completed.id,
+            None,
+            Some(serde_json::json!(&completed)),
+        )
+        .await?;
+
+        finalized.push(completed);
+    }
+
+    Ok(finalized)
+}
@@ -0,0 +1,126 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Dispute, DisputeOutcome, EscrowPurpose, ReviewVote};
+use crate::services::{jurors, reputation};
+
+/// A reviewer with no track record yet (or a negative one) still gets a
+/// minimal say in how strongly a dispute vindicates or overturns their
+/// vote, mirroring `services::quorum::MIN_REVIEWER_WEIGHT`.
+const MIN_REVIEWER_WEIGHT: Decimal = dec!(0.1);
+
+/// Credibility a reviewer earns when a dispute vindicates their vote on
+/// the disputed submission, or loses when the dispute overturns it. Larger
+/// than `services::quorum::REVIEWER_CREDIBILITY_DELTA` since an arbiter
+/// ruling is a stronger signal than simply agreeing with the tally.
+const DISPUTE_OVERTURN_CREDIBILITY_DELTA: Decimal = dec!(0.25);
+
+/// Applies a dispute outcome: releases or claws back the submission escrow,
+/// and returns or slashes the initiator's stake. Shared by the manual
+/// arbiter-resolution endpoint and the deadline auto-resolution worker so
+/// both paths settle funds identically.
+pub async fn settle(db: &PgPool, dispute: &Dispute, outcome: DisputeOutcome) -> AppResult<()> {
+    let mut tx = db.begin().await?;
+
+    match outcome {
+        DisputeOutcome::FavorSubmitter => {
+            // Only the bounty's reward hold, never a dispute stake hold
+            // that happens to share this `bounty_id` (see `EscrowPurpose`).
+            sqlx::query(
+                "UPDATE escrow_holds SET status = 'released' WHERE bounty_id = $1 AND purpose = $2 AND status = 'held'",
+            )
+            .bind(dispute.bounty_id)
+            .bind(EscrowPurpose::BountyReward)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+                .bind(dispute.initiator_stake)
+                .bind(&dispute.initiator_did)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query(
+                "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+                 VALUES ($1, $2, $3, 'dispute_stake_returned', $4, now())",
+            )
+            .bind(Uuid::new_v4())
+            .bind(&dispute.initiator_did)
+            .bind(dispute.initiator_stake)
+            .bind(dispute.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        DisputeOutcome::FavorRequester => {
+            sqlx::query(
+                "UPDATE escrow_holds SET status = 'refunded' WHERE bounty_id = $1 AND purpose = $2 AND status = 'held'",
+            )
+            .bind(dispute.bounty_id)
+            .bind(EscrowPurpose::BountyReward)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+                 VALUES ($1, $2, $3, 'dispute_stake_slashed', $4, now())",
+            )
+            .bind(Uuid::new_v4())
+            .bind(&dispute.initiator_did)
+            .bind(-dispute.initiator_stake)
+            .bind(dispute.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    sqlx::query("UPDATE disputes SET status = 'resolved', outcome = $2, resolved_at = now() WHERE id = $1")
+        .bind(dispute.id)
+        .bind(outcome)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    let (winner, loser) = match outcome {
+        DisputeOutcome::FavorSubmitter => (&dispute.respondent_did, &dispute.initiator_did),
+        DisputeOutcome::FavorRequester => (&dispute.initiator_did, &dispute.respondent_did),
+    };
+    reputation::mint_reputation(db, winner, Decimal::ONE, Some(dispute.bounty_id), Some(dispute.submission_id), None).await?;
+    reputation::mint_reputation(db, loser, -Decimal::ONE, Some(dispute.bounty_id), Some(dispute.submission_id), None).await?;
+
+    adjust_reviewer_credibility(db, dispute, outcome).await?;
+    jurors::distribute_stakes(db, dispute, outcome).await?;
+
+    Ok(())
+}
+
+/// Reviewers who voted on `dispute.submission_id` had their say overruled
+/// or confirmed by this resolution — vindicated reviewers earn credibility,
+/// overturned ones lose it, on top of whatever `tally_and_maybe_release`
+/// already awarded them for agreeing with the quorum's own majority.
+async fn adjust_reviewer_credibility(db: &PgPool, dispute: &Dispute, outcome: DisputeOutcome) -> AppResult<()> {
+    let vindicated_vote = match outcome {
+        DisputeOutcome::FavorSubmitter => ReviewVote::Approve,
+        DisputeOutcome::FavorRequester => ReviewVote::Reject,
+    };
+
+    let votes: Vec<(String, ReviewVote, Decimal)> = sqlx::query_as(
+        "SELECT r.reviewer_did, r.vote, GREATEST(COALESCE(s.score, 0), $2) AS weight
+         FROM submission_reviews r
+         LEFT JOIN reputation_scores s ON s.did = r.reviewer_did
+         WHERE r.submission_id = $1",
+    )
+    .bind(dispute.submission_id)
+    .bind(MIN_REVIEWER_WEIGHT)
+    .fetch_all(db)
+    .await?;
+
+    for (reviewer_did, vote, weight) in votes {
+        let delta = if vote == vindicated_vote { DISPUTE_OVERTURN_CREDIBILITY_DELTA } else { -DISPUTE_OVERTURN_CREDIBILITY_DELTA };
+        reputation::mint_reputation(db, &reviewer_did, delta, Some(dispute.bounty_id), Some(dispute.submission_id), Some(weight)).await?;
+    }
+
+    Ok(())
+}
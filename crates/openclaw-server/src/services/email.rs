@@ -0,0 +1,26 @@
+use lettre::message::Message;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+/// Sends a notification email via SMTP (SES is reached through its SMTP
+/// endpoint, so one transport covers both). Configured entirely from
+/// environment so self-hosted deployments can point at their own relay.
+pub async fn send(to: &str, subject: &str, body: &str) -> anyhow::Result<()> {
+    let host = std::env::var("SMTP_HOST")?;
+    let username = std::env::var("SMTP_USERNAME")?;
+    let password = std::env::var("SMTP_PASSWORD")?;
+    let from = std::env::var("SMTP_FROM").unwrap_or_else(|_| "notifications@protocol-m".into());
+
+    let message = Message::builder()
+        .from(from.parse()?)
+        .to(to.parse()?)
+        .subject(subject)
+        .body(body.to_string())?;
+
+    let transport = AsyncSmtpTransport::<Tokio1Executor>::relay(&host)?
+        .credentials(Credentials::new(username, password))
+        .build();
+
+    transport.send(message).await?;
+    Ok(())
+}
@@ -0,0 +1,336 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{EscrowHold, EscrowPurpose};
+use crate::services::{audit, fees, promo};
+
+/// Creates a new escrow hold against `held_did`'s balance for `amount`.
+/// Promo credits are drawn down first (see `services::promo`), and only
+/// the remainder is debited from the main balance, in the same transaction
+/// as the matching ledger event. `delegate_did` is set when a delegate
+/// acted on `held_did`'s behalf (see `services::delegation`), so the
+/// ledger entry records who actually spent the funds. `purpose`
+/// distinguishes this hold from others that may share the same
+/// `bounty_id` (e.g. a reward hold versus a dispute stake), so
+/// `release_to`/`refund_escrow_for_bounty` only ever touch the hold they
+/// mean to.
+pub async fn hold_escrow(
+    db: &PgPool,
+    bounty_id: Uuid,
+    held_did: &str,
+    amount: Decimal,
+    delegate_did: Option<&str>,
+    purpose: EscrowPurpose,
+) -> AppResult<EscrowHold> {
+    let mut tx = db.begin().await?;
+
+    let from_main_balance = promo::spend_promo_first(&mut tx, held_did, amount).await?;
+
+    if from_main_balance > Decimal::ZERO {
+        // Lock the account row for the rest of the transaction so a
+        // concurrent hold or redemption against the same DID can't read a
+        // stale balance and also pass its own check — without this,
+        // `balance >= $1` in the UPDATE below is checked and applied
+        // atomically per statement, but two concurrent transactions can
+        // each see enough balance to proceed before either commits.
+        let balance: Option<Decimal> = sqlx::query_scalar("SELECT balance FROM m_credits_accounts WHERE did = $1 FOR UPDATE")
+            .bind(held_did)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if balance.unwrap_or_default() < from_main_balance {
+            return Err(AppError::domain_with_details(
+                crate::error::ErrorCode::InsufficientBalance,
+                format!(
+                    "insufficient balance: held {held_did} needs {from_main_balance}, has {}",
+                    balance.unwrap_or_default()
+                ),
+                serde_json::json!({ "needs": from_main_balance, "has": balance.unwrap_or_default() }),
+            ));
+        }
+
+        sqlx::query("UPDATE m_credits_accounts SET balance = balance - $1 WHERE did = $2")
+            .bind(from_main_balance)
+            .bind(held_did)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let promo_amount = amount - from_main_balance;
+    let hold = sqlx::query_as::<_, EscrowHold>(
+        "INSERT INTO escrow_holds (id, bounty_id, held_did, amount, status, purpose, promo_amount, created_at)
+         VALUES ($1, $2, $3, $4, 'held', $5, $6, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(bounty_id)
+    .bind(held_did)
+    .bind(amount)
+    .bind(purpose)
+    .bind(promo_amount)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if from_main_balance > Decimal::ZERO {
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, delegate_did, created_at)
+             VALUES ($1, $2, $3, 'escrow_hold', $4, $5, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(held_did)
+        .bind(-from_main_balance)
+        .bind(bounty_id)
+        .bind(delegate_did)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    audit::record(db, held_did, "hold_escrow", "escrow_hold", hold.id, None, Some(serde_json::json!(&hold))).await?;
+
+    Ok(hold)
+}
+
+/// Releases every `held` escrow of `purpose` for `bounty_id` by crediting
+/// `recipient_did` with `amount` (less the platform fee on a `BountyReward`
+/// payout, see `fees::split`) and marking the hold(s) released. Used
+/// when a bounty closes successfully, whether by requester sign-off or
+/// quorum approval; `purpose` is `BountyReward` for every current caller,
+/// since reward holds settle independently of any dispute stake on the
+/// same bounty.
+pub async fn release_to(
+    db: &PgPool,
+    bounty_id: Uuid,
+    recipient_did: &str,
+    amount: Decimal,
+    purpose: EscrowPurpose,
+) -> AppResult<()> {
+    // Only a reward payout is a "payout" for fee purposes — a dispute or
+    // juror stake being returned to its own owner isn't platform revenue.
+    let (net_amount, fee) = if purpose == EscrowPurpose::BountyReward {
+        fees::split(amount, recipient_did)
+    } else {
+        (amount, Decimal::ZERO)
+    };
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query("UPDATE escrow_holds SET status = 'released' WHERE bounty_id = $1 AND purpose = $2 AND status = 'held'")
+        .bind(bounty_id)
+        .bind(purpose)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+        .bind(net_amount)
+        .bind(recipient_did)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, 'escrow_release', $4, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(recipient_did)
+    .bind(net_amount)
+    .bind(bounty_id)
+    .execute(&mut *tx)
+    .await?;
+
+    fees::credit_treasury(&mut tx, fee, "platform_fee", bounty_id).await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        recipient_did,
+        "release_escrow",
+        "bounty",
+        bounty_id,
+        None,
+        Some(serde_json::json!({ "recipient_did": recipient_did, "amount": amount })),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Same as `release_to`, except when `artifact_id` is the winning
+/// submission's artifact and it declares a derivation, `config::current().
+/// royalty_split_percent` of `amount` is split evenly across the direct
+/// parent artifacts' signers instead of going to `recipient_did`. Parents
+/// more than one hop up the derivation graph aren't included — a bounty
+/// payout rewards direct attribution, not every ancestor of an ancestor.
+/// A `pending` (not yet countersigned or waived) derivation claim doesn't
+/// count — see `services::artifacts::resolve_derivation_claim`. Falls back
+/// to a plain `release_to` when the config percentage is zero or the
+/// artifact has no verified parents.
+pub async fn release_to_with_royalties(
+    db: &PgPool,
+    bounty_id: Uuid,
+    recipient_did: &str,
+    amount: Decimal,
+    purpose: EscrowPurpose,
+    artifact_id: Option<Uuid>,
+) -> AppResult<()> {
+    let split_percent = crate::config::current().royalty_split_percent;
+    let parent_signers: Vec<String> = if split_percent > Decimal::ZERO {
+        match artifact_id {
+            Some(artifact_id) => {
+                sqlx::query_scalar(
+                    "SELECT DISTINCT a.signer_did FROM artifact_derivations d
+                     JOIN artifacts a ON a.id = d.parent_artifact_id
+                     WHERE d.child_artifact_id = $1 AND d.status != 'pending' AND a.signer_did != $2",
+                )
+                .bind(artifact_id)
+                .bind(recipient_did)
+                .fetch_all(db)
+                .await?
+            }
+            None => Vec::new(),
+        }
+    } else {
+        Vec::new()
+    };
+
+    if parent_signers.is_empty() {
+        return release_to(db, bounty_id, recipient_did, amount, purpose).await;
+    }
+
+    let royalty_total = amount * split_percent / dec!(100);
+    let royalty_share = royalty_total / Decimal::from(parent_signers.len());
+    let gross_recipient_share = amount - royalty_share * Decimal::from(parent_signers.len());
+    let (recipient_share, fee) = fees::split(gross_recipient_share, recipient_did);
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query("UPDATE escrow_holds SET status = 'released' WHERE bounty_id = $1 AND purpose = $2 AND status = 'held'")
+        .bind(bounty_id)
+        .bind(purpose)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+        .bind(recipient_share)
+        .bind(recipient_did)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, 'escrow_release', $4, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(recipient_did)
+    .bind(recipient_share)
+    .bind(bounty_id)
+    .execute(&mut *tx)
+    .await?;
+
+    for parent_did in &parent_signers {
+        sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+            .bind(royalty_share)
+            .bind(parent_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+             VALUES ($1, $2, $3, 'royalty_payout', $4, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(parent_did)
+        .bind(royalty_share)
+        .bind(bounty_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    fees::credit_treasury(&mut tx, fee, "platform_fee", bounty_id).await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        recipient_did,
+        "release_escrow_with_royalties",
+        "bounty",
+        bounty_id,
+        None,
+        Some(serde_json::json!({
+            "recipient_did": recipient_did,
+            "recipient_share": recipient_share,
+            "royalty_share": royalty_share,
+            "royalty_recipients": parent_signers,
+        })),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Returns every `held` escrow of `purpose` for `bounty_id` to the account
+/// it was taken from, crediting the balance and writing a matching ledger
+/// event. Used when a bounty expires or is cancelled before its reward is
+/// paid out; scoped to `BountyReward` so an open dispute's stake hold on
+/// the same bounty isn't swept up in the refund.
+pub async fn refund_escrow_for_bounty(db: &PgPool, bounty_id: Uuid, purpose: EscrowPurpose) -> AppResult<Vec<EscrowHold>> {
+    let mut tx = db.begin().await?;
+
+    let holds = sqlx::query_as::<_, EscrowHold>(
+        "UPDATE escrow_holds SET status = 'refunded'
+         WHERE bounty_id = $1 AND purpose = $2 AND status = 'held'
+         RETURNING *",
+    )
+    .bind(bounty_id)
+    .bind(purpose)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for hold in &holds {
+        // `hold.amount` was never entirely debited from main balance —
+        // `hold_escrow` draws promo credit first (see `promo::spend_promo_first`)
+        // and only takes `amount - promo_amount` from `m_credits_accounts`.
+        // Crediting the full `amount` back to main balance here would mint
+        // `promo_amount` of free, redeemable balance, so each portion goes
+        // back to the pool it came from.
+        let main_refund = hold.amount - hold.promo_amount;
+        if main_refund > Decimal::ZERO {
+            sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+                .bind(main_refund)
+                .bind(&hold.held_did)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query(
+                "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+                 VALUES ($1, $2, $3, 'escrow_refund', $4, now())",
+            )
+            .bind(Uuid::new_v4())
+            .bind(&hold.held_did)
+            .bind(main_refund)
+            .bind(bounty_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        promo::refund(&mut tx, &hold.held_did, hold.promo_amount, hold.id).await?;
+    }
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        "system",
+        "refund_escrow",
+        "bounty",
+        bounty_id,
+        None,
+        Some(serde_json::json!(&holds)),
+    )
+    .await?;
+
+    Ok(holds)
+}
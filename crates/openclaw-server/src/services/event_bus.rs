@@ -0,0 +1,96 @@
+//! A single shared `LISTEN/NOTIFY` connection fanning Postgres
+//! notifications out to every SSE subscriber, instead of each
+//! `routes::events::stream_events` / `routes::ledger::stream_public_ledger`
+//! connection opening its own `PgListener`. SSE clients are long-lived, so
+//! "one Postgres connection per open browser tab" has no cap, and a DB
+//! hiccup on any one of those connections used to panic the Axum task
+//! handling it. `init` opens the one connection this process holds and
+//! reconnects it on its own; `subscribe` hands out cheap in-process
+//! receivers instead.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use sqlx::postgres::PgListener;
+use tokio::sync::broadcast;
+
+use crate::db::Db;
+
+const CHANNELS: &[&str] = &[
+    "bounty_events",
+    "dispute_events",
+    "credit_events",
+    "approval_events",
+    "ledger_events",
+    "reserve_attestation_events",
+];
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone)]
+pub struct BusEvent {
+    pub channel: String,
+    pub payload: serde_json::Value,
+}
+
+static BUS: OnceLock<broadcast::Sender<BusEvent>> = OnceLock::new();
+
+/// Opens the one shared `PgListener` this process holds, subscribed to
+/// every channel an SSE route cares about, and spawns the task that
+/// forwards notifications onto the in-process broadcast channel every
+/// subscriber reads from. `main` calls this once at startup, right
+/// alongside `db::init_replica`/`identity::init`, so a connection failure
+/// here is an ordinary startup error rather than a panic in a request
+/// handler. Idempotent; later calls are no-ops.
+pub async fn init(db: &Db) -> Result<(), sqlx::Error> {
+    if BUS.get().is_some() {
+        return Ok(());
+    }
+    let listener = connect(db).await?;
+    let (tx, _) = broadcast::channel(1024);
+    let _ = BUS.set(tx.clone());
+    tokio::spawn(forward(db.clone(), listener, tx));
+    Ok(())
+}
+
+async fn connect(db: &Db) -> Result<PgListener, sqlx::Error> {
+    let mut listener = PgListener::connect_with(db).await?;
+    listener.listen_all(CHANNELS.iter().copied()).await?;
+    Ok(listener)
+}
+
+/// Forwards notifications from `listener` onto `tx` for the life of the
+/// process, reconnecting with a short delay if the connection drops — a
+/// transient DB hiccup should cost subscribers a few seconds of silence,
+/// not kill every open SSE stream for good.
+async fn forward(db: Db, mut listener: PgListener, tx: broadcast::Sender<BusEvent>) {
+    loop {
+        match listener.recv().await {
+            Ok(notification) => {
+                let payload = serde_json::from_str(notification.payload()).unwrap_or_default();
+                let _ = tx.send(BusEvent { channel: notification.channel().to_string(), payload });
+            }
+            Err(err) => {
+                tracing::error!(?err, "event bus listener dropped; reconnecting");
+                loop {
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                    match connect(&db).await {
+                        Ok(new_listener) => {
+                            listener = new_listener;
+                            break;
+                        }
+                        Err(err) => tracing::error!(?err, "event bus reconnect failed"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A fresh subscription to every event this process's shared listener
+/// forwards — cheap (an in-process channel receiver), unlike opening a new
+/// `PgListener` per caller. Panics if `init` hasn't run yet, the same
+/// contract `db::replica()` uses.
+pub fn subscribe() -> broadcast::Receiver<BusEvent> {
+    BUS.get().expect("event_bus::init was not called").subscribe()
+}
@@ -0,0 +1,106 @@
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Bounty, ClosureType, ExecutionReceipt, Submission, SubmissionStatus};
+use crate::services::{audit, escrow, trusted_runners};
+
+#[derive(Serialize)]
+struct ExecutionReceiptPayload<'a> {
+    action: &'a str,
+    submission_id: Uuid,
+    passed: bool,
+    detail: &'a Value,
+}
+
+/// Verifies `signature` is over `{action: "execution_receipt", submission_id,
+/// passed, detail}` signed by `runner_did`, requires `runner_did` to be on
+/// `bounty`'s trusted-runner registry, then records the receipt: a passing
+/// result accepts the submission, closes the bounty, and releases escrow to
+/// the submitter the same way `select_winner` does; a failing result just
+/// rejects the submission and leaves the bounty open for other runs.
+pub async fn record(
+    db: &PgPool,
+    bounty: &Bounty,
+    submission: &Submission,
+    runner_did: &str,
+    passed: bool,
+    detail: Value,
+    signature: &str,
+) -> AppResult<ExecutionReceipt> {
+    if bounty.closure_type != ClosureType::Test {
+        return Err(AppError::BadRequest("bounty is not test-closure".into()));
+    }
+    if submission.status != SubmissionStatus::Pending && submission.status != SubmissionStatus::InReview {
+        return Err(AppError::BadRequest("submission is not awaiting an execution receipt".into()));
+    }
+    if !trusted_runners::is_trusted(db, bounty.id, runner_did).await? {
+        return Err(AppError::Forbidden("runner is not on this bounty's trusted-runner registry".into()));
+    }
+
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(runner_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid runner did: {e}")))?;
+    let payload = ExecutionReceiptPayload { action: "execution_receipt", submission_id: submission.id, passed, detail: &detail };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("execution receipt signature invalid: {e}")))?;
+
+    let receipt = sqlx::query_as::<_, ExecutionReceipt>(
+        "INSERT INTO execution_receipts (id, submission_id, runner_did, passed, detail, signature, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(submission.id)
+    .bind(runner_did)
+    .bind(passed)
+    .bind(&detail)
+    .bind(signature)
+    .fetch_one(db)
+    .await?;
+
+    if !passed {
+        sqlx::query("UPDATE submissions SET status = 'rejected', updated_at = now() WHERE id = $1")
+            .bind(submission.id)
+            .execute(db)
+            .await?;
+        audit::record(
+            db,
+            runner_did,
+            "execution_receipt",
+            "submission",
+            submission.id,
+            None,
+            Some(serde_json::json!(&receipt)),
+        )
+        .await?;
+        return Ok(receipt);
+    }
+
+    let mut tx = db.begin().await?;
+    sqlx::query("UPDATE submissions SET status = 'accepted', updated_at = now() WHERE id = $1")
+        .bind(submission.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE bounties SET status = 'closed', updated_at = now() WHERE id = $1")
+        .bind(bounty.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    escrow::release_to_with_royalties(db, bounty.id, &submission.submitter_did, bounty.reward, crate::models::EscrowPurpose::BountyReward, submission.artifact_id).await?;
+
+    audit::record(
+        db,
+        runner_did,
+        "execution_receipt",
+        "submission",
+        submission.id,
+        None,
+        Some(serde_json::json!(&receipt)),
+    )
+    .await?;
+
+    Ok(receipt)
+}
@@ -0,0 +1,98 @@
+//! A configurable cut of bounty payouts and redemptions, routed to a
+//! platform treasury pseudo-account instead of the payee. Percentage and
+//! treasury account are set via `config::RuntimeConfig`; individual DIDs
+//! can be exempted with `PLATFORM_FEE_EXEMPT_DIDS`.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+
+fn is_exempt(did: &str) -> bool {
+    std::env::var("PLATFORM_FEE_EXEMPT_DIDS")
+        .map(|list| list.split(',').any(|d| d.trim() == did))
+        .unwrap_or(false)
+}
+
+/// Splits `gross` into what `payee_did` actually receives and what the
+/// platform treasury takes, per `config::current().platform_fee_percent`.
+/// An exempt payee (see `PLATFORM_FEE_EXEMPT_DIDS`) keeps the full gross
+/// amount and the fee is zero.
+pub fn split(gross: Decimal, payee_did: &str) -> (Decimal, Decimal) {
+    let percent = crate::config::current().platform_fee_percent;
+    if percent <= Decimal::ZERO || is_exempt(payee_did) {
+        return (gross, Decimal::ZERO);
+    }
+    let fee = gross * percent / dec!(100);
+    (gross - fee, fee)
+}
+
+/// Credits the platform treasury with `fee` inside an already-open
+/// transaction, and writes a matching ledger entry. A no-op when `fee` is
+/// zero, so callers can call this unconditionally after `split`.
+pub async fn credit_treasury(
+    tx: &mut sqlx::PgConnection,
+    fee: Decimal,
+    event_type: &str,
+    reference_id: Uuid,
+) -> AppResult<()> {
+    if fee <= Decimal::ZERO {
+        return Ok(());
+    }
+
+    let treasury_did = &crate::config::current().platform_treasury_did;
+
+    sqlx::query(
+        "INSERT INTO m_credits_accounts (did, balance) VALUES ($1, $2)
+         ON CONFLICT (did) DO UPDATE SET balance = m_credits_accounts.balance + excluded.balance",
+    )
+    .bind(treasury_did)
+    .bind(fee)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(treasury_did)
+    .bind(fee)
+    .bind(event_type)
+    .bind(reference_id)
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+/// The platform treasury's current balance.
+pub async fn treasury_balance(db: &PgPool) -> AppResult<Decimal> {
+    let treasury_did = &crate::config::current().platform_treasury_did;
+    let balance: Option<Decimal> = sqlx::query_scalar("SELECT balance FROM m_credits_accounts WHERE did = $1")
+        .bind(treasury_did)
+        .fetch_optional(db)
+        .await?;
+    Ok(balance.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+
+    // `config::current()` reads from a process-wide `OnceLock` seeded by
+    // `config::init()`; its default `platform_fee_percent` is zero, so
+    // `split` takes no fee unless a deployment sets `PLATFORM_FEE_PERCENT`.
+    // Percent-specific behavior isn't unit-testable here — the `OnceLock`
+    // can only be seeded once per process — so that path needs the
+    // end-to-end harness this repo doesn't have yet.
+    #[test]
+    fn takes_no_fee_by_default() {
+        crate::config::init();
+        let (net, fee) = split(rust_decimal_macros::dec!(100), "did:key:zAlice");
+        assert_eq!(net, rust_decimal_macros::dec!(100));
+        assert_eq!(fee, rust_decimal::Decimal::ZERO);
+    }
+}
@@ -0,0 +1,132 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::config;
+use crate::error::AppResult;
+use crate::models::{PurchaseReview, PurchaseReviewStatus};
+
+/// The outcome of `evaluate_purchase`: either clean, or flagged with the
+/// specific rule(s) that tripped so a reviewer doesn't have to re-derive it.
+pub struct VelocityCheck {
+    pub reasons: Vec<String>,
+}
+
+impl VelocityCheck {
+    pub fn flagged(&self) -> bool {
+        !self.reasons.is_empty()
+    }
+}
+
+/// Evaluates purchase velocity rules before an invoice/checkout session is
+/// created. Counts are against already-recorded invoices, so this must run
+/// before the new invoice row is inserted, not after.
+pub async fn evaluate_purchase(
+    db: &PgPool,
+    did: &str,
+    card_fingerprint: Option<&str>,
+    payer_mismatch: bool,
+) -> AppResult<VelocityCheck> {
+    let cfg = config::current();
+    let mut reasons = Vec::new();
+
+    let hour_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM invoices WHERE did = $1 AND created_at > now() - interval '1 hour'",
+    )
+    .bind(did)
+    .fetch_one(db)
+    .await?;
+    if hour_count >= cfg.max_purchases_per_hour {
+        reasons.push(format!(
+            "{did} has placed {hour_count} purchases in the last hour (limit {})",
+            cfg.max_purchases_per_hour
+        ));
+    }
+
+    let day_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM invoices WHERE did = $1 AND created_at > now() - interval '1 day'",
+    )
+    .bind(did)
+    .fetch_one(db)
+    .await?;
+    if day_count >= cfg.max_purchases_per_day {
+        reasons.push(format!(
+            "{did} has placed {day_count} purchases in the last day (limit {})",
+            cfg.max_purchases_per_day
+        ));
+    }
+
+    if let Some(fingerprint) = card_fingerprint {
+        let card_hour_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM invoices WHERE card_fingerprint = $1 AND created_at > now() - interval '1 hour'",
+        )
+        .bind(fingerprint)
+        .fetch_one(db)
+        .await?;
+        if card_hour_count >= cfg.max_purchases_per_hour_per_card {
+            reasons.push(format!(
+                "payment instrument has been used {card_hour_count} times in the last hour (limit {})",
+                cfg.max_purchases_per_hour_per_card
+            ));
+        }
+    }
+
+    if payer_mismatch {
+        reasons.push("purchase is credited to a DID other than the authenticated caller".into());
+    }
+
+    Ok(VelocityCheck { reasons })
+}
+
+/// Opens a `PurchaseReview` for an invoice a velocity rule flagged, leaving
+/// it `Pending` for an operator to approve or reject.
+pub async fn open_review(db: &PgPool, invoice_id: Uuid, did: &str, reasons: Vec<String>) -> AppResult<PurchaseReview> {
+    let review = sqlx::query_as::<_, PurchaseReview>(
+        "INSERT INTO purchase_reviews (id, invoice_id, did, reasons, status, created_at)
+         VALUES ($1, $2, $3, $4, 'pending', now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(invoice_id)
+    .bind(did)
+    .bind(&reasons)
+    .fetch_one(db)
+    .await?;
+    Ok(review)
+}
+
+/// Lists purchase reviews, newest first, optionally filtered by status.
+pub async fn list_reviews(db: &PgPool, status: Option<PurchaseReviewStatus>) -> AppResult<Vec<PurchaseReview>> {
+    let reviews = sqlx::query_as::<_, PurchaseReview>(
+        "SELECT * FROM purchase_reviews
+         WHERE ($1::text IS NULL OR status = $1)
+         ORDER BY created_at DESC",
+    )
+    .bind(status)
+    .fetch_all(db)
+    .await?;
+    Ok(reviews)
+}
+
+/// Decides a pending review. Approving does not itself open a checkout
+/// session — the caller re-submits the purchase once cleared, the same
+/// way a rejected one is simply abandoned.
+pub async fn decide_review(
+    db: &PgPool,
+    review_id: Uuid,
+    approve: bool,
+    decided_by: &str,
+) -> AppResult<PurchaseReview> {
+    let status = if approve { PurchaseReviewStatus::Approved } else { PurchaseReviewStatus::Rejected };
+    let review = sqlx::query_as::<_, PurchaseReview>(
+        "UPDATE purchase_reviews SET status = $2, decided_by = $3, decided_at = now()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(review_id)
+    .bind(status)
+    .bind(decided_by)
+    .fetch_optional(db)
+    .await?
+    .ok_or(crate::error::AppError::NotFound)?;
+    Ok(review)
+}
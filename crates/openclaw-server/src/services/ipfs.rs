@@ -0,0 +1,40 @@
+//! Pins artifact content to a pinning service's HTTP API (Pinata-shaped:
+//! multipart upload, JSON response with an `IpfsHash`/CID field). Any
+//! service speaking that shape works by pointing `IPFS_PIN_API_URL` at it.
+
+use crate::error::AppError;
+use crate::AppResult;
+
+/// Pins `content` and returns its CID, or `None` if IPFS pinning isn't
+/// configured for this deployment — pinning is opt-in, not required for
+/// the registry to function.
+pub async fn pin(content: bytes::Bytes, filename: &str) -> AppResult<Option<String>> {
+    let Ok(api_url) = std::env::var("IPFS_PIN_API_URL") else {
+        return Ok(None);
+    };
+    let api_key = std::env::var("IPFS_PIN_API_KEY").ok();
+
+    let part = reqwest::multipart::Part::bytes(content.to_vec()).file_name(filename.to_string());
+    let form = reqwest::multipart::Form::new().part("file", part);
+
+    let mut request = reqwest::Client::new().post(&api_url).multipart(form);
+    if let Some(key) = api_key {
+        request = request.bearer_auth(key);
+    }
+
+    let body: serde_json::Value = request
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .error_for_status()
+        .map_err(|e| AppError::Internal(e.into()))?
+        .json()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+
+    let cid = body["IpfsHash"]
+        .as_str()
+        .or_else(|| body["cid"].as_str())
+        .map(str::to_string);
+    Ok(cid)
+}
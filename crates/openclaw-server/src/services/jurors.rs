@@ -0,0 +1,145 @@
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Dispute, DisputeOutcome, DisputeStatus, EscrowHold, EscrowPurpose, JurorVote, StakeJurorVoteRequest};
+use crate::services::{audit, promo};
+
+/// Records a juror's staked vote on an open dispute, holding the stake in
+/// escrow under `EscrowPurpose::JurorStake` until `distribute_stakes`
+/// settles it. A juror may only vote once per dispute.
+pub async fn stake_vote(db: &PgPool, dispute: &Dispute, juror_did: &str, req: StakeJurorVoteRequest) -> AppResult<JurorVote> {
+    if dispute.status != DisputeStatus::Open {
+        return Err(AppError::BadRequest("dispute is already resolved".into()));
+    }
+    if req.stake <= Decimal::ZERO {
+        return Err(AppError::BadRequest("stake must be positive".into()));
+    }
+
+    let already_voted: Option<Uuid> = sqlx::query_scalar("SELECT id FROM juror_votes WHERE dispute_id = $1 AND juror_did = $2")
+        .bind(dispute.id)
+        .bind(juror_did)
+        .fetch_optional(db)
+        .await?;
+    if already_voted.is_some() {
+        return Err(AppError::BadRequest("juror has already voted on this dispute".into()));
+    }
+
+    crate::services::escrow::hold_escrow(db, dispute.bounty_id, juror_did, req.stake, None, EscrowPurpose::JurorStake).await?;
+
+    let vote = sqlx::query_as::<_, JurorVote>(
+        "INSERT INTO juror_votes (id, dispute_id, juror_did, vote, stake, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(dispute.id)
+    .bind(juror_did)
+    .bind(req.vote)
+    .bind(req.stake)
+    .fetch_one(db)
+    .await?;
+
+    Ok(vote)
+}
+
+/// Settles every juror stake on a resolved dispute: jurors who voted for
+/// the outcome the arbiter didn't pick forfeit their stake, pooled and
+/// split evenly across jurors who voted correctly (who also get their own
+/// stake back). A no-op when nobody staked a vote.
+pub async fn distribute_stakes(db: &PgPool, dispute: &Dispute, outcome: DisputeOutcome) -> AppResult<()> {
+    let votes: Vec<JurorVote> = sqlx::query_as("SELECT * FROM juror_votes WHERE dispute_id = $1")
+        .bind(dispute.id)
+        .fetch_all(db)
+        .await?;
+    if votes.is_empty() {
+        return Ok(());
+    }
+
+    let (winners, losers): (Vec<JurorVote>, Vec<JurorVote>) = votes.into_iter().partition(|v| v.vote == outcome);
+    let forfeited: Decimal = losers.iter().map(|v| v.stake).sum();
+    let bonus_share = if winners.is_empty() { Decimal::ZERO } else { forfeited / Decimal::from(winners.len()) };
+
+    let mut tx = db.begin().await?;
+
+    for loser in &losers {
+        sqlx::query(
+            "UPDATE escrow_holds SET status = 'slashed'
+             WHERE bounty_id = $1 AND held_did = $2 AND purpose = $3 AND amount = $4 AND status = 'held'",
+        )
+        .bind(dispute.bounty_id)
+        .bind(&loser.juror_did)
+        .bind(EscrowPurpose::JurorStake)
+        .bind(loser.stake)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+             VALUES ($1, $2, $3, 'juror_stake_slashed', $4, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(&loser.juror_did)
+        .bind(-loser.stake)
+        .bind(dispute.id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for winner in &winners {
+        // `winner.stake` was never entirely debited from main balance if
+        // part of it was promo-funded (see `escrow::hold_escrow`); only
+        // `stake - promo_amount` of a winner's own stake goes back to main
+        // balance, with the promo portion returned to promo credit instead
+        // (see `escrow::refund_escrow_for_bounty`, which has the same
+        // split). The pooled `bonus_share` from slashed losers is new
+        // winnings, not a refund, so it's credited to main balance in full.
+        let hold = sqlx::query_as::<_, EscrowHold>(
+            "UPDATE escrow_holds SET status = 'released'
+             WHERE bounty_id = $1 AND held_did = $2 AND purpose = $3 AND amount = $4 AND status = 'held'
+             RETURNING *",
+        )
+        .bind(dispute.bounty_id)
+        .bind(&winner.juror_did)
+        .bind(EscrowPurpose::JurorStake)
+        .bind(winner.stake)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let promo_amount = hold.map(|h| h.promo_amount).unwrap_or(Decimal::ZERO);
+
+        let payout = winner.stake - promo_amount + bonus_share;
+        sqlx::query("UPDATE m_credits_accounts SET balance = balance + $1 WHERE did = $2")
+            .bind(payout)
+            .bind(&winner.juror_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+             VALUES ($1, $2, $3, 'juror_stake_payout', $4, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(&winner.juror_did)
+        .bind(payout)
+        .bind(dispute.id)
+        .execute(&mut *tx)
+        .await?;
+
+        promo::refund(&mut tx, &winner.juror_did, promo_amount, dispute.id).await?;
+    }
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        "system",
+        "distribute_juror_stakes",
+        "dispute",
+        dispute.id,
+        None,
+        Some(serde_json::json!({ "winners": winners.len(), "losers": losers.len(), "forfeited": forfeited })),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::DidKeyRotation;
+use crate::services::audit;
+
+#[derive(Serialize)]
+struct RotationPayload<'a> {
+    action: &'a str,
+    old_did: &'a str,
+    new_did: &'a str,
+}
+
+/// Records `old_did` endorsing `new_did` as its successor signing key.
+/// `signature` must be over `{action: "rotate_key", old_did, new_did}`,
+/// signed by `old_did` — only the outgoing key can nominate its successor.
+/// Takes effect immediately: there's no fund movement to safeguard here, so
+/// none of `services::did_binding::request_rebinding`'s cooldown applies.
+pub async fn rotate(db: &PgPool, old_did: &str, new_did: &str, signature: &str) -> AppResult<DidKeyRotation> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(old_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid old_did: {e}")))?;
+    let payload = RotationPayload { action: "rotate_key", old_did, new_did };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("rotation signature invalid: {e}")))?;
+
+    let rotation = sqlx::query_as::<_, DidKeyRotation>(
+        "INSERT INTO did_key_rotations (id, old_did, new_did, rotated_at)
+         VALUES ($1, $2, $3, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(old_did)
+    .bind(new_did)
+    .fetch_one(db)
+    .await?;
+
+    audit::record(
+        db,
+        old_did,
+        "rotate_key",
+        "did_key_rotation",
+        rotation.id,
+        None,
+        Some(serde_json::json!(&rotation)),
+    )
+    .await?;
+
+    Ok(rotation)
+}
+
+/// Every DID in `did`'s rotation chain, `did` included, so a caller can
+/// match attribution across a rotation instead of just the one DID it asked
+/// about. Chains are expected to stay short (a handful of rotations at
+/// most), so this walks the full `did_key_rotations` table in memory rather
+/// than recursing in SQL.
+///
+/// `routes::artifacts::list_artifacts`'s `signer` filter and
+/// `services::profiles::view`'s artifact count consult this; bounty and
+/// reputation lookups should do the same once there's a query that filters
+/// either by DID.
+pub async fn resolve_chain(db: &PgPool, did: &str) -> AppResult<Vec<String>> {
+    let rotations = sqlx::query_as::<_, DidKeyRotation>("SELECT * FROM did_key_rotations").fetch_all(db).await?;
+
+    let mut chain = vec![did.to_string()];
+    loop {
+        let mut grew = false;
+        for rotation in &rotations {
+            if chain.contains(&rotation.old_did) && !chain.contains(&rotation.new_did) {
+                chain.push(rotation.new_did.clone());
+                grew = true;
+            }
+            if chain.contains(&rotation.new_did) && !chain.contains(&rotation.old_did) {
+                chain.push(rotation.old_did.clone());
+                grew = true;
+            }
+        }
+        if !grew {
+            break;
+        }
+    }
+
+    Ok(chain)
+}
@@ -0,0 +1,126 @@
+//! Identity verification gating for large purchases and redemptions. One
+//! `kyc_verifications` row per DID tracks the latest status; `assert_verified`
+//! is the enforcement point `routes::credits` calls before any movement at
+//! or above `RuntimeConfig::kyc_verification_threshold`. Mirrors
+//! `services::provider_clients`'s trait-plus-first-implementation shape so a
+//! second vendor (Onfido, Jumio, ...) can be added without touching
+//! `routes::credits`.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::config;
+use crate::error::{AppError, AppResult};
+use crate::models::{KycStatus, KycVerification};
+
+/// The result of asking a provider to check or start verification for a DID.
+pub struct KycCheck {
+    pub status: KycStatus,
+    pub external_ref: Option<String>,
+}
+
+/// A backend capable of checking or initiating identity verification for a
+/// DID. Implementations own their own credential lookup, the same as
+/// `ProviderClient`.
+#[async_trait]
+pub trait KycProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn check(&self, did: &str) -> AppResult<KycCheck>;
+}
+
+/// Checks inquiry status with Persona, keyed by `reference-id=<did>` at
+/// inquiry-creation time on the client side; this only polls status.
+struct PersonaKycProvider {
+    api_key: String,
+}
+
+#[async_trait]
+impl KycProvider for PersonaKycProvider {
+    fn name(&self) -> &'static str {
+        "persona"
+    }
+
+    async fn check(&self, did: &str) -> AppResult<KycCheck> {
+        let resp = reqwest::Client::new()
+            .get("https://withpersona.com/api/v1/inquiries")
+            .query(&[("filter[reference-id]", did)])
+            .bearer_auth(&self.api_key)
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("persona inquiry lookup failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("persona inquiry lookup rejected: {e}")))?;
+
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("persona inquiry response unreadable: {e}")))?;
+
+        let inquiry = body["data"].get(0);
+        let status = match inquiry.and_then(|i| i["attributes"]["status"].as_str()) {
+            Some("completed") | Some("approved") => KycStatus::Verified,
+            Some("declined") | Some("failed") => KycStatus::Rejected,
+            Some(_) => KycStatus::Pending,
+            None => KycStatus::Unverified,
+        };
+        let external_ref = inquiry.and_then(|i| i["id"].as_str()).map(str::to_string);
+
+        Ok(KycCheck { status, external_ref })
+    }
+}
+
+fn provider() -> AppResult<Box<dyn KycProvider>> {
+    let api_key = std::env::var("PERSONA_API_KEY")
+        .map_err(|_| AppError::Internal(anyhow::anyhow!("PERSONA_API_KEY not configured")))?;
+    Ok(Box::new(PersonaKycProvider { api_key }))
+}
+
+/// Reads the DID's stored verification status, defaulting to `Unverified`
+/// if no row exists yet (the DID has never gone through a check).
+pub async fn status_for(db: &PgPool, did: &str) -> AppResult<KycStatus> {
+    let verification = sqlx::query_as::<_, KycVerification>(
+        "SELECT * FROM kyc_verifications WHERE did = $1",
+    )
+    .bind(did)
+    .fetch_optional(db)
+    .await?;
+    Ok(verification.map(|v| v.status).unwrap_or(KycStatus::Unverified))
+}
+
+/// Asks the configured provider to (re-)check `did` and upserts the result.
+pub async fn refresh(db: &PgPool, did: &str) -> AppResult<KycVerification> {
+    let client = provider()?;
+    let check = client.check(did).await?;
+
+    let verification = sqlx::query_as::<_, KycVerification>(
+        "INSERT INTO kyc_verifications (did, status, provider, external_ref, updated_at)
+         VALUES ($1, $2, $3, $4, now())
+         ON CONFLICT (did) DO UPDATE SET status = excluded.status, provider = excluded.provider,
+             external_ref = excluded.external_ref, updated_at = now()
+         RETURNING *",
+    )
+    .bind(did)
+    .bind(check.status)
+    .bind(client.name())
+    .bind(check.external_ref)
+    .fetch_one(db)
+    .await?;
+
+    Ok(verification)
+}
+
+/// Enforcement point for `routes::credits`: if `amount` is at or above the
+/// configured threshold, `did` must already be `Verified`. Below threshold,
+/// this is a no-op — most purchases never touch KYC at all.
+pub async fn assert_verified(db: &PgPool, did: &str, amount: rust_decimal::Decimal) -> AppResult<()> {
+    if amount < config::current().kyc_verification_threshold {
+        return Ok(());
+    }
+    if status_for(db, did).await? != KycStatus::Verified {
+        return Err(AppError::Forbidden(format!(
+            "identity verification is required for amounts at or above {}",
+            config::current().kyc_verification_threshold
+        )));
+    }
+    Ok(())
+}
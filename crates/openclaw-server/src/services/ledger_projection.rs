@@ -0,0 +1,64 @@
+//! `m_credits_accounts.balance` is meant to be a materialized projection
+//! of `SUM(amount)` over `m_credits_ledger` for that DID — every spend
+//! path writes both in the same transaction (see `services::escrow`,
+//! `routes::credits`) specifically so they can't drift. `jobs::reconciliation`
+//! only *detects* drift, into `ledger_discrepancies`; this module is the
+//! other half, recomputing a DID's balance straight from its ledger and
+//! overwriting the stored projection, for operators to repair a
+//! discrepancy once it's found (see `routes::admin::rebuild_ledger_projection`).
+//!
+//! A full trigger- or projector-maintained `m_credits_accounts` (so the
+//! column can never be written directly) would mean moving every balance
+//! mutation in `services::escrow`, `services::promo`, `services::disputes`,
+//! and `routes::credits` onto a single ledger-insert-only path — a
+//! cross-cutting rewrite, not something to fold into a rebuild endpoint.
+//! This gives operators a correct, on-demand way to re-derive a balance
+//! in the meantime.
+
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+
+use crate::error::AppResult;
+
+/// Recomputes `did`'s balance as `SUM(amount)` over `m_credits_ledger` and
+/// overwrites `m_credits_accounts.balance` with it, creating the account
+/// row if it doesn't exist yet. Returns the rebuilt balance.
+pub async fn rebuild_one(db: &PgPool, did: &str) -> AppResult<Decimal> {
+    let mut tx = db.begin().await?;
+
+    let ledger_balance: Decimal = sqlx::query_scalar("SELECT COALESCE(SUM(amount), 0) FROM m_credits_ledger WHERE did = $1")
+        .bind(did)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_accounts (did, balance) VALUES ($1, $2)
+         ON CONFLICT (did) DO UPDATE SET balance = excluded.balance",
+    )
+    .bind(did)
+    .bind(ledger_balance)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(ledger_balance)
+}
+
+/// Rebuilds every DID that appears in either `m_credits_accounts` or
+/// `m_credits_ledger`, returning how many accounts were touched.
+pub async fn rebuild_all(db: &PgPool) -> AppResult<u64> {
+    let dids: Vec<String> = sqlx::query_scalar(
+        "SELECT did FROM m_credits_accounts
+         UNION
+         SELECT did FROM m_credits_ledger",
+    )
+    .fetch_all(db)
+    .await?;
+
+    for did in &dids {
+        rebuild_one(db, did).await?;
+    }
+
+    Ok(dids.len() as u64)
+}
@@ -0,0 +1,45 @@
+use openclaw_crypto::types::ManifestV1;
+use openclaw_crypto::verifying_key_from_did_key;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::AppError;
+use crate::models::StoredManifest;
+use crate::services::artifacts;
+use crate::AppResult;
+
+/// Verifies a manifest's outer signature and every nested artifact
+/// envelope, registers whichever artifacts aren't already known, and
+/// stores the manifest linked to the signer DID.
+pub async fn submit(db: &Db, manifest: ManifestV1) -> AppResult<StoredManifest> {
+    let verifying_key = verifying_key_from_did_key(&manifest.did)
+        .map_err(|e| AppError::BadRequest(format!("invalid signer DID: {e}")))?;
+    openclaw_crypto::verify::verify_manifest(&verifying_key, manifest.clone())
+        .map_err(|e| AppError::BadRequest(format!("manifest signature invalid: {e}")))?;
+
+    for envelope in &manifest.artifacts {
+        artifacts::register(db, envelope.clone(), Vec::new()).await?;
+    }
+
+    let manifest_json = serde_json::to_value(&manifest).map_err(|e| AppError::Internal(e.into()))?;
+    let row = sqlx::query_as::<_, StoredManifest>(
+        "INSERT INTO manifests (id, signer_did, manifest, created_at)
+         VALUES ($1, $2, $3, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&manifest.did)
+    .bind(manifest_json)
+    .fetch_one(db)
+    .await?;
+
+    Ok(row)
+}
+
+pub async fn get(db: &Db, id: Uuid) -> AppResult<Option<StoredManifest>> {
+    let row = sqlx::query_as::<_, StoredManifest>("SELECT * FROM manifests WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    Ok(row)
+}
@@ -0,0 +1,40 @@
+pub mod approvals;
+pub mod artifacts;
+pub mod bounty_templates;
+pub mod attestation;
+pub mod compute_providers;
+pub mod audit;
+pub mod chat;
+pub mod did_binding;
+pub mod delegation;
+pub mod disputes;
+pub mod email;
+pub mod escrow;
+pub mod event_bus;
+pub mod execution_receipts;
+pub mod fees;
+pub mod fraud;
+pub mod ipfs;
+pub mod jurors;
+pub mod key_rotation;
+pub mod kyc;
+pub mod ledger_projection;
+pub mod manifests;
+pub mod notifications;
+pub mod payment_providers;
+pub mod plagiarism;
+pub mod policy;
+pub mod posts;
+pub mod profiles;
+pub mod provider_clients;
+pub mod quorum;
+pub mod reputation;
+pub mod promo;
+pub mod reviewer_pool;
+pub mod runner_registry;
+pub mod spend_limits;
+pub mod storage;
+pub mod tags;
+pub mod trusted_runners;
+pub mod usage;
+pub mod webhooks;
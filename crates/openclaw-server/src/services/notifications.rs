@@ -0,0 +1,96 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::{NotificationChannel, NotificationPreferences, UserPolicy};
+use crate::services::webhooks;
+
+/// Category used to check `NotificationPreferences.event_types` against,
+/// e.g. `"bounty.expired"` -> `"submissions"`. Unrecognized prefixes default
+/// to always-on so new event kinds aren't silently swallowed.
+fn category_of(event: &str) -> Option<&'static str> {
+    match event.split('.').next().unwrap_or(event) {
+        "approval" => Some("approvals"),
+        "submission" | "bounty" => Some("submissions"),
+        "credit" => Some("credits"),
+        "dispute" => Some("disputes"),
+        _ => None,
+    }
+}
+
+/// Fans an event out to every channel `did`'s preferences and policy have
+/// enabled, plus any registered webhooks (webhooks aren't gated by
+/// preferences since they're opt-in by registration alone).
+pub async fn notify(db: &PgPool, did: &str, event: &str, detail: serde_json::Value) {
+    tracing::info!(did, event, ?detail, "notification");
+
+    if let Err(err) = webhooks::enqueue(db, did, event, detail.clone()).await {
+        tracing::error!(?err, did, event, "failed to enqueue webhook delivery");
+    }
+
+    let prefs = sqlx::query_as::<_, NotificationPreferences>(
+        "SELECT * FROM notification_preferences WHERE did = $1",
+    )
+    .bind(did)
+    .fetch_optional(db)
+    .await
+    .ok()
+    .flatten();
+    let category = category_of(event);
+    let wants = |channel: &str| match (prefs.as_ref(), category) {
+        (Some(p), Some(category)) => p.wants(channel, category),
+        (Some(p), None) => p.channels.iter().any(|c| c == channel),
+        (None, _) => true,
+    };
+
+    let Ok(Some(policy)) = sqlx::query_as::<_, UserPolicy>(
+        "SELECT * FROM user_policies WHERE did = $1",
+    )
+    .bind(did)
+    .fetch_optional(db)
+    .await
+    else {
+        return;
+    };
+
+    if wants("email") && policy.notification_channels.iter().any(|c| c == "email") {
+        if let Some(address) = policy.emergency_contact.get("email").and_then(|v| v.as_str()) {
+            if let Err(err) = enqueue(db, did, NotificationChannel::Email, address, event, &detail.to_string()).await {
+                tracing::error!(?err, did, event, "failed to enqueue notification email");
+            }
+        }
+    }
+
+    if wants("chat") && policy.notification_channels.iter().any(|c| c == "chat") {
+        if let Some(url) = policy.chat_webhook.as_ref().and_then(|w| w.get("webhook_url")).and_then(|v| v.as_str()) {
+            if let Err(err) = enqueue(db, did, NotificationChannel::Chat, url, event, &detail.to_string()).await {
+                tracing::error!(?err, did, event, "failed to enqueue chat notification");
+            }
+        }
+    }
+}
+
+/// Queues a single email or chat notification. Delivery happens out-of-band
+/// via `jobs::notification_delivery`, which handles retries with backoff
+/// the same way `webhooks::enqueue` hands off to `jobs::webhook_delivery`.
+async fn enqueue(
+    db: &PgPool,
+    did: &str,
+    channel: NotificationChannel,
+    destination: &str,
+    event: &str,
+    body: &str,
+) -> crate::error::AppResult<()> {
+    sqlx::query(
+        "INSERT INTO notification_deliveries (id, did, channel, destination, event, body, status, attempts, next_attempt_at, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, 'pending', 0, now(), now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(channel)
+    .bind(destination)
+    .bind(event)
+    .bind(body)
+    .execute(db)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,347 @@
+//! Pluggable checkout/webhook/refund backends for `/credits/purchase`,
+//! mirroring `services::provider_clients`'s trait-plus-first-implementation
+//! shape. Stripe is the only `PaymentProvider` implementation; Apple Pay's
+//! flow doesn't fit the trait, so it is the separate
+//! `ApplePayPaymentProvider` below. Other backends (USDC, ...) can be added
+//! as new `PaymentProvider` implementations without touching
+//! `routes::credits` again.
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::Invoice;
+
+pub struct CheckoutSession {
+    pub external_ref: String,
+    pub url: String,
+}
+
+pub struct RefundResult {
+    pub external_ref: String,
+}
+
+/// What a verified webhook event told us happened to an invoice.
+pub enum PaymentWebhookEvent {
+    Completed { invoice_id: Uuid, external_ref: String },
+    Failed { invoice_id: Uuid },
+    /// An event type this provider sends but `routes::credits` doesn't act
+    /// on (e.g. Stripe's `charge.dispute.created`). Not an error.
+    Ignored,
+}
+
+#[async_trait]
+pub trait PaymentProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn create_checkout(&self, invoice: &Invoice, did: &str) -> AppResult<CheckoutSession>;
+    /// Verifies `payload` against `signature` and parses it into a
+    /// `PaymentWebhookEvent`. Implementations must verify before parsing —
+    /// an unverified payload must never reach the `Completed` branch.
+    async fn verify_webhook(&self, payload: &[u8], signature: &str) -> AppResult<PaymentWebhookEvent>;
+    async fn refund(&self, external_ref: &str, amount: Decimal) -> AppResult<RefundResult>;
+}
+
+pub struct StripePaymentProvider {
+    secret_key: String,
+    webhook_secret: String,
+}
+
+impl StripePaymentProvider {
+    pub fn from_env() -> AppResult<Self> {
+        Ok(Self {
+            secret_key: std::env::var("STRIPE_SECRET_KEY")
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("STRIPE_SECRET_KEY not configured")))?,
+            webhook_secret: std::env::var("STRIPE_WEBHOOK_SECRET")
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("STRIPE_WEBHOOK_SECRET not configured")))?,
+        })
+    }
+}
+
+#[async_trait]
+impl PaymentProvider for StripePaymentProvider {
+    fn name(&self) -> &'static str {
+        "stripe"
+    }
+
+    /// Opens a Stripe Checkout Session tagged with the invoice ID and DID
+    /// in metadata, so `verify_webhook` can find its way back to this
+    /// invoice without trusting anything the client sends.
+    async fn create_checkout(&self, invoice: &Invoice, did: &str) -> AppResult<CheckoutSession> {
+        let unit_amount = (invoice.amount_usd * Decimal::from(100)).round().to_string();
+
+        let params = [
+            ("mode", "payment"),
+            ("success_url", "https://protocol-m.example/credits/success"),
+            ("cancel_url", "https://protocol-m.example/credits/cancel"),
+            ("line_items[0][quantity]", "1"),
+            ("line_items[0][price_data][currency]", &invoice.currency),
+            ("line_items[0][price_data][unit_amount]", &unit_amount),
+            ("line_items[0][price_data][product_data][name]", "Protocol M credits"),
+            ("metadata[invoice_id]", &invoice.id.to_string()),
+            ("metadata[did]", did),
+        ];
+
+        let resp = reqwest::Client::new()
+            .post("https://api.stripe.com/v1/checkout/sessions")
+            .basic_auth(&self.secret_key, Some(""))
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(e.into()))?;
+
+        let body: serde_json::Value = resp.json().await.map_err(|e| AppError::Internal(e.into()))?;
+
+        Ok(CheckoutSession {
+            external_ref: body["id"].as_str().unwrap_or_default().to_string(),
+            url: body["url"].as_str().unwrap_or_default().to_string(),
+        })
+    }
+
+    async fn verify_webhook(&self, payload: &[u8], signature: &str) -> AppResult<PaymentWebhookEvent> {
+        verify_stripe_signature(payload, signature, &self.webhook_secret)?;
+
+        let event: serde_json::Value = serde_json::from_slice(payload)
+            .map_err(|e| AppError::BadRequest(format!("invalid webhook payload: {e}")))?;
+
+        let event_type = event["type"].as_str().unwrap_or_default();
+        let object = &event["data"]["object"];
+        let invoice_id: Option<Uuid> = object["metadata"]["invoice_id"]
+            .as_str()
+            .and_then(|s| s.parse().ok());
+
+        match (event_type, invoice_id) {
+            ("checkout.session.completed", Some(invoice_id)) => Ok(PaymentWebhookEvent::Completed {
+                invoice_id,
+                external_ref: object["payment_intent"].as_str().unwrap_or_default().to_string(),
+            }),
+            ("checkout.session.expired" | "checkout.session.async_payment_failed", Some(invoice_id)) => {
+                Ok(PaymentWebhookEvent::Failed { invoice_id })
+            }
+            _ => Ok(PaymentWebhookEvent::Ignored),
+        }
+    }
+
+    async fn refund(&self, external_ref: &str, amount: Decimal) -> AppResult<RefundResult> {
+        let unit_amount = (amount * Decimal::from(100)).round().to_string();
+
+        let resp = reqwest::Client::new()
+            .post("https://api.stripe.com/v1/refunds")
+            .basic_auth(&self.secret_key, Some(""))
+            .form(&[("payment_intent", external_ref), ("amount", &unit_amount)])
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(e.into()))?;
+
+        let body: serde_json::Value = resp.json().await.map_err(|e| AppError::Internal(e.into()))?;
+
+        Ok(RefundResult {
+            external_ref: body["id"].as_str().unwrap_or_default().to_string(),
+        })
+    }
+}
+
+/// Verifies a Stripe `Stripe-Signature` header (`t=<timestamp>,v1=<hmac>`)
+/// against the raw request body using HMAC-SHA256, per Stripe's documented
+/// scheme. Rejects anything that doesn't produce an exact `v1` match.
+fn verify_stripe_signature(payload: &[u8], signature_header: &str, webhook_secret: &str) -> AppResult<()> {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let mut timestamp = None;
+    let mut v1 = None;
+    for part in signature_header.split(',') {
+        if let Some(t) = part.strip_prefix("t=") {
+            timestamp = Some(t);
+        } else if let Some(sig) = part.strip_prefix("v1=") {
+            v1 = Some(sig);
+        }
+    }
+    let (timestamp, v1) = timestamp
+        .zip(v1)
+        .ok_or_else(|| AppError::BadRequest("malformed Stripe-Signature header".into()))?;
+
+    let signed_payload = format!("{timestamp}.{}", String::from_utf8_lossy(payload));
+    let mut mac = Hmac::<Sha256>::new_from_slice(webhook_secret.as_bytes())
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("invalid webhook secret: {e}")))?;
+    mac.update(signed_payload.as_bytes());
+
+    // `Mac::verify_slice` compares in constant time; comparing the hex
+    // digests with `!=` would leak how many leading bytes matched through
+    // timing, letting an attacker who can measure response latency forge a
+    // valid signature byte by byte.
+    let v1_bytes = hex::decode(v1).map_err(|_| AppError::BadRequest("malformed Stripe-Signature header".into()))?;
+    mac.verify_slice(&v1_bytes)
+        .map_err(|_| AppError::BadRequest("Stripe webhook signature mismatch".into()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use super::verify_stripe_signature;
+
+    fn header_for(payload: &[u8], timestamp: &str, secret: &str) -> String {
+        let signed_payload = format!("{timestamp}.{}", String::from_utf8_lossy(payload));
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(signed_payload.as_bytes());
+        format!("t={timestamp},v1={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn accepts_a_correctly_signed_payload() {
+        let payload = b"{\"type\":\"checkout.session.completed\"}";
+        let header = header_for(payload, "1700000000", "whsec_test");
+        assert!(verify_stripe_signature(payload, &header, "whsec_test").is_ok());
+    }
+
+    #[test]
+    fn rejects_a_payload_signed_with_the_wrong_secret() {
+        let payload = b"{\"type\":\"checkout.session.completed\"}";
+        let header = header_for(payload, "1700000000", "whsec_wrong");
+        assert!(verify_stripe_signature(payload, &header, "whsec_test").is_err());
+    }
+
+    #[test]
+    fn rejects_a_tampered_payload() {
+        let signed_payload = b"{\"type\":\"checkout.session.completed\"}";
+        let header = header_for(signed_payload, "1700000000", "whsec_test");
+        let tampered = b"{\"type\":\"checkout.session.expired\"}";
+        assert!(verify_stripe_signature(tampered, &header, "whsec_test").is_err());
+    }
+
+    #[test]
+    fn rejects_a_malformed_header() {
+        let payload = b"{}";
+        assert!(verify_stripe_signature(payload, "not-a-valid-header", "whsec_test").is_err());
+    }
+}
+
+/// Resolves the configured payment provider. Only Stripe is wired up
+/// today; a second provider would be selected here the same way
+/// `provider_clients::client_for` dispatches on a credential scheme.
+pub fn current() -> AppResult<Box<dyn PaymentProvider>> {
+    Ok(Box::new(StripePaymentProvider::from_env()?))
+}
+
+/// The result of exchanging an Apple Pay payment token for a charge.
+pub struct ChargeResult {
+    pub external_ref: String,
+    pub succeeded: bool,
+}
+
+/// Apple Pay's flow doesn't fit `PaymentProvider::create_checkout` —
+/// there's no hosted redirect, only a merchant-validation round trip
+/// followed by a client-side token the server charges directly — so it's
+/// exposed as its own type rather than forced into that trait. It still
+/// charges through Stripe's tokens API (`POST /v1/tokens` with
+/// `pk_token=<apple pay payment data>`), so the server never sees a raw
+/// card number; only the merchant validation and token exchange are
+/// Apple-Pay-specific.
+pub struct ApplePayPaymentProvider {
+    secret_key: String,
+    merchant_id: String,
+    /// PEM bundle containing both the Apple Pay merchant identity
+    /// certificate and its private key, as Apple's Merchant Identity
+    /// Certificate is issued (cert and key concatenated in one file).
+    merchant_identity_pem_path: String,
+}
+
+impl ApplePayPaymentProvider {
+    pub fn from_env() -> AppResult<Self> {
+        Ok(Self {
+            secret_key: std::env::var("STRIPE_SECRET_KEY")
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("STRIPE_SECRET_KEY not configured")))?,
+            merchant_id: std::env::var("APPLE_PAY_MERCHANT_ID")
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("APPLE_PAY_MERCHANT_ID not configured")))?,
+            merchant_identity_pem_path: std::env::var("APPLE_PAY_MERCHANT_IDENTITY_PEM")
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("APPLE_PAY_MERCHANT_IDENTITY_PEM not configured")))?,
+        })
+    }
+
+    /// Proves merchant domain ownership to Apple by presenting our
+    /// merchant identity certificate to the validation URL the client's
+    /// `ApplePaySession` received from Apple. The opaque JSON response is
+    /// relayed straight back to the client for
+    /// `completeMerchantValidation`; the server never interprets it.
+    pub async fn validate_merchant(&self, validation_url: &str, display_name: &str) -> AppResult<serde_json::Value> {
+        let pem = std::fs::read(&self.merchant_identity_pem_path)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("failed to read Apple Pay merchant identity: {e}")))?;
+        let identity = reqwest::Identity::from_pem(&pem)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("invalid Apple Pay merchant identity PEM: {e}")))?;
+        let client = reqwest::Client::builder()
+            .identity(identity)
+            .build()
+            .map_err(|e| AppError::Internal(e.into()))?;
+
+        let resp = client
+            .post(validation_url)
+            .json(&serde_json::json!({
+                "merchantIdentifier": self.merchant_id,
+                "domainName": "protocol-m.example",
+                "displayName": display_name,
+            }))
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("apple pay merchant validation failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("apple pay merchant validation rejected: {e}")))?;
+
+        resp.json()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("apple pay merchant validation response unreadable: {e}")))
+    }
+
+    /// Exchanges the Apple Pay payment token the client collected from
+    /// `ApplePaySession.onpaymentauthorized` for a charge, via Stripe's
+    /// token exchange (`pk_token`) followed by a confirmed PaymentIntent.
+    /// Unlike the Stripe Checkout path, this is synchronous: Apple Pay
+    /// tokens are single-use and already user-authorized, so there's no
+    /// redirect to wait on.
+    pub async fn charge(&self, payment_token: &serde_json::Value, amount_usd: Decimal, currency: &str) -> AppResult<ChargeResult> {
+        let client = reqwest::Client::new();
+
+        let token_resp = client
+            .post("https://api.stripe.com/v1/tokens")
+            .basic_auth(&self.secret_key, Some(""))
+            .form(&[("pk_token", &payment_token.to_string())])
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("apple pay token exchange failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::BadRequest(format!("apple pay token was rejected: {e}")))?;
+        let token_body: serde_json::Value = token_resp.json().await.map_err(|e| AppError::Internal(e.into()))?;
+        let stripe_token = token_body["id"]
+            .as_str()
+            .ok_or_else(|| AppError::Internal(anyhow::anyhow!("apple pay token exchange response missing id")))?;
+
+        let unit_amount = (amount_usd * Decimal::from(100)).round().to_string();
+        let pi_resp = client
+            .post("https://api.stripe.com/v1/payment_intents")
+            .basic_auth(&self.secret_key, Some(""))
+            .form(&[
+                ("amount", unit_amount.as_str()),
+                ("currency", currency),
+                ("confirm", "true"),
+                ("payment_method_data[type]", "card"),
+                ("payment_method_data[card][token]", stripe_token),
+            ])
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("apple pay charge failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::BadRequest(format!("apple pay charge was declined: {e}")))?;
+        let pi_body: serde_json::Value = pi_resp.json().await.map_err(|e| AppError::Internal(e.into()))?;
+
+        Ok(ChargeResult {
+            external_ref: pi_body["id"].as_str().unwrap_or_default().to_string(),
+            succeeded: pi_body["status"].as_str() == Some("succeeded"),
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use openclaw_crypto::fuzzy_hash;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::models::{Artifact, SimilarityMatch, SimilarityMatchType, SubmissionSimilarityReport};
+use crate::services::artifacts::SIMILARITY_THRESHOLD;
+use crate::AppResult;
+
+/// Compares `artifact`'s content hash and fuzzy hash against every other
+/// signer's registered artifacts and persists the result as a
+/// `SubmissionSimilarityReport`, so quorum reviewers and the requester can
+/// see it before voting or releasing escrow. Run once, at submission
+/// creation (see `routes::submissions::create`).
+pub async fn check_submission(db: &Db, submission_id: Uuid, artifact: &Artifact) -> AppResult<SubmissionSimilarityReport> {
+    let mut matches = Vec::new();
+
+    let exact: Vec<(Uuid, String)> = sqlx::query_as(
+        "SELECT id, signer_did FROM artifacts WHERE sha256 = $1 AND signer_did != $2",
+    )
+    .bind(&artifact.sha256)
+    .bind(&artifact.signer_did)
+    .fetch_all(db)
+    .await?;
+    for (artifact_id, signer_did) in exact {
+        matches.push(SimilarityMatch {
+            artifact_id,
+            signer_did,
+            similarity: 1.0,
+            match_type: SimilarityMatchType::ExactHash,
+        });
+    }
+
+    if let Some(fuzzy) = &artifact.fuzzy_hash {
+        let candidates: Vec<(Uuid, String, String)> = sqlx::query_as(
+            "SELECT id, signer_did, fuzzy_hash FROM artifacts
+             WHERE id != $1 AND signer_did != $2 AND fuzzy_hash IS NOT NULL",
+        )
+        .bind(artifact.id)
+        .bind(&artifact.signer_did)
+        .fetch_all(db)
+        .await?;
+        for (artifact_id, signer_did, candidate_fuzzy) in candidates {
+            // Already counted as an exact-hash match.
+            if matches.iter().any(|m| m.artifact_id == artifact_id) {
+                continue;
+            }
+            if let Some(score) = fuzzy_hash::similarity(fuzzy, &candidate_fuzzy) {
+                if score >= SIMILARITY_THRESHOLD {
+                    matches.push(SimilarityMatch {
+                        artifact_id,
+                        signer_did,
+                        similarity: score,
+                        match_type: SimilarityMatchType::FuzzyHash,
+                    });
+                }
+            }
+        }
+    }
+
+    let report = sqlx::query_as::<_, SubmissionSimilarityReport>(
+        "INSERT INTO submission_similarity_reports (id, submission_id, matches, created_at)
+         VALUES ($1, $2, $3, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(submission_id)
+    .bind(serde_json::to_value(&matches).map_err(|e| crate::error::AppError::Internal(e.into()))?)
+    .fetch_one(db)
+    .await?;
+
+    Ok(report)
+}
+
+/// The similarity report recorded for a submission at creation time, if its
+/// artifact had one computed (i.e. it had an `artifact_id` at submission
+/// time — see `check_submission`).
+pub async fn report_for_submission(db: &Db, submission_id: Uuid) -> AppResult<Option<SubmissionSimilarityReport>> {
+    let report = sqlx::query_as::<_, SubmissionSimilarityReport>(
+        "SELECT * FROM submission_similarity_reports WHERE submission_id = $1",
+    )
+    .bind(submission_id)
+    .fetch_optional(db)
+    .await?;
+    Ok(report)
+}
@@ -0,0 +1,305 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{AccountFreezeEvent, ApprovalTier, PolicyRevision, UserPolicy};
+use crate::services::audit;
+
+/// One entry of `SetPolicyPayload::approval_tiers`. Mirrors `ApprovalTier`
+/// minus `id`/`did`, which are assigned when the tier is stored.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ApprovalTierInput {
+    pub name: String,
+    pub approvers: Vec<String>,
+    pub quorum: i32,
+    pub threshold: Decimal,
+    pub notification_channel: Option<String>,
+}
+
+/// The document `did` signs to replace its policy. `version` is
+/// optimistic-concurrency: it must be exactly one more than the version
+/// currently on file (or `1` for a first-time policy), so a stale local
+/// `policy set` can't silently clobber a newer one synced from elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SetPolicyPayload {
+    pub version: i32,
+    pub max_spend_per_day: Option<Decimal>,
+    pub max_spend_per_bounty: Option<Decimal>,
+    #[serde(default)]
+    pub allowed_delegates: Vec<String>,
+    #[serde(default)]
+    pub notification_channels: Vec<String>,
+    #[serde(default)]
+    pub emergency_contact: Value,
+    pub chat_webhook: Option<Value>,
+    #[serde(default)]
+    pub approval_tiers: Vec<ApprovalTierInput>,
+}
+
+#[derive(Serialize)]
+struct SetPolicySignaturePayload<'a> {
+    action: &'a str,
+    did: &'a str,
+    version: i32,
+    max_spend_per_day: Option<Decimal>,
+    max_spend_per_bounty: Option<Decimal>,
+    allowed_delegates: &'a [String],
+    notification_channels: &'a [String],
+    emergency_contact: &'a Value,
+    chat_webhook: &'a Option<Value>,
+    approval_tiers: &'a [ApprovalTierInput],
+}
+
+fn validate_tiers(tiers: &[ApprovalTierInput]) -> AppResult<()> {
+    for tier in tiers {
+        if tier.approvers.is_empty() {
+            return Err(AppError::BadRequest(format!("tier '{}' has no approvers", tier.name)));
+        }
+        if tier.quorum < 1 || tier.quorum as usize > tier.approvers.len() {
+            return Err(AppError::BadRequest(format!(
+                "tier '{}' quorum must be between 1 and its approver count",
+                tier.name
+            )));
+        }
+        if tier.threshold < Decimal::ZERO {
+            return Err(AppError::BadRequest(format!("tier '{}' threshold must not be negative", tier.name)));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct RollbackSignaturePayload<'a> {
+    action: &'a str,
+    did: &'a str,
+    target_version: i32,
+}
+
+/// Verifies `signature` is over `{action: "set_policy", did, ...document}`
+/// signed by `did`, validates `document.approval_tiers`, and replaces `did`'s
+/// policy and tiers in a single transaction. `document.version` must follow
+/// directly from the version currently on file.
+pub async fn set(db: &PgPool, did: &str, document: SetPolicyPayload, signature: &str) -> AppResult<UserPolicy> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid did: {e}")))?;
+    let payload = SetPolicySignaturePayload {
+        action: "set_policy",
+        did,
+        version: document.version,
+        max_spend_per_day: document.max_spend_per_day,
+        max_spend_per_bounty: document.max_spend_per_bounty,
+        allowed_delegates: &document.allowed_delegates,
+        notification_channels: &document.notification_channels,
+        emergency_contact: &document.emergency_contact,
+        chat_webhook: &document.chat_webhook,
+        approval_tiers: &document.approval_tiers,
+    };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("policy signature invalid: {e}")))?;
+
+    persist(db, did, document, signature).await
+}
+
+/// Every revision `did`'s policy has gone through, newest first, each
+/// carrying the signing envelope that authorized it — see `models::policy`
+/// for why that envelope is kept instead of just the resulting row.
+pub async fn history(db: &PgPool, did: &str) -> AppResult<Vec<PolicyRevision>> {
+    let revisions = sqlx::query_as::<_, PolicyRevision>(
+        "SELECT * FROM user_policy_revisions WHERE did = $1 ORDER BY version DESC",
+    )
+    .bind(did)
+    .fetch_all(db)
+    .await?;
+    Ok(revisions)
+}
+
+/// Verifies `signature` is over `{action: "rollback_policy", did,
+/// target_version}` signed by `did`, then re-applies `target_version`'s
+/// document as a brand-new revision (never rewriting history in place, so
+/// the audit trail of "who loosened what, and when it was caught and
+/// reverted" stays intact).
+pub async fn rollback(db: &PgPool, did: &str, target_version: i32, signature: &str) -> AppResult<UserPolicy> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid did: {e}")))?;
+    let payload = RollbackSignaturePayload { action: "rollback_policy", did, target_version };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("rollback signature invalid: {e}")))?;
+
+    let revision = sqlx::query_as::<_, PolicyRevision>(
+        "SELECT * FROM user_policy_revisions WHERE did = $1 AND version = $2",
+    )
+    .bind(did)
+    .bind(target_version)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)?;
+
+    let mut document: SetPolicyPayload = serde_json::from_value(revision.document)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("stored policy revision {target_version} is corrupt: {e}")))?;
+
+    let current_version = sqlx::query_scalar::<_, i32>("SELECT version FROM user_policies WHERE did = $1")
+        .bind(did)
+        .fetch_optional(db)
+        .await?
+        .unwrap_or(0);
+    document.version = current_version + 1;
+
+    persist(db, did, document, signature).await
+}
+
+async fn persist(db: &PgPool, did: &str, document: SetPolicyPayload, signature: &str) -> AppResult<UserPolicy> {
+    validate_tiers(&document.approval_tiers)?;
+
+    let mut tx = db.begin().await?;
+
+    let current_version = sqlx::query_scalar::<_, i32>("SELECT version FROM user_policies WHERE did = $1")
+        .bind(did)
+        .fetch_optional(&mut *tx)
+        .await?;
+    let expected_version = current_version.unwrap_or(0) + 1;
+    if document.version != expected_version {
+        return Err(AppError::BadRequest(format!(
+            "version must be {expected_version}, the policy may have changed since it was last fetched"
+        )));
+    }
+
+    let before: Option<UserPolicy> = sqlx::query_as("SELECT * FROM user_policies WHERE did = $1")
+        .bind(did)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+    let policy = sqlx::query_as::<_, UserPolicy>(
+        "INSERT INTO user_policies (did, max_spend_per_day, max_spend_per_bounty, allowed_delegates, notification_channels, emergency_contact, chat_webhook, version, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+         ON CONFLICT (did) DO UPDATE SET
+             max_spend_per_day = excluded.max_spend_per_day,
+             max_spend_per_bounty = excluded.max_spend_per_bounty,
+             allowed_delegates = excluded.allowed_delegates,
+             notification_channels = excluded.notification_channels,
+             emergency_contact = excluded.emergency_contact,
+             chat_webhook = excluded.chat_webhook,
+             version = excluded.version,
+             updated_at = now()
+         RETURNING *",
+    )
+    .bind(did)
+    .bind(document.max_spend_per_day)
+    .bind(document.max_spend_per_bounty)
+    .bind(&document.allowed_delegates)
+    .bind(&document.notification_channels)
+    .bind(&document.emergency_contact)
+    .bind(&document.chat_webhook)
+    .bind(document.version)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM approval_tiers WHERE did = $1")
+        .bind(did)
+        .execute(&mut *tx)
+        .await?;
+    for tier in &document.approval_tiers {
+        sqlx::query_as::<_, ApprovalTier>(
+            "INSERT INTO approval_tiers (id, did, name, approvers, quorum, threshold, notification_channel)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+        )
+        .bind(Uuid::new_v4())
+        .bind(did)
+        .bind(&tier.name)
+        .bind(&tier.approvers)
+        .bind(tier.quorum)
+        .bind(tier.threshold)
+        .bind(&tier.notification_channel)
+        .fetch_one(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query_as::<_, PolicyRevision>(
+        "INSERT INTO user_policy_revisions (id, did, version, document, signature, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(document.version)
+    .bind(serde_json::json!(&document))
+    .bind(signature)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        did,
+        "set_policy",
+        "user_policy",
+        Uuid::nil(),
+        before.map(|p| serde_json::json!(p)),
+        Some(serde_json::json!(&policy)),
+    )
+    .await?;
+
+    Ok(policy)
+}
+
+/// `did`'s currently enforced policy, or `NotFound` if it has never set one.
+pub async fn get(db: &PgPool, did: &str) -> AppResult<UserPolicy> {
+    sqlx::query_as::<_, UserPolicy>("SELECT * FROM user_policies WHERE did = $1")
+        .bind(did)
+        .fetch_optional(db)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+/// Whether `did`'s most recent freeze event left it frozen. An account with
+/// no freeze history is never frozen.
+pub async fn is_frozen(db: &PgPool, did: &str) -> AppResult<bool> {
+    let frozen = sqlx::query_scalar::<_, bool>(
+        "SELECT frozen FROM account_freeze_events WHERE did = $1 ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(did)
+    .fetch_optional(db)
+    .await?
+    .unwrap_or(false);
+    Ok(frozen)
+}
+
+/// Blocks the caller's action with `Forbidden` if `did` is currently
+/// frozen. Called at the start of the spend-adjacent flows a freeze
+/// blocks: bounty creation (`routes::bounties::create`), credit
+/// redemption (`routes::credits::redeem_credits`), and Apple Pay charges
+/// (`routes::credits::apple_pay_charge`).
+pub async fn assert_not_frozen(db: &PgPool, did: &str) -> AppResult<()> {
+    if is_frozen(db, did).await? {
+        return Err(AppError::Forbidden(format!("{did}'s account is frozen")));
+    }
+    Ok(())
+}
+
+/// Records a freeze/unfreeze event for `did`, immediately changing what
+/// `assert_not_frozen` reports. `actor_did` is either `did` itself (a
+/// self-triggered emergency freeze) or an operator acting on `did`'s behalf
+/// after verifying the emergency contact out of band.
+pub async fn set_frozen(db: &PgPool, did: &str, frozen: bool, reason: &str, actor_did: &str) -> AppResult<AccountFreezeEvent> {
+    let event = sqlx::query_as::<_, AccountFreezeEvent>(
+        "INSERT INTO account_freeze_events (id, did, frozen, reason, actor_did, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(frozen)
+    .bind(reason)
+    .bind(actor_did)
+    .fetch_one(db)
+    .await?;
+
+    let action = if frozen { "freeze_account" } else { "unfreeze_account" };
+    audit::record(db, actor_did, action, "account_freeze_event", event.id, None, Some(serde_json::json!(&event))).await?;
+
+    Ok(event)
+}
@@ -0,0 +1,106 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Comment, Post};
+
+#[derive(Serialize)]
+struct PostPayload<'a> {
+    action: &'a str,
+    body: &'a str,
+}
+
+/// Verifies `signature` is over `{action: "create_post", body}` signed by
+/// `author_did`, then records the post.
+pub async fn create_post(db: &PgPool, author_did: &str, body: &str, signature: &str) -> AppResult<Post> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(author_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid author_did: {e}")))?;
+    let payload = PostPayload { action: "create_post", body };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("post signature invalid: {e}")))?;
+
+    let post = sqlx::query_as::<_, Post>(
+        "INSERT INTO posts (id, author_did, body, signature, created_at)
+         VALUES ($1, $2, $3, $4, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(author_did)
+    .bind(body)
+    .bind(signature)
+    .fetch_one(db)
+    .await?;
+
+    Ok(post)
+}
+
+pub async fn list_posts(db: &PgPool, cursor: Option<Uuid>, limit: i64) -> AppResult<Vec<Post>> {
+    let posts = sqlx::query_as::<_, Post>(
+        "SELECT * FROM posts
+         WHERE ($1::uuid IS NULL OR (created_at, id) < (SELECT created_at, id FROM posts WHERE id = $1))
+         ORDER BY created_at DESC, id DESC
+         LIMIT $2",
+    )
+    .bind(cursor)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    Ok(posts)
+}
+
+#[derive(Serialize)]
+struct CommentPayload<'a> {
+    action: &'a str,
+    post_id: Uuid,
+    body: &'a str,
+}
+
+/// Verifies `signature` is over `{action: "create_comment", post_id, body}`
+/// signed by `author_did`, then records the comment.
+pub async fn create_comment(
+    db: &PgPool,
+    post_id: Uuid,
+    author_did: &str,
+    body: &str,
+    signature: &str,
+) -> AppResult<Comment> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(author_did)
+        .map_err(|e| AppError::BadRequest(format!("invalid author_did: {e}")))?;
+    let payload = CommentPayload { action: "create_comment", post_id, body };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("comment signature invalid: {e}")))?;
+
+    let comment = sqlx::query_as::<_, Comment>(
+        "INSERT INTO comments (id, post_id, author_did, body, signature, created_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(post_id)
+    .bind(author_did)
+    .bind(body)
+    .bind(signature)
+    .fetch_one(db)
+    .await?;
+
+    Ok(comment)
+}
+
+pub async fn list_comments(db: &PgPool, post_id: Uuid, cursor: Option<Uuid>, limit: i64) -> AppResult<Vec<Comment>> {
+    let comments = sqlx::query_as::<_, Comment>(
+        "SELECT * FROM comments
+         WHERE post_id = $1
+           AND ($2::uuid IS NULL OR (created_at, id) > (SELECT created_at, id FROM comments WHERE id = $2))
+         ORDER BY created_at ASC, id ASC
+         LIMIT $3",
+    )
+    .bind(post_id)
+    .bind(cursor)
+    .bind(limit)
+    .fetch_all(db)
+    .await?;
+
+    Ok(comments)
+}
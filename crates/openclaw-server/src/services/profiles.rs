@@ -0,0 +1,100 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{Profile, ProfilePublicKey, ProfileView};
+use crate::services::key_rotation;
+
+#[derive(Serialize)]
+struct ProfilePayload<'a> {
+    action: &'a str,
+    display_name: &'a Option<String>,
+    links: &'a [String],
+    avatar_artifact_id: Option<Uuid>,
+}
+
+/// Verifies `signature` is over `{action: "update_profile", display_name,
+/// links, avatar_artifact_id}` signed by `did`, then upserts the profile.
+pub async fn upsert(
+    db: &PgPool,
+    did: &str,
+    display_name: Option<String>,
+    links: Vec<String>,
+    avatar_artifact_id: Option<Uuid>,
+    signature: &str,
+) -> AppResult<Profile> {
+    let verifying_key = openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid did: {e}")))?;
+    let payload = ProfilePayload { action: "update_profile", display_name: &display_name, links: &links, avatar_artifact_id };
+    openclaw_crypto::verify::verify_canonical(&verifying_key, &payload, signature)
+        .map_err(|e| AppError::BadRequest(format!("profile signature invalid: {e}")))?;
+
+    let profile = sqlx::query_as::<_, Profile>(
+        "INSERT INTO profiles (did, display_name, links, avatar_artifact_id, signature, updated_at)
+         VALUES ($1, $2, $3, $4, $5, now())
+         ON CONFLICT (did) DO UPDATE SET
+             display_name = excluded.display_name,
+             links = excluded.links,
+             avatar_artifact_id = excluded.avatar_artifact_id,
+             signature = excluded.signature,
+             updated_at = now()
+         RETURNING *",
+    )
+    .bind(did)
+    .bind(&display_name)
+    .bind(&links)
+    .bind(avatar_artifact_id)
+    .bind(signature)
+    .fetch_one(db)
+    .await?;
+
+    Ok(profile)
+}
+
+/// Assembles `GET /api/v1/profile/{did}`: the self-asserted profile (or an
+/// empty default if `did` never set one), a verified-artifact count
+/// resolved across `did`'s key rotation chain, its running reputation
+/// score, and a DID-document-shaped public key record — so a caller doesn't
+/// need to trust anything the DID claims about itself beyond `display_name`
+/// and `links`.
+pub async fn view(db: &PgPool, did: &str) -> AppResult<ProfileView> {
+    openclaw_crypto::verifying_key_from_did_key(did)
+        .map_err(|e| AppError::BadRequest(format!("invalid did: {e}")))?;
+
+    let profile = sqlx::query_as::<_, Profile>("SELECT * FROM profiles WHERE did = $1")
+        .bind(did)
+        .fetch_optional(db)
+        .await?;
+
+    let chain = key_rotation::resolve_chain(db, did).await?;
+    let verified_artifact_count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM artifacts WHERE signer_did = ANY($1::text[])")
+            .bind(&chain)
+            .fetch_one(db)
+            .await?;
+
+    let reputation_score: rust_decimal::Decimal =
+        sqlx::query_scalar("SELECT score FROM reputation_scores WHERE did = $1")
+            .bind(did)
+            .fetch_optional(db)
+            .await?
+            .unwrap_or_default();
+
+    let public_key = ProfilePublicKey {
+        id: did.to_string(),
+        verification_method: format!("{did}#controller"),
+        public_key_multibase: did.strip_prefix("did:key:").unwrap_or(did).to_string(),
+    };
+
+    Ok(ProfileView {
+        did: did.to_string(),
+        display_name: profile.as_ref().and_then(|p| p.display_name.clone()),
+        links: profile.as_ref().map(|p| p.links.clone()).unwrap_or_default(),
+        avatar_artifact_id: profile.as_ref().and_then(|p| p.avatar_artifact_id),
+        verified_artifact_count,
+        reputation_score,
+        public_key,
+        updated_at: profile.map(|p| p.updated_at),
+    })
+}
@@ -0,0 +1,202 @@
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::config;
+use crate::error::{AppError, AppResult};
+use crate::models::PromoGrant;
+use crate::services::audit;
+
+/// Grants `amount` of promo credit to `did`, refusing the grant if it would
+/// push that DID's outstanding (unexpired, undrawn) promo balance past
+/// `RuntimeConfig::promo_credit_cap`. The cap is per-DID, not global — it
+/// bounds how much promo exposure any one account can carry, not how much
+/// the platform hands out in total.
+pub async fn grant(
+    db: &PgPool,
+    did: &str,
+    amount: Decimal,
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    actor_did: &str,
+) -> AppResult<PromoGrant> {
+    if amount <= Decimal::ZERO {
+        return Err(AppError::BadRequest("grant amount must be positive".into()));
+    }
+
+    let mut tx = db.begin().await?;
+
+    let outstanding: Vec<Decimal> = sqlx::query_scalar(
+        "SELECT remaining FROM promo_grants
+         WHERE did = $1 AND remaining > 0 AND (expires_at IS NULL OR expires_at > now())
+         FOR UPDATE",
+    )
+    .bind(did)
+    .fetch_all(&mut *tx)
+    .await?;
+    let outstanding: Decimal = outstanding.into_iter().sum();
+
+    let cap = config::current().promo_credit_cap;
+    if outstanding + amount > cap {
+        return Err(AppError::BadRequest(format!(
+            "grant would push {did}'s outstanding promo balance to {}, which exceeds the cap of {cap}",
+            outstanding + amount
+        )));
+    }
+
+    let grant = sqlx::query_as::<_, PromoGrant>(
+        "INSERT INTO promo_grants (id, did, amount, remaining, expires_at, created_at)
+         VALUES ($1, $2, $3, $3, $4, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(amount)
+    .bind(expires_at)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, 'promo_grant', $4, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(amount)
+    .bind(grant.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    audit::record(
+        db,
+        actor_did,
+        "grant_promo",
+        "promo_grant",
+        grant.id,
+        None,
+        Some(serde_json::json!(&grant)),
+    )
+    .await?;
+
+    Ok(grant)
+}
+
+/// Draws `amount` down from `did`'s unexpired promo grants, oldest first,
+/// and returns however much couldn't be covered by promo balance (to be
+/// charged against the main balance by the caller). Promo credits are
+/// always consumed before main balance.
+pub async fn spend_promo_first(
+    tx: &mut Transaction<'_, Postgres>,
+    did: &str,
+    amount: Decimal,
+) -> AppResult<Decimal> {
+    let mut remaining_to_charge = amount;
+
+    let grants = sqlx::query_as::<_, PromoGrant>(
+        "SELECT * FROM promo_grants
+         WHERE did = $1 AND remaining > 0 AND (expires_at IS NULL OR expires_at > now())
+         ORDER BY created_at ASC
+         FOR UPDATE",
+    )
+    .bind(did)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for grant in grants {
+        if remaining_to_charge <= Decimal::ZERO {
+            break;
+        }
+        let draw = grant.remaining.min(remaining_to_charge);
+        sqlx::query("UPDATE promo_grants SET remaining = remaining - $1 WHERE id = $2")
+            .bind(draw)
+            .bind(grant.id)
+            .execute(&mut **tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+             VALUES ($1, $2, $3, 'promo_spend', $4, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(did)
+        .bind(-draw)
+        .bind(grant.id)
+        .execute(&mut **tx)
+        .await?;
+        remaining_to_charge -= draw;
+    }
+
+    Ok(remaining_to_charge)
+}
+
+/// Returns `amount` of previously-drawn promo credit to `did`, for
+/// unwinding a hold that `spend_promo_first` partly funded (see
+/// `escrow::refund_escrow_for_bounty`). Rather than trying to re-credit
+/// the exact originating grant(s) `spend_promo_first` drew from — which may
+/// have since expired or been drawn down further by other holds — this
+/// opens a new, non-expiring grant for the refunded amount; the credit is
+/// promo either way, so which grant row holds it doesn't change what the
+/// DID can do with it.
+pub async fn refund(
+    tx: &mut Transaction<'_, Postgres>,
+    did: &str,
+    amount: Decimal,
+    reference_id: Uuid,
+) -> AppResult<()> {
+    if amount <= Decimal::ZERO {
+        return Ok(());
+    }
+
+    sqlx::query(
+        "INSERT INTO promo_grants (id, did, amount, remaining, expires_at, created_at)
+         VALUES ($1, $2, $3, $3, NULL, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(amount)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+         VALUES ($1, $2, $3, 'promo_refund', $4, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(amount)
+    .bind(reference_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Burns any promo balance that has passed its `expires_at`, writing a
+/// ledger event so the loss is auditable rather than a silent zeroing-out.
+pub async fn burn_expired(db: &PgPool) -> AppResult<u64> {
+    let expired = sqlx::query_as::<_, PromoGrant>(
+        "SELECT * FROM promo_grants WHERE remaining > 0 AND expires_at IS NOT NULL AND expires_at <= now()",
+    )
+    .fetch_all(db)
+    .await?;
+
+    let count = expired.len() as u64;
+    for grant in expired {
+        sqlx::query("UPDATE promo_grants SET remaining = 0 WHERE id = $1")
+            .bind(grant.id)
+            .execute(db)
+            .await?;
+        sqlx::query(
+            "INSERT INTO m_credits_ledger (id, did, amount, event_type, reference_id, created_at)
+             VALUES ($1, $2, $3, 'promo_expired', $4, now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(&grant.did)
+        .bind(-grant.remaining)
+        .bind(grant.id)
+        .execute(db)
+        .await?;
+    }
+
+    Ok(count)
+}
@@ -0,0 +1,94 @@
+//! Pluggable backends for `/credits/redeem`'s actual provider allocation.
+//! Each `ComputeProvider` row names a client via `credential_ref`'s scheme
+//! (e.g. `"anthropic:ANTHROPIC_ADMIN_KEY"` resolves to
+//! `AnthropicCreditGrantClient`, reading the API key from the named env
+//! var); unrecognized schemes are a configuration error, not a runtime one.
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::error::{AppError, AppResult};
+use crate::models::ComputeProvider;
+
+/// The result of successfully allocating compute with a provider.
+pub struct ProviderAllocation {
+    /// The provider's own identifier for this grant, stored as
+    /// `redemption_receipts.external_ref` for reconciliation.
+    pub external_ref: String,
+}
+
+/// A backend capable of turning redeemed credits into allocated compute.
+/// Implementations own their own credential lookup and map provider-side
+/// failures to `AppError` themselves, so `allocate_with_provider` stays a
+/// thin dispatcher.
+#[async_trait]
+pub trait ProviderClient: Send + Sync {
+    async fn allocate(&self, did: &str, amount: Decimal) -> AppResult<ProviderAllocation>;
+}
+
+/// Grants Anthropic API credits to `did` via the admin credit-grant API.
+/// `credential_ref` must name an env var holding an admin API key.
+struct AnthropicCreditGrantClient {
+    api_key: String,
+}
+
+#[async_trait]
+impl ProviderClient for AnthropicCreditGrantClient {
+    async fn allocate(&self, did: &str, amount: Decimal) -> AppResult<ProviderAllocation> {
+        let resp = reqwest::Client::new()
+            .post("https://api.anthropic.com/v1/organizations/credit_grants")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .json(&serde_json::json!({
+                "external_account_id": did,
+                "amount_usd": amount,
+            }))
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("anthropic credit grant request failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("anthropic credit grant rejected: {e}")))?;
+
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("anthropic credit grant response unreadable: {e}")))?;
+
+        let external_ref = body["id"]
+            .as_str()
+            .ok_or_else(|| AppError::Internal(anyhow::anyhow!("anthropic credit grant response missing id")))?
+            .to_string();
+
+        Ok(ProviderAllocation { external_ref })
+    }
+}
+
+/// Resolves `provider.credential_ref` (`"<scheme>:<env_var>"`) to a
+/// `ProviderClient`, reading the credential from the named env var.
+fn client_for(provider: &ComputeProvider) -> AppResult<Box<dyn ProviderClient>> {
+    let (scheme, env_var) = provider
+        .credential_ref
+        .split_once(':')
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("provider '{}' has a malformed credential_ref", provider.name)))?;
+
+    match scheme {
+        "anthropic" => {
+            let api_key = std::env::var(env_var)
+                .map_err(|_| AppError::Internal(anyhow::anyhow!("{env_var} not configured for provider '{}'", provider.name)))?;
+            Ok(Box::new(AnthropicCreditGrantClient { api_key }))
+        }
+        other => Err(AppError::Internal(anyhow::anyhow!(
+            "provider '{}' has unsupported credential scheme '{other}'",
+            provider.name
+        ))),
+    }
+}
+
+/// Allocates `amount` of compute with `provider` on `did`'s behalf,
+/// returning the provider's reference for the grant. Replaces the
+/// placeholder `allocate_with_provider` that `routes::credits::redeem_credits`
+/// used to leave unimplemented.
+pub async fn allocate_with_provider(provider: &ComputeProvider, did: &str, amount: Decimal) -> AppResult<ProviderAllocation> {
+    let client = client_for(provider)?;
+    client.allocate(did, amount).await
+}
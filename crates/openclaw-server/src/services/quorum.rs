@@ -0,0 +1,92 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Bounty, ReviewVote};
+use crate::services::{escrow, reputation};
+
+/// A reviewer with no track record yet (or a negative one) still gets a say
+/// in the weighted tally, just a minimal one — otherwise a single bad early
+/// vote could zero out their input forever.
+const MIN_REVIEWER_WEIGHT: Decimal = dec!(0.1);
+
+/// Credibility a reviewer earns for voting with the quorum's
+/// credibility-weighted outcome, or loses for voting against it.
+const REVIEWER_CREDIBILITY_DELTA: Decimal = dec!(0.1);
+
+#[derive(sqlx::FromRow)]
+struct WeightedVote {
+    reviewer_did: String,
+    vote: ReviewVote,
+    weight: Decimal,
+}
+
+/// Tallies recorded votes for `submission_id`, weighted by each reviewer's
+/// current reputation score, against the bounty's `reviewer_count`
+/// threshold; once enough votes are in and the credibility-weighted
+/// majority approves, releases escrow to the submitter and marks the
+/// bounty closed. Either way, each reviewer earns or loses a small amount
+/// of credibility depending on whether their vote matched the outcome.
+pub async fn tally_and_maybe_release(
+    db: &PgPool,
+    bounty: &Bounty,
+    submission_id: Uuid,
+    submitter_did: &str,
+) -> AppResult<bool> {
+    let required = bounty.reviewer_count.unwrap_or(1);
+
+    let votes: Vec<WeightedVote> = sqlx::query_as(
+        "SELECT r.reviewer_did, r.vote, GREATEST(COALESCE(s.score, 0), $2) AS weight
+         FROM submission_reviews r
+         LEFT JOIN reputation_scores s ON s.did = r.reviewer_did
+         WHERE r.submission_id = $1",
+    )
+    .bind(submission_id)
+    .bind(MIN_REVIEWER_WEIGHT)
+    .fetch_all(db)
+    .await?;
+
+    if (votes.len() as i32) < required {
+        return Ok(false);
+    }
+
+    let total_weight: Decimal = votes.iter().map(|v| v.weight).sum();
+    let approval_weight: Decimal =
+        votes.iter().filter(|v| v.vote == ReviewVote::Approve).map(|v| v.weight).sum();
+    let quorum_reached = approval_weight * dec!(2) > total_weight;
+
+    let winning_vote = if quorum_reached { ReviewVote::Approve } else { ReviewVote::Reject };
+    for v in &votes {
+        let delta = if v.vote == winning_vote { REVIEWER_CREDIBILITY_DELTA } else { -REVIEWER_CREDIBILITY_DELTA };
+        reputation::mint_reputation(db, &v.reviewer_did, delta, Some(bounty.id), Some(submission_id), Some(v.weight)).await?;
+    }
+
+    if !quorum_reached {
+        sqlx::query("UPDATE submissions SET status = 'rejected', updated_at = now() WHERE id = $1")
+            .bind(submission_id)
+            .execute(db)
+            .await?;
+        return Ok(false);
+    }
+
+    let mut tx = db.begin().await?;
+    sqlx::query("UPDATE submissions SET status = 'accepted', updated_at = now() WHERE id = $1")
+        .bind(submission_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE bounties SET status = 'closed', updated_at = now() WHERE id = $1")
+        .bind(bounty.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    let artifact_id: Option<Uuid> =
+        sqlx::query_scalar("SELECT artifact_id FROM submissions WHERE id = $1")
+            .bind(submission_id)
+            .fetch_one(db)
+            .await?;
+    escrow::release_to_with_royalties(db, bounty.id, submitter_did, bounty.reward, crate::models::EscrowPurpose::BountyReward, artifact_id).await?;
+    Ok(true)
+}
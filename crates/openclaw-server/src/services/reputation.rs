@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{ReputationEvent, ReputationEventKind};
+
+/// Below this account age, a mint is scaled down towards
+/// `NEW_ACCOUNT_WEIGHT_FLOOR` — a freshly bound DID hasn't earned the
+/// benefit of the doubt yet.
+const ACCOUNT_AGE_FULL_WEIGHT_DAYS: i64 = 30;
+const NEW_ACCOUNT_WEIGHT_FLOOR: Decimal = dec!(0.25);
+
+/// Below this credits balance, a mint is scaled down towards
+/// `STAKE_WEIGHT_FLOOR` — an account with nothing at stake is cheap to
+/// throw away and recreate.
+const STAKE_FOR_FULL_WEIGHT: Decimal = dec!(100);
+const STAKE_WEIGHT_FLOOR: Decimal = dec!(0.5);
+
+/// Once a DID has earned this much lifetime positive reputation from a
+/// single poster, further mints tied to that poster's bounties are
+/// dropped entirely, so a poster can't farm reputation for an account by
+/// repeatedly closing bounties in its own favor.
+const MAX_REPUTATION_PER_POSTER: Decimal = dec!(50);
+
+/// A reviewer's own credibility can't zero out (or invert the sign of) a
+/// quorum-derived award just because it dipped low; it only ever scales a
+/// mint down towards this floor, never below it.
+const MIN_CREDIBILITY_WEIGHT: Decimal = dec!(0.1);
+
+/// Mints (or slashes, via a negative `amount`) reputation for `did`.
+///
+/// Positive mints are scaled by a Sybil-resistance weight — account age,
+/// credits balance as a stake proxy, and how much of `did`'s reputation
+/// already came from this same poster — before being recorded and rolled
+/// into `reputation_scores`. Slashes are never softened by the weighting:
+/// a low-stake, brand-new, or farm-suspected account should lose
+/// reputation just as readily as an established one.
+///
+/// `reviewer_credibility`, when set, further scales a positive mint by the
+/// reviewer's own standing (floored at `MIN_CREDIBILITY_WEIGHT`) — see
+/// `services::quorum::tally_and_maybe_release`, which passes a reviewer's
+/// current score in when minting their quorum-participation credibility,
+/// so a track record of accurate votes compounds into larger swings.
+pub async fn mint_reputation(
+    db: &PgPool,
+    did: &str,
+    amount: Decimal,
+    bounty_id: Option<Uuid>,
+    submission_id: Option<Uuid>,
+    reviewer_credibility: Option<Decimal>,
+) -> AppResult<ReputationEvent> {
+    let kind = if amount >= Decimal::ZERO {
+        ReputationEventKind::Mint
+    } else {
+        ReputationEventKind::Slash
+    };
+
+    let amount = if amount > Decimal::ZERO {
+        let credibility_weight = reviewer_credibility.map(|c| c.max(MIN_CREDIBILITY_WEIGHT)).unwrap_or(Decimal::ONE);
+        amount * credibility_weight * sybil_weight(db, did, bounty_id).await?
+    } else {
+        amount
+    };
+
+    let event = sqlx::query_as::<_, ReputationEvent>(
+        "INSERT INTO reputation_events (id, did, kind, amount, bounty_id, submission_id, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, now())
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(kind)
+    .bind(amount)
+    .bind(bounty_id)
+    .bind(submission_id)
+    .fetch_one(db)
+    .await?;
+
+    sqlx::query("UPDATE reputation_scores SET score = score + $1 WHERE did = $2")
+        .bind(amount)
+        .bind(did)
+        .execute(db)
+        .await?;
+
+    Ok(event)
+}
+
+/// Combined Sybil-resistance multiplier for a positive mint: account age
+/// weight, times stake weight, times the counterparty-diversity cap.
+async fn sybil_weight(db: &PgPool, did: &str, bounty_id: Option<Uuid>) -> AppResult<Decimal> {
+    Ok(account_age_weight(db, did).await? * stake_weight(db, did).await? * counterparty_weight(db, did, bounty_id).await?)
+}
+
+async fn account_age_weight(db: &PgPool, did: &str) -> AppResult<Decimal> {
+    let bound_at: Option<DateTime<Utc>> =
+        sqlx::query_scalar("SELECT bound_at FROM did_bindings WHERE did = $1 AND revoked_at IS NULL")
+            .bind(did)
+            .fetch_optional(db)
+            .await?;
+
+    let Some(bound_at) = bound_at else {
+        return Ok(NEW_ACCOUNT_WEIGHT_FLOOR);
+    };
+
+    let age_days = (Utc::now() - bound_at).num_days().max(0);
+    if age_days >= ACCOUNT_AGE_FULL_WEIGHT_DAYS {
+        return Ok(Decimal::ONE);
+    }
+
+    let progress = Decimal::from(age_days) / Decimal::from(ACCOUNT_AGE_FULL_WEIGHT_DAYS);
+    Ok(NEW_ACCOUNT_WEIGHT_FLOOR + (Decimal::ONE - NEW_ACCOUNT_WEIGHT_FLOOR) * progress)
+}
+
+async fn stake_weight(db: &PgPool, did: &str) -> AppResult<Decimal> {
+    let balance: Option<Decimal> = sqlx::query_scalar("SELECT balance FROM m_credits_accounts WHERE did = $1")
+        .bind(did)
+        .fetch_optional(db)
+        .await?;
+    let balance = balance.unwrap_or_default().max(Decimal::ZERO);
+
+    if balance >= STAKE_FOR_FULL_WEIGHT {
+        return Ok(Decimal::ONE);
+    }
+
+    let progress = balance / STAKE_FOR_FULL_WEIGHT;
+    Ok(STAKE_WEIGHT_FLOOR + (Decimal::ONE - STAKE_WEIGHT_FLOOR) * progress)
+}
+
+async fn counterparty_weight(db: &PgPool, did: &str, bounty_id: Option<Uuid>) -> AppResult<Decimal> {
+    let Some(bounty_id) = bounty_id else {
+        return Ok(Decimal::ONE);
+    };
+
+    let poster_did: Option<String> = sqlx::query_scalar("SELECT poster_did FROM bounties WHERE id = $1")
+        .bind(bounty_id)
+        .fetch_optional(db)
+        .await?;
+    let Some(poster_did) = poster_did else {
+        return Ok(Decimal::ONE);
+    };
+
+    let earned_from_poster: Option<Decimal> = sqlx::query_scalar(
+        "SELECT SUM(e.amount) FROM reputation_events e
+         JOIN bounties b ON b.id = e.bounty_id
+         WHERE e.did = $1 AND b.poster_did = $2 AND e.amount > 0",
+    )
+    .bind(did)
+    .bind(&poster_did)
+    .fetch_one(db)
+    .await?;
+
+    if earned_from_poster.unwrap_or_default() >= MAX_REPUTATION_PER_POSTER {
+        Ok(Decimal::ZERO)
+    } else {
+        Ok(Decimal::ONE)
+    }
+}
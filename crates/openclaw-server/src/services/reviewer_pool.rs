@@ -0,0 +1,64 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::{Bounty, ReviewerAssignment};
+
+/// Picks `bounty.reviewer_count` eligible reviewers for `submission_id` and
+/// records the assignment.
+///
+/// Eligibility: reputation at or above `min_reviewer_rep`, not the poster or
+/// submitter (conflict of interest), and not already assigned to this
+/// submission. Among eligible candidates we prefer whoever has been
+/// assigned least recently, which rotates the pool instead of always
+/// picking the highest-reputation reviewers.
+pub async fn assign_reviewers(
+    db: &PgPool,
+    bounty: &Bounty,
+    submission_id: Uuid,
+    submitter_did: &str,
+) -> AppResult<Vec<ReviewerAssignment>> {
+    let needed = bounty.reviewer_count.unwrap_or(1);
+    let min_rep = bounty.min_reviewer_rep.unwrap_or_default();
+
+    let candidates: Vec<String> = sqlx::query_scalar(
+        "SELECT r.did FROM reputation_scores r
+         LEFT JOIN (
+             SELECT reviewer_did, max(assigned_at) AS last_assigned
+             FROM reviewer_assignments GROUP BY reviewer_did
+         ) a ON a.reviewer_did = r.did
+         WHERE r.score >= $1
+           AND r.did <> $2
+           AND r.did <> $3
+           AND NOT EXISTS (
+               SELECT 1 FROM reviewer_assignments x
+               WHERE x.submission_id = $5 AND x.reviewer_did = r.did
+           )
+         ORDER BY a.last_assigned ASC NULLS FIRST
+         LIMIT $4",
+    )
+    .bind(min_rep)
+    .bind(&bounty.poster_did)
+    .bind(submitter_did)
+    .bind(needed as i64)
+    .bind(submission_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut assignments = Vec::with_capacity(candidates.len());
+    for reviewer_did in candidates {
+        let assignment = sqlx::query_as::<_, ReviewerAssignment>(
+            "INSERT INTO reviewer_assignments (id, submission_id, reviewer_did, assigned_at)
+             VALUES ($1, $2, $3, now())
+             RETURNING *",
+        )
+        .bind(Uuid::new_v4())
+        .bind(submission_id)
+        .bind(&reviewer_did)
+        .fetch_one(db)
+        .await?;
+        assignments.push(assignment);
+    }
+
+    Ok(assignments)
+}
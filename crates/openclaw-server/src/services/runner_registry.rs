@@ -0,0 +1,79 @@
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{RunnerRegistration, RunnerRegistrationStatus};
+
+/// Submits or updates a runner's application to the global registry.
+/// Re-applying while pending or rejected resets it to `pending` so the
+/// operator re-reviews it; an already-approved or suspended runner must go
+/// through `set_status` instead of self-service re-registration.
+pub async fn register(
+    db: &PgPool,
+    runner_did: &str,
+    harness_types: Vec<String>,
+    attestation: Value,
+) -> AppResult<RunnerRegistration> {
+    if let Some(existing) = get(db, runner_did).await? {
+        if existing.status == RunnerRegistrationStatus::Approved || existing.status == RunnerRegistrationStatus::Suspended {
+            return Err(AppError::BadRequest(format!(
+                "runner is already {:?}; contact an operator to change status",
+                existing.status
+            )));
+        }
+    }
+
+    let registration = sqlx::query_as::<_, RunnerRegistration>(
+        "INSERT INTO runner_registrations (id, runner_did, harness_types, attestation, status, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, 'pending', now(), now())
+         ON CONFLICT (runner_did) DO UPDATE SET
+            harness_types = excluded.harness_types,
+            attestation = excluded.attestation,
+            status = 'pending',
+            updated_at = now()
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(runner_did)
+    .bind(&harness_types)
+    .bind(&attestation)
+    .fetch_one(db)
+    .await?;
+
+    Ok(registration)
+}
+
+pub async fn get(db: &PgPool, runner_did: &str) -> AppResult<Option<RunnerRegistration>> {
+    let registration = sqlx::query_as::<_, RunnerRegistration>(
+        "SELECT * FROM runner_registrations WHERE runner_did = $1",
+    )
+    .bind(runner_did)
+    .fetch_optional(db)
+    .await?;
+    Ok(registration)
+}
+
+/// The public directory: only runners an operator has approved, so
+/// submitters don't have to guess which trusted-runner additions will
+/// actually be honored.
+pub async fn list_approved(db: &PgPool) -> AppResult<Vec<RunnerRegistration>> {
+    let registrations = sqlx::query_as::<_, RunnerRegistration>(
+        "SELECT * FROM runner_registrations WHERE status = 'approved' ORDER BY created_at ASC",
+    )
+    .fetch_all(db)
+    .await?;
+    Ok(registrations)
+}
+
+pub async fn set_status(db: &PgPool, id: Uuid, status: RunnerRegistrationStatus) -> AppResult<RunnerRegistration> {
+    let registration = sqlx::query_as::<_, RunnerRegistration>(
+        "UPDATE runner_registrations SET status = $2, updated_at = now() WHERE id = $1 RETURNING *",
+    )
+    .bind(id)
+    .bind(status)
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::NotFound)?;
+    Ok(registration)
+}
@@ -0,0 +1,43 @@
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+
+use crate::error::AppResult;
+use crate::models::UserPolicy;
+
+/// Sum of debits (`escrow_hold`, `redemption`, ...) posted to `did`'s
+/// ledger in the trailing 24 hours. Credits flowing the other way don't
+/// offset this — the cap is on spend, not net balance change.
+pub async fn rolling_24h_spend(db: &PgPool, did: &str) -> AppResult<Decimal> {
+    let spent: Option<Decimal> = sqlx::query_scalar(
+        "SELECT -SUM(amount) FROM m_credits_ledger
+         WHERE did = $1 AND amount < 0 AND created_at > now() - interval '24 hours'",
+    )
+    .bind(did)
+    .fetch_one(db)
+    .await?;
+    Ok(spent.unwrap_or_default())
+}
+
+/// Returns `Ok(())` if spending `amount` more would stay within `did`'s
+/// `max_spend_per_day` policy limit (no policy row, or no limit set, means
+/// unlimited).
+pub async fn check_daily_limit(db: &PgPool, did: &str, amount: Decimal) -> AppResult<()> {
+    let Some(policy) = sqlx::query_as::<_, UserPolicy>("SELECT * FROM user_policies WHERE did = $1")
+        .bind(did)
+        .fetch_optional(db)
+        .await?
+    else {
+        return Ok(());
+    };
+    let Some(limit) = policy.max_spend_per_day else {
+        return Ok(());
+    };
+
+    let spent = rolling_24h_spend(db, did).await?;
+    if spent + amount > limit {
+        return Err(crate::error::AppError::BadRequest(format!(
+            "spend of {amount} would exceed daily policy limit of {limit} ({spent} already spent in the last 24h)"
+        )));
+    }
+    Ok(())
+}
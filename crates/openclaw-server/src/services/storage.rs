@@ -0,0 +1,135 @@
+//! Minimal, dependency-free AWS SigV4 presigned URLs for S3-compatible
+//! object storage (AWS S3, Cloudflare R2, MinIO, ...). One object per
+//! artifact, keyed by its sha256 hash so the key itself is content-derived.
+
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::error::AppError;
+use crate::AppResult;
+
+type HmacSha256 = Hmac<Sha256>;
+
+struct StorageConfig {
+    access_key: String,
+    secret_key: String,
+    region: String,
+    bucket: String,
+    endpoint_host: String,
+}
+
+fn load_config() -> AppResult<StorageConfig> {
+    let missing = |var: &str| AppError::Internal(anyhow::anyhow!("{var} not configured"));
+    Ok(StorageConfig {
+        access_key: std::env::var("S3_ACCESS_KEY_ID").map_err(|_| missing("S3_ACCESS_KEY_ID"))?,
+        secret_key: std::env::var("S3_SECRET_ACCESS_KEY").map_err(|_| missing("S3_SECRET_ACCESS_KEY"))?,
+        region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+        bucket: std::env::var("S3_BUCKET").map_err(|_| missing("S3_BUCKET"))?,
+        endpoint_host: std::env::var("S3_ENDPOINT_HOST")
+            .unwrap_or_else(|_| "s3.amazonaws.com".to_string()),
+    })
+}
+
+/// Presigns a request for `method` (`"PUT"` or `"GET"`) against the object
+/// keyed by `sha256`, valid for `expires_in_seconds`.
+fn presign(method: &str, sha256: &str, expires_in_seconds: u32) -> AppResult<String> {
+    let cfg = load_config()?;
+    let now = chrono::Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date_stamp = now.format("%Y%m%d").to_string();
+    let credential_scope = format!("{date_stamp}/{}/s3/aws4_request", cfg.region);
+    let host = format!("{}.{}", cfg.bucket, cfg.endpoint_host);
+
+    let mut query_params = vec![
+        ("X-Amz-Algorithm".to_string(), "AWS4-HMAC-SHA256".to_string()),
+        (
+            "X-Amz-Credential".to_string(),
+            format!("{}/{credential_scope}", cfg.access_key),
+        ),
+        ("X-Amz-Date".to_string(), amz_date.clone()),
+        ("X-Amz-Expires".to_string(), expires_in_seconds.to_string()),
+        ("X-Amz-SignedHeaders".to_string(), "host".to_string()),
+    ];
+    query_params.sort();
+    let canonical_query_string = query_params
+        .iter()
+        .map(|(k, v)| format!("{}={}", uri_encode(k, true), uri_encode(v, true)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let canonical_request = format!(
+        "{method}\n/{key}\n{canonical_query_string}\nhost:{host}\n\nhost\nUNSIGNED-PAYLOAD",
+        key = uri_encode(sha256, false),
+    );
+    let canonical_request_hash = hex::encode(Sha256::digest(canonical_request.as_bytes()));
+
+    let string_to_sign =
+        format!("AWS4-HMAC-SHA256\n{amz_date}\n{credential_scope}\n{canonical_request_hash}");
+
+    let signature = hex::encode(sign_chain(&cfg.secret_key, &date_stamp, &cfg.region, &string_to_sign));
+
+    Ok(format!(
+        "https://{host}/{key}?{canonical_query_string}&X-Amz-Signature={signature}",
+        key = uri_encode(sha256, false),
+    ))
+}
+
+fn sign_chain(secret_key: &str, date_stamp: &str, region: &str, string_to_sign: &str) -> Vec<u8> {
+    let hmac = |key: &[u8], data: &str| -> Vec<u8> {
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+        mac.update(data.as_bytes());
+        mac.finalize().into_bytes().to_vec()
+    };
+    let k_date = hmac(format!("AWS4{secret_key}").as_bytes(), date_stamp);
+    let k_region = hmac(&k_date, region);
+    let k_service = hmac(&k_region, "s3");
+    let k_signing = hmac(&k_service, "aws4_request");
+    hmac(&k_signing, string_to_sign)
+}
+
+/// Percent-encodes per the AWS SigV4 "URI encode" rules: everything except
+/// unreserved characters is escaped with uppercase hex; `/` is left alone
+/// in object keys (`encode_slash = false`) but escaped in query values.
+fn uri_encode(input: &str, encode_slash: bool) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(byte as char),
+            b'/' if !encode_slash => out.push('/'),
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+const UPLOAD_URL_TTL_SECONDS: u32 = 900;
+const DOWNLOAD_URL_TTL_SECONDS: u32 = 3600;
+
+/// A presigned PUT URL the caller can upload artifact bytes to directly,
+/// keyed by the artifact's content hash.
+pub fn presigned_upload_url(sha256: &str) -> AppResult<String> {
+    presign("PUT", sha256, UPLOAD_URL_TTL_SECONDS)
+}
+
+/// A presigned GET URL to retrieve previously uploaded artifact bytes.
+pub fn presigned_download_url(sha256: &str) -> AppResult<String> {
+    presign("GET", sha256, DOWNLOAD_URL_TTL_SECONDS)
+}
+
+/// Downloads the object keyed by `sha256`, for hash verification and IPFS
+/// pinning once content has supposedly been uploaded.
+pub async fn fetch_uploaded_content(sha256: &str) -> AppResult<bytes::Bytes> {
+    let url = presigned_download_url(sha256)?;
+    reqwest::get(&url)
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+        .error_for_status()
+        .map_err(|e| AppError::Internal(e.into()))?
+        .bytes()
+        .await
+        .map_err(|e| AppError::Internal(e.into()))
+}
+
+pub fn hash_matches(content: &[u8], sha256: &str) -> bool {
+    hex::encode(Sha256::digest(content)) == sha256
+}
@@ -0,0 +1,107 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::TagSubscription;
+use crate::services::notifications;
+
+/// The managed bounty category taxonomy. Deliberately a fixed list rather
+/// than free text: `tag subscription` only means something if the set of
+/// tags an agent can subscribe to is stable and known in advance.
+pub const TAXONOMY: &[&str] = &[
+    "backend",
+    "frontend",
+    "infra",
+    "security",
+    "data",
+    "ml",
+    "docs",
+    "design",
+    "research",
+    "other",
+];
+
+/// Rejects any tag not in `TAXONOMY`, so bounties can't accumulate
+/// free-text tags that no one will ever subscribe to.
+pub fn validate(tags: &[String]) -> AppResult<()> {
+    for tag in tags {
+        if !TAXONOMY.contains(&tag.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "unknown tag {tag:?}; must be one of {TAXONOMY:?}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub async fn subscribe(db: &PgPool, did: &str, tag: &str) -> AppResult<TagSubscription> {
+    validate(std::slice::from_ref(&tag.to_string()))?;
+
+    let subscription = sqlx::query_as::<_, TagSubscription>(
+        "INSERT INTO tag_subscriptions (id, did, tag, created_at)
+         VALUES ($1, $2, $3, now())
+         ON CONFLICT (did, tag) DO NOTHING
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(tag)
+    .fetch_optional(db)
+    .await?;
+
+    if let Some(subscription) = subscription {
+        return Ok(subscription);
+    }
+
+    let subscription = sqlx::query_as::<_, TagSubscription>(
+        "SELECT * FROM tag_subscriptions WHERE did = $1 AND tag = $2",
+    )
+    .bind(did)
+    .bind(tag)
+    .fetch_one(db)
+    .await?;
+
+    Ok(subscription)
+}
+
+pub async fn unsubscribe(db: &PgPool, did: &str, tag: &str) -> AppResult<()> {
+    sqlx::query("DELETE FROM tag_subscriptions WHERE did = $1 AND tag = $2")
+        .bind(did)
+        .bind(tag)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn list_subscriptions(db: &PgPool, did: &str) -> AppResult<Vec<TagSubscription>> {
+    let subscriptions = sqlx::query_as::<_, TagSubscription>(
+        "SELECT * FROM tag_subscriptions WHERE did = $1 ORDER BY tag ASC",
+    )
+    .bind(did)
+    .fetch_all(db)
+    .await?;
+    Ok(subscriptions)
+}
+
+/// Notifies every DID subscribed to any of `tags` that a new bounty matching
+/// their specialty went up. Best-effort per subscriber, same as every other
+/// `notifications::notify` call site — one subscriber's failed delivery
+/// shouldn't stop the others from hearing about it.
+pub async fn notify_subscribers(db: &PgPool, tags: &[String], detail: serde_json::Value) -> AppResult<()> {
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    let subscribers: Vec<String> = sqlx::query_scalar(
+        "SELECT DISTINCT did FROM tag_subscriptions WHERE tag = ANY($1::text[])",
+    )
+    .bind(tags)
+    .fetch_all(db)
+    .await?;
+
+    for did in subscribers {
+        notifications::notify(db, &did, "bounty.tag_match", detail.clone()).await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::TrustedRunner;
+
+/// Adds `runner_did` to `bounty_id`'s trusted-runner registry; idempotent,
+/// since a poster re-adding a runner they already trust isn't an error.
+pub async fn add(db: &PgPool, bounty_id: Uuid, runner_did: &str, added_by: &str) -> AppResult<TrustedRunner> {
+    let runner = sqlx::query_as::<_, TrustedRunner>(
+        "INSERT INTO trusted_runners (id, bounty_id, runner_did, added_by, created_at)
+         VALUES ($1, $2, $3, $4, now())
+         ON CONFLICT (bounty_id, runner_did) DO NOTHING
+         RETURNING *",
+    )
+    .bind(Uuid::new_v4())
+    .bind(bounty_id)
+    .bind(runner_did)
+    .bind(added_by)
+    .fetch_optional(db)
+    .await?;
+
+    match runner {
+        Some(runner) => Ok(runner),
+        None => Ok(sqlx::query_as::<_, TrustedRunner>(
+            "SELECT * FROM trusted_runners WHERE bounty_id = $1 AND runner_did = $2",
+        )
+        .bind(bounty_id)
+        .bind(runner_did)
+        .fetch_one(db)
+        .await?),
+    }
+}
+
+pub async fn list(db: &PgPool, bounty_id: Uuid) -> AppResult<Vec<TrustedRunner>> {
+    let runners = sqlx::query_as::<_, TrustedRunner>(
+        "SELECT * FROM trusted_runners WHERE bounty_id = $1 ORDER BY created_at ASC",
+    )
+    .bind(bounty_id)
+    .fetch_all(db)
+    .await?;
+    Ok(runners)
+}
+
+pub async fn is_trusted(db: &PgPool, bounty_id: Uuid, runner_did: &str) -> AppResult<bool> {
+    let trusted: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM trusted_runners WHERE bounty_id = $1 AND runner_did = $2)",
+    )
+    .bind(bounty_id)
+    .bind(runner_did)
+    .fetch_one(db)
+    .await?;
+    Ok(trusted)
+}
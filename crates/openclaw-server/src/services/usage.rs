@@ -0,0 +1,58 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::UsageMetric;
+
+/// Appends one usage event for `did`. Called from `routes::track_usage` for
+/// every request carrying a DID header, and from the artifact/submission handlers for the more
+/// specific metrics — the per-request event alone can't tell an operator
+/// how many of those requests were artifact registrations vs. plain reads.
+pub async fn record(db: &PgPool, did: &str, metric: UsageMetric) -> AppResult<()> {
+    sqlx::query(
+        "INSERT INTO usage_events (id, did, metric, created_at) VALUES ($1, $2, $3, now())",
+    )
+    .bind(Uuid::new_v4())
+    .bind(did)
+    .bind(metric)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Count of `metric` events for `did` in the trailing 24 hours.
+pub async fn rolling_24h_count(db: &PgPool, did: &str, metric: UsageMetric) -> AppResult<i64> {
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM usage_events
+         WHERE did = $1 AND metric = $2 AND created_at > now() - interval '24 hours'",
+    )
+    .bind(did)
+    .bind(metric)
+    .fetch_one(db)
+    .await?;
+    Ok(count)
+}
+
+/// Returns `Ok(())` if `did` has not yet exceeded `limit` occurrences of
+/// `metric` in the trailing 24 hours; mirrors
+/// `spend_limits::check_daily_limit`, but against a request-count quota
+/// instead of a credit-spend one. `None` means unlimited.
+pub async fn check_quota(
+    db: &PgPool,
+    did: &str,
+    metric: UsageMetric,
+    limit: Option<i64>,
+) -> AppResult<()> {
+    let Some(limit) = limit else {
+        return Ok(());
+    };
+
+    let count = rolling_24h_count(db, did, metric).await?;
+    if count >= limit {
+        return Err(crate::error::AppError::domain(
+            crate::error::ErrorCode::BadRequest,
+            format!("{did} has exceeded its {metric:?} quota of {limit} per 24h"),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::WebhookRegistration;
+
+/// Queues a delivery for every webhook `did` has registered. Delivery
+/// happens out-of-band via `jobs::webhook_delivery`, which signs the
+/// payload and handles retries; this just writes the pending rows.
+pub async fn enqueue(db: &PgPool, did: &str, event: &str, payload: serde_json::Value) -> AppResult<()> {
+    let webhooks = sqlx::query_as::<_, WebhookRegistration>(
+        "SELECT * FROM webhook_registrations WHERE did = $1",
+    )
+    .bind(did)
+    .fetch_all(db)
+    .await?;
+
+    for webhook in webhooks {
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (id, webhook_id, event, payload, status, attempts, next_attempt_at, created_at)
+             VALUES ($1, $2, $3, $4, 'pending', 0, now(), now())",
+        )
+        .bind(Uuid::new_v4())
+        .bind(webhook.id)
+        .bind(event)
+        .bind(&payload)
+        .execute(db)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// HMAC-SHA256 of the JSON payload bytes, hex-encoded, so receivers can
+/// verify `X-Protocol-M-Signature` against their registered secret.
+pub fn sign_payload(secret: &str, payload: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac accepts any key length");
+    mac.update(payload);
+    hex::encode(mac.finalize().into_bytes())
+}
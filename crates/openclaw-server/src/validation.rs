@@ -0,0 +1,50 @@
+//! Shared request-DTO validation. Handlers call `req.validate()?` right
+//! after extracting the body, instead of hand-rolling the same
+//! length/range/DID-format checks per route; see `bounties::create` and
+//! `credits::purchase` for the pattern new endpoints should follow.
+
+use rust_decimal::Decimal;
+
+use crate::error::{AppError, AppResult};
+
+pub trait Validate {
+    fn validate(&self) -> AppResult<()>;
+}
+
+pub fn non_empty(value: &str, field: &str) -> AppResult<()> {
+    if value.trim().is_empty() {
+        return Err(AppError::BadRequest(format!("{field} must not be empty")));
+    }
+    Ok(())
+}
+
+pub fn max_len(value: &str, max: usize, field: &str) -> AppResult<()> {
+    if value.len() > max {
+        return Err(AppError::BadRequest(format!("{field} must be at most {max} characters")));
+    }
+    Ok(())
+}
+
+/// A DID is `did:<method>:<identifier>`; this only checks the shape, not
+/// that `<method>` is one this server resolves.
+pub fn did(value: &str, field: &str) -> AppResult<()> {
+    let mut parts = value.splitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("did"), Some(method), Some(id)) if !method.is_empty() && !id.is_empty() => Ok(()),
+        _ => Err(AppError::BadRequest(format!("{field} must be a did:<method>:<id> DID"))),
+    }
+}
+
+pub fn in_range(value: Decimal, min: Decimal, max: Decimal, field: &str) -> AppResult<()> {
+    if value < min || value > max {
+        return Err(AppError::BadRequest(format!("{field} must be between {min} and {max}")));
+    }
+    Ok(())
+}
+
+pub fn positive(value: Decimal, field: &str) -> AppResult<()> {
+    if value <= Decimal::ZERO {
+        return Err(AppError::BadRequest(format!("{field} must be positive")));
+    }
+    Ok(())
+}